@@ -7,7 +7,7 @@ use frame_support::{
     traits::{EnsureOrigin, UnfilteredDispatchable, Imbalance},
 };
 use frame_support::dispatch::DispatchResultWithPostInfo;
-use frame_support::sp_runtime::Percent;
+use frame_support::sp_runtime::{DispatchError, Percent};
 use frame_support::traits::tokens::Balance;
 use frame_system::{ensure_signed, RawOrigin};
 use scale_info::TypeInfo;
@@ -23,18 +23,33 @@ pub trait CreateRecoveryCallFactory<Origin, AccountId, BlockNumber> {
     fn build_create_recovery_call(legal_officers: Vec<AccountId>, threshold: u16, delay_period: BlockNumber) -> Self::Call;
 }
 
-pub struct LegalOfficerCaseSummary<AccountId> {
+pub struct LegalOfficerCaseSummary<AccountId, BlockNumber> {
     pub owner: AccountId,
     pub requester: Option<AccountId>,
+    pub created_on: Option<BlockNumber>,
+    pub closed_on: Option<BlockNumber>,
+    pub voided_on: Option<BlockNumber>,
 }
 
-pub trait LocQuery<LocId, AccountId> {
-    fn has_closed_identity_locs(account: &AccountId, legal_officer: &Vec<AccountId>) -> bool;
-    fn get_loc(loc_id: &LocId) -> Option<LegalOfficerCaseSummary<AccountId>>;
+/// Queries LOCs, an error being returned while LOCs cannot be read (e.g. during a storage migration).
+pub trait LocQuery<LocId, AccountId, BlockNumber> {
+    fn has_closed_identity_locs(account: &AccountId, legal_officer: &Vec<AccountId>) -> Result<bool, DispatchError>;
+    fn get_loc(loc_id: &LocId) -> Result<Option<LegalOfficerCaseSummary<AccountId, BlockNumber>>, DispatchError>;
 }
 
+/// Checks LOCs validity, an error being returned while LOCs cannot be read (e.g. during a storage migration).
 pub trait LocValidity<LocId, AccountId> {
-    fn loc_valid_with_owner(loc_id: &LocId, legal_officer: &AccountId) -> bool;
+    fn loc_valid_with_owner(loc_id: &LocId, legal_officer: &AccountId) -> Result<bool, DispatchError>;
+}
+
+pub trait LocOwnership<AccountId> {
+    fn owns_locs(legal_officer: &AccountId) -> bool;
+}
+
+impl<AccountId> LocOwnership<AccountId> for () {
+    fn owns_locs(_legal_officer: &AccountId) -> bool {
+        false
+    }
 }
 
 pub trait MultisigApproveAsMultiCallFactory<Origin, AccountId, Timepoint> {
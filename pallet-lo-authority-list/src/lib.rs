@@ -16,7 +16,7 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use sp_runtime::traits::BadOrigin;
 use frame_support::{BoundedVec, sp_runtime, traits::EnsureOrigin};
 
-use logion_shared::{IsLegalOfficer, LegalOfficerCreation};
+use logion_shared::{IsLegalOfficer, LegalOfficerCreation, LocOwnership};
 use scale_info::{TypeInfo, prelude::string::String};
 use serde::{Deserialize, Serialize};
 
@@ -149,9 +149,16 @@ pub mod pallet {
 
 		/// The maximum length in bytes of PeerId
 		type MaxPeerIdLength: Get<u32> + Member + TypeInfo + Ord;
+
+		/// Tells if a LO still owns LOCs, preventing its removal. Use `()` to always allow removal.
+		type LocOwnership: LocOwnership<Self::AccountId>;
 	}
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// All LOs indexed by their account ID.
@@ -169,26 +176,6 @@ pub mod pallet {
     #[pallet::getter(fn legal_officer_nodes)]
     pub type LegalOfficerNodes<T> = StorageValue<_, BoundedBTreeSet<BoundedPeerId<<T as pallet::Config>::MaxPeerIdLength>, <T as pallet::Config>::MaxNodes>, ValueQuery>;
 
-    #[derive(Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
-    pub enum StorageVersion {
-        V1,
-        V2AddOnchainSettings,
-        V3GuestLegalOfficers,
-        V4Region,
-        V5Imported,
-    }
-
-    impl Default for StorageVersion {
-        fn default() -> StorageVersion {
-            return StorageVersion::V5Imported;
-        }
-    }
-
-    /// Storage version
-    #[pallet::storage]
-    #[pallet::getter(fn pallet_storage_version)]
-    pub type PalletStorageVersion<T> = StorageValue<_, StorageVersion, ValueQuery>;
-
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub legal_officers: Vec<(T::AccountId, GenesisHostData)>,
@@ -205,7 +192,6 @@ pub mod pallet {
     where <T::Region as FromStr>::Err: Debug
     {
         fn build(&self) {
-            PalletStorageVersion::<T>::put(StorageVersion::default());
             Pallet::<T>::initialize_legal_officers(&self.legal_officers);
         }
     }
@@ -215,7 +201,7 @@ pub mod pallet {
 
         #[cfg(feature = "try-runtime")]
         fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
-            assert_eq!(PalletStorageVersion::<T>::get(), StorageVersion::default());
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), STORAGE_VERSION);
             Ok(())
         }
     }
@@ -259,6 +245,8 @@ pub mod pallet {
 		BaseUrlTooLong,
 		/// The PeerId is too long
 		PeerIdTooLong,
+		/// The LO still owns LOCs and cannot be removed
+		LegalOfficerOwnsLocs,
 	}
 
     #[pallet::call]
@@ -294,6 +282,8 @@ pub mod pallet {
                 Err(Error::<T>::NotFound)?
             } else if Self::host_has_guest(&legal_officer_id) {
                 Err(Error::<T>::HostHasGuest)?
+            } else if T::LocOwnership::owns_locs(&legal_officer_id) {
+                Err(Error::<T>::LegalOfficerOwnsLocs)?
             } else {
                 <LegalOfficerSet<T>>::remove(&legal_officer_id);
                 Self::try_reset_legal_officer_nodes(&to_remove.unwrap())?;
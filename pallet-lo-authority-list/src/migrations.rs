@@ -1,10 +1,10 @@
-use frame_support::traits::Get;
+use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
 use frame_support::weights::Weight;
 
-use crate::{Config, PalletStorageVersion, pallet::StorageVersion};
+use crate::Config;
 
 pub mod v5 {
-    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::migrations::VersionedMigration;
     use super::*;
     use crate::*;
 
@@ -34,56 +34,120 @@ pub mod v5 {
         <T as Config>::MaxBaseUrlLen,
     >;
 
-    pub struct AddImported<T>(sp_std::marker::PhantomData<T>);
+    #[frame_support::storage_alias]
+    pub type LegalOfficerSet<T: Config> = StorageMap<
+        Pallet<T>,
+        frame_support::Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        LegalOfficerDataV4Of<T>,
+    >;
+
+    /// Adds the `imported` flag to legal officers, executed only if the on-chain storage version is 4.
+    pub type AddImported<T> = VersionedMigration<
+        4,
+        5,
+        AddImportedUnversioned<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    pub struct AddImportedUnversioned<T>(sp_std::marker::PhantomData<T>);
 
-    impl<T: Config> OnRuntimeUpgrade for AddImported<T> {
+    impl<T: Config> OnRuntimeUpgrade for AddImportedUnversioned<T> {
 
         fn on_runtime_upgrade() -> Weight {
-            super::do_storage_upgrade::<T, _>(
-                StorageVersion::V4Region,
-                StorageVersion::V5Imported,
-                "AddImported",
-                || {
-                    let mut number_translated = 0;
-                    LegalOfficerSet::<T>::translate_values(|legal_officer: LegalOfficerDataV4Of<T>| {
-                        let translated = match legal_officer {
-                            LegalOfficerDataV4::Host(host_data) => LegalOfficerData::Host(HostData {
-                                node_id: host_data.node_id,
-                                base_url: host_data.base_url,
-                                region: host_data.region,
-                                imported: false,
-                            }),
-                            LegalOfficerDataV4::Guest(account_id) => LegalOfficerData::Guest(GuestData {
-                                host_id: account_id,
-                                imported: false,
-                            }),
-                        };
-                        number_translated += 1;
-                        Some(translated)
-                    });
-                    T::DbWeight::get().reads_writes(number_translated, number_translated)
-                }
-            )
+            let mut number_translated = 0;
+            crate::LegalOfficerSet::<T>::translate_values(|legal_officer: LegalOfficerDataV4Of<T>| {
+                let translated = match legal_officer {
+                    LegalOfficerDataV4::Host(host_data) => LegalOfficerData::Host(HostData {
+                        node_id: host_data.node_id,
+                        base_url: host_data.base_url,
+                        region: host_data.region,
+                        imported: false,
+                    }),
+                    LegalOfficerDataV4::Guest(account_id) => LegalOfficerData::Guest(GuestData {
+                        host_id: account_id,
+                        imported: false,
+                    }),
+                };
+                number_translated += 1;
+                Some(translated)
+            });
+            T::DbWeight::get().reads_writes(number_translated, number_translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let count = LegalOfficerSet::<T>::iter_keys().count() as u64;
+            frame_support::ensure!(LegalOfficerSet::<T>::iter_values().count() as u64 == count, "Some legal officers cannot be decoded");
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let count: u64 = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            frame_support::ensure!(crate::LegalOfficerSet::<T>::iter_keys().count() as u64 == count, "Some legal officers were lost");
+            frame_support::ensure!(crate::LegalOfficerSet::<T>::iter_values().count() as u64 == count, "Some legal officers cannot be decoded");
+            Ok(())
         }
     }
 }
 
-#[allow(dead_code)]
-fn do_storage_upgrade<T: Config, F>(expected_version: StorageVersion, target_version: StorageVersion, migration_name: &str, migration: F) -> Weight
-where F: FnOnce() -> Weight {
-    let storage_version = PalletStorageVersion::<T>::get();
-    if storage_version == expected_version {
-        let weight = migration();
-
-        PalletStorageVersion::<T>::set(target_version);
-        log::info!("✅ {:?} migration successfully executed", migration_name);
-        weight
-    } else {
-        if storage_version != target_version {
-            log::warn!("❗ {:?} cannot run migration with storage version {:?} (expected {:?})", migration_name, storage_version, expected_version);
-        } else {
-            log::info!("❎ {:?} execution skipped, already at target version {:?}", migration_name, target_version);
+/// Replaces the legacy `PalletStorageVersion` value with FRAME's on-chain storage version.
+///
+/// Must be executed before any other migration of the pallet.
+pub struct MigrateToFrameStorageVersion<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToFrameStorageVersion<T> {
+
+    fn on_runtime_upgrade() -> Weight {
+        match legacy::PalletStorageVersion::<T>::take() {
+            Some(legacy_index) => {
+                let version = legacy::to_storage_version(legacy_index);
+                version.put::<crate::Pallet<T>>();
+                log::info!("✅ Legacy storage version index {} replaced by version {:?}", legacy_index, version);
+                T::DbWeight::get().reads_writes(1, 2)
+            },
+            None => {
+                log::info!("❎ No legacy storage version, migration skipped");
+                T::DbWeight::get().reads(1)
+            },
         }
-        T::DbWeight::get().reads(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::DispatchError> {
+        use codec::Encode;
+        if legacy::PalletStorageVersion::<T>::exists() {
+            frame_support::ensure!(legacy::PalletStorageVersion::<T>::get().is_some(), "Legacy storage version cannot be decoded");
+        }
+        Ok(legacy::PalletStorageVersion::<T>::get().encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        use codec::Decode;
+        let legacy_index: Option<u8> = Decode::decode(&mut &state[..])
+            .map_err(|_| "Invalid pre-upgrade state")?;
+        frame_support::ensure!(!legacy::PalletStorageVersion::<T>::exists(), "Legacy storage version was not removed");
+        if let Some(legacy_index) = legacy_index {
+            frame_support::ensure!(StorageVersion::get::<crate::Pallet<T>>() == legacy::to_storage_version(legacy_index), "Unexpected storage version");
+        }
+        Ok(())
+    }
+}
+
+pub mod legacy {
+    use crate::{Config, Pallet};
+
+    /// The legacy storage version. It was stored as the SCALE encoding of a fieldless enum whose variants were
+    /// named `V1`, `V2...`, etc., i.e. the index of the variant.
+    #[frame_support::storage_alias]
+    pub type PalletStorageVersion<T: Config> = StorageValue<Pallet<T>, u8>;
+
+    /// The FRAME storage version matching a legacy version index i.e. the index of `V<n>...` becomes `n`.
+    pub fn to_storage_version(legacy_index: u8) -> frame_support::traits::StorageVersion {
+        frame_support::traits::StorageVersion::new(legacy_index as u16 + 1)
     }
 }
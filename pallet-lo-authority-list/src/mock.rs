@@ -8,6 +8,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup}, BuildStorage,
 };
 use crate::weights::SubstrateWeight;
+use logion_shared::LocOwnership;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -92,6 +93,15 @@ parameter_types! {
 	pub const MaxPeerIdLength: u32 = 48;
 }
 
+pub const LEGAL_OFFICER_WITH_LOCS: u64 = 6;
+
+pub struct LocOwnershipMock;
+impl LocOwnership<u64> for LocOwnershipMock {
+    fn owns_locs(legal_officer: &u64) -> bool {
+        *legal_officer == LEGAL_OFFICER_WITH_LOCS
+    }
+}
+
 impl pallet_lo_authority_list::Config for Test {
     type AddOrigin = EnsureRoot<u64>;
     type RemoveOrigin = EnsureRoot<u64>;
@@ -102,6 +112,7 @@ impl pallet_lo_authority_list::Config for Test {
 	type MaxBaseUrlLen = MaxBaseUrlLen;
 	type MaxNodes = MaxNodes;
 	type MaxPeerIdLength = MaxPeerIdLength;
+	type LocOwnership = LocOwnershipMock;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,4 +1,6 @@
 use crate::{mock::*, LegalOfficerData, Error, LegalOfficerDataOf, HostDataOf, LegalOfficerDataParam, HostDataParam, LegalOfficerDataParamOf, BoundedPeerId, HostData, HostDataParamOf, GuestData};
+use crate::migrations::{legacy, MigrateToFrameStorageVersion};
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
 use frame_support::{assert_err, assert_ok};
 use logion_shared::IsLegalOfficer;
 use sp_core::OpaquePeerId;
@@ -348,6 +350,14 @@ fn it_fails_removing_host_with_guests() {
     });
 }
 
+#[test]
+fn it_fails_removing_legal_officer_owning_locs() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(LoAuthorityList::add_legal_officer(RuntimeOrigin::root(), LEGAL_OFFICER_WITH_LOCS, Default::default()));
+        assert_err!(LoAuthorityList::remove_legal_officer(RuntimeOrigin::root(), LEGAL_OFFICER_WITH_LOCS), Error::<Test>::LegalOfficerOwnsLocs);
+    });
+}
+
 #[test]
 fn it_fails_adding_guest_with_guest() {
     new_test_ext().execute_with(|| {
@@ -616,3 +626,15 @@ fn it_fails_import_guest_twice() {
         );
     });
 }
+
+#[test]
+fn it_replaces_legacy_storage_version() {
+    new_test_ext().execute_with(|| {
+        legacy::PalletStorageVersion::<Test>::put(4); // Index of V5Imported
+
+        MigrateToFrameStorageVersion::<Test>::on_runtime_upgrade();
+
+        assert!(!legacy::PalletStorageVersion::<Test>::exists());
+        assert_eq!(StorageVersion::get::<LoAuthorityList>(), StorageVersion::new(5));
+    });
+}
@@ -32,18 +32,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(101))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:101 w:1)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: Some(2185), added: 4660, mode: `MaxEncodedLen`)
-	/// Storage: `LoAuthorityList::LegalOfficerNodes` (r:0 w:1)
-	/// Proof: `LoAuthorityList::LegalOfficerNodes` (`max_values`: Some(1), `max_size`: Some(13002), added: 13497, mode: `MaxEncodedLen`)
 	fn remove_legal_officer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `9400`
-		//  Estimated: `471650`
-		// Minimum execution time: 598_565_000 picoseconds.
-		Weight::from_parts(621_236_000, 0)
+		Weight::from_parts(625_481_000, 0)
 			.saturating_add(Weight::from_parts(0, 471650))
-			.saturating_add(T::DbWeight::get().reads(101))
+			.saturating_add(T::DbWeight::get().reads(102))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:101 w:1)
@@ -2,13 +2,13 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
-use crate::{ItemsParams, MetadataItemParamsOf, FileParamsOf, LocLinkParamsOf, Pallet as LogionLoc};
+use crate::{FeePayer, ItemsParams, MetadataItemParamsOf, FileParamsOf, LocLinkParamsOf, Pallet as LogionLoc};
 
 use frame_benchmarking::{account, impl_benchmark_test_suite, v2::*, BenchmarkError};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
 
-use logion_shared::IsLegalOfficer;
+use logion_shared::{IsLegalOfficer, LocOwnership};
 
 use sp_core::{Get, hash::H256};
 use sp_io::hashing::sha2_256;
@@ -263,6 +263,39 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// Benchmark `add_items` extrinsic with the worst possible conditions:
+	// * All items are added to an empty LOC by its requester.
+	#[benchmark]
+	fn add_items(
+		m: Linear<0, { T::MaxLocMetadata::get() }>,
+		f: Linear<0, { T::MaxLocFiles::get() }>,
+		l: Linear<0, { T::MaxLocLinks::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+		create_locs_to_link_to::<T>(&requester); // Targets of the many links
+
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(T::MaxLocLinks::get());
+		create_loc::<T>(loc_id, &legal_officer_id, &requester);
+		let items = ItemsParams {
+			metadata: (0..m).map(|i| metadata_item::<T>(i, &requester)).collect(),
+			files: (0..f).map(|i| file::<T>(i, &requester)).collect(),
+			links: (0..l).map(|i| loc_link::<T>(i, &requester)).collect(),
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			items,
+			FeePayer::Requester,
+		);
+
+		Ok(())
+	}
+
 	// Benchmark `make_void` extrinsic.
 	#[benchmark]
 	fn make_void() -> Result<(), BenchmarkError> {
@@ -278,6 +311,107 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// Benchmark `make_void_with_refund` extrinsic with the worst possible conditions:
+	// * Max co-owners, each one refunding its share of the legal fee.
+	#[benchmark]
+	fn make_void_with_refund() -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let co_owners: Vec<T::AccountId> = T::IsLegalOfficer::legal_officers().into_iter()
+			.filter(|legal_officer| *legal_officer != legal_officer_id)
+			.take(T::MaxLocCoOwners::get() as usize)
+			.collect();
+		if co_owners.is_empty() {
+			return Err(BenchmarkError::Stop("At least 2 legal officers are required"));
+		}
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(0);
+		assert_ok!(LogionLoc::<T>::create_polkadot_co_owned_transaction_loc(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+			loc_id,
+			legal_officer_id.clone(),
+			co_owners.clone(),
+			1,
+			1000u32.into(),
+			ItemsParams {
+				metadata: Vec::new(),
+				files: Vec::new(),
+				links: Vec::new(),
+			},
+		));
+		ensure_enough_funds::<T>(&legal_officer_id);
+		for co_owner in co_owners.iter() {
+			ensure_enough_funds::<T>(co_owner);
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(legal_officer_id.clone()),
+			loc_id,
+		);
+
+		assert!(LogionLoc::<T>::loc(loc_id).unwrap().void_info.is_some());
+
+		Ok(())
+	}
+
+	// Benchmark `release_storage_deposits` extrinsic with the worst possible conditions:
+	// * Each released entry is a collection item with max files and max versions.
+	#[benchmark]
+	fn release_storage_deposits(n: Linear<1, { T::MaxCollectionItemsPerBatch::get() }>) -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_collection_loc::<T>();
+		let legal_officer_id = any_legal_officer::<T>();
+		assert_ok!(LogionLoc::<T>::close(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id.clone())),
+			loc_id,
+			None,
+			false,
+		));
+		let mut items = Vec::with_capacity(n as usize);
+		for i in 0..n {
+			items.push(CollectionItemParams {
+				item_id: T::CollectionItemIdFactory::collection_item_id(i as u8),
+				item_description: T::Hasher::hash(&Vec::from([0u8])),
+				item_files: max_item_files::<T>(),
+				item_token: None,
+				restricted_delivery: false,
+				terms_and_conditions: Vec::new(),
+				native_token: None,
+			});
+		}
+		let items: BoundedVec<CollectionItemParamsOf<T>, T::MaxCollectionItemsPerBatch> = items.try_into().unwrap();
+		assert_ok!(LogionLoc::<T>::add_collection_items(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+			loc_id,
+			items,
+		));
+		for i in 0..n {
+			for j in 0..T::MaxCollectionItemVersions::get() {
+				assert_ok!(LogionLoc::<T>::update_collection_item(
+					<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+					loc_id,
+					T::CollectionItemIdFactory::collection_item_id(i as u8),
+					T::Hasher::hash(&Vec::from([j as u8])),
+					max_item_files::<T>(),
+				));
+			}
+		}
+		assert_ok!(LogionLoc::<T>::make_void(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id.clone())),
+			loc_id,
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(legal_officer_id),
+			loc_id,
+			n,
+		);
+
+		Ok(())
+	}
+
 	// Benchmark `make_void_and_replace` extrinsic.
 	#[benchmark]
 	fn make_void_and_replace() -> Result<(), BenchmarkError> {
@@ -344,8 +478,49 @@ mod benchmarks {
 			None,
 			false,
 			max_item_tcs::<T>(),
+			None,
+		);
+
+		Ok(())
+	}
+
+	// Benchmark `add_collection_items` extrinsic with the worst possible conditions:
+	// * Max number of files per item
+	// * Max number of T&C elements per item
+	#[benchmark]
+	fn add_collection_items(n: Linear<1, { T::MaxCollectionItemsPerBatch::get() }>) -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_collection_loc::<T>();
+		let legal_officer_id = any_legal_officer::<T>();
+		assert_ok!(LogionLoc::<T>::close(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id)),
+			loc_id,
+			None,
+			false,
+		));
+
+		let mut items = Vec::with_capacity(n as usize);
+		for i in 0..n {
+			items.push(CollectionItemParams {
+				item_id: T::CollectionItemIdFactory::collection_item_id(i as u8),
+				item_description: T::Hasher::hash(&Vec::from([0u8])),
+				item_files: max_item_files::<T>(),
+				item_token: None,
+				restricted_delivery: false,
+				terms_and_conditions: max_item_tcs::<T>(),
+				native_token: None,
+			});
+		}
+		let items: BoundedVec<CollectionItemParamsOf<T>, T::MaxCollectionItemsPerBatch> = items.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			items,
 		);
 
+		assert_eq!(LogionLoc::<T>::collection_size(&loc_id), Some(n));
+
 		Ok(())
 	}
 
@@ -511,8 +686,89 @@ mod benchmarks {
 			record_id,
 			description,
 			max_tokens_record_files::<T>(),
+			false,
+		);
+
+		Ok(())
+	}
+
+	// Benchmark `add_tokens_record_with_targets` extrinsic with the worst possible conditions:
+	// * Max files
+	// * Superseding another record
+	#[benchmark]
+	fn add_tokens_record_with_targets(t: Linear<0, { T::MaxTokensRecordTargets::get() }>) -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(0);
+		assert_ok!(LogionLoc::<T>::create_collection_loc(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+			loc_id,
+			legal_officer_id.clone(),
+			None,
+			Some(100),
+			true,
+			0u32.into(),
+			0u32.into(),
+			0u32.into(),
+			0u32.into(),
+			ItemsParams {
+				metadata: Vec::new(),
+				files: Vec::new(),
+				links: Vec::new(),
+			},
+		));
+		assert_ok!(LogionLoc::<T>::close(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id.clone())),
+			loc_id,
+			None,
+			false,
+		));
+
+		let mut target_items = Vec::with_capacity(t as usize);
+		for i in 0..t {
+			let item_id = T::CollectionItemIdFactory::collection_item_id(i as u8);
+			assert_ok!(LogionLoc::<T>::add_collection_item(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+				loc_id,
+				item_id,
+				T::Hasher::hash(&Vec::from([0u8])),
+				Vec::new(),
+				None,
+				false,
+				Vec::new(),
+			));
+			target_items.push(item_id);
+		}
+		let superseded_record_id: T::TokensRecordId = T::TokensRecordIdFactory::tokens_record_id(1);
+		assert_ok!(LogionLoc::<T>::add_tokens_record(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+			loc_id,
+			superseded_record_id,
+			T::Hasher::hash(&Vec::from([1u8])),
+			max_tokens_record_files::<T>(),
+			false,
+		));
+
+		let record_id: T::TokensRecordId = T::TokensRecordIdFactory::tokens_record_id(0);
+		let description = T::Hasher::hash(&Vec::from([0u8]));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			record_id,
+			description,
+			max_tokens_record_files::<T>(),
+			false,
+			target_items,
+			Some(superseded_record_id),
 		);
 
+		assert_eq!(LogionLoc::<T>::tokens_record_supersedes(&loc_id, &record_id), Some(superseded_record_id));
+
 		Ok(())
 	}
 
@@ -614,7 +870,7 @@ mod benchmarks {
 		_(
 			RawOrigin::Signed(legal_officer_id.clone()),
 			loc_id,
-			T::Hasher::hash(&Vec::from([(T::MaxLocMetadata::get() - 1) as u8])),
+			T::Hasher::hash(&(T::MaxLocMetadata::get() - 1).to_le_bytes().to_vec()),
 		);
 
 		Ok(())
@@ -648,7 +904,7 @@ mod benchmarks {
 		_(
 			RawOrigin::Signed(legal_officer_id.clone()),
 			loc_id,
-			T::Hasher::hash(&Vec::from([(T::MaxLocFiles::get() - 1) as u8])),
+			T::Hasher::hash(&(T::MaxLocFiles::get() - 1).to_le_bytes().to_vec()),
 		);
 
 		Ok(())
@@ -689,16 +945,72 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// Benchmark `acknowledge_items` extrinsic with the worst possible conditions:
+	// * LOC with max files and max co-owners, each acknowledgement counting the acknowledgements of all owners.
+	// * Acknowledge the last n items.
+	#[benchmark]
+	fn acknowledge_items(n: Linear<1, { T::MaxLocFiles::get() }>) -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let co_owners: Vec<T::AccountId> = T::IsLegalOfficer::legal_officers().into_iter()
+			.filter(|legal_officer| *legal_officer != legal_officer_id)
+			.take(T::MaxLocCoOwners::get() as usize)
+			.collect();
+		if co_owners.is_empty() {
+			return Err(BenchmarkError::Stop("At least 2 legal officers are required"));
+		}
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(0);
+		let items = ItemsParams {
+			metadata: Vec::new(),
+			files: max_files::<T>(&requester),
+			links: Vec::new(),
+		};
+		assert_ok!(LogionLoc::<T>::create_polkadot_co_owned_transaction_loc(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+			loc_id,
+			legal_officer_id.clone(),
+			co_owners,
+			1,
+			0u32.into(),
+			items,
+		));
+		let files = (T::MaxLocFiles::get() - n .. T::MaxLocFiles::get())
+			.map(|i| T::Hasher::hash(&i.to_le_bytes().to_vec()))
+			.collect();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(legal_officer_id.clone()),
+			loc_id,
+			Vec::new(),
+			files,
+			Vec::new(),
+		);
+
+		Ok(())
+	}
+
 	// Benchmark `close` extrinsic with the worst possible conditions:
-	// * LOC with max items.
+	// * LOC with n items, metadata first then files and links.
 	// * With auto-ack.
 	#[benchmark]
-	fn close() -> Result<(), BenchmarkError> {
+	fn close(
+		n: Linear<0, { T::MaxLocMetadata::get() + T::MaxLocFiles::get() + T::MaxLocLinks::get() }>,
+	) -> Result<(), BenchmarkError> {
 		let legal_officer_id = any_legal_officer::<T>();
 		let requester: T::AccountId = account("requester", 1, SEED);
 		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
 		ensure_enough_funds::<T>(&requester);
-		let items = max_items::<T>(&requester);
+		let mut items = max_items::<T>(&requester);
+		let metadata = n.min(T::MaxLocMetadata::get());
+		let files = (n - metadata).min(T::MaxLocFiles::get());
+		let links = n - metadata - files;
+		items.metadata.truncate(metadata as usize);
+		items.files.truncate(files as usize);
+		items.links.truncate(links as usize);
 		let next_loc_id: T::LocId = T::LocIdFactory::loc_id(next_loc_id::<T>());
 
 		assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(
@@ -852,6 +1164,59 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// Benchmark `import_tokens_record_with_targets` extrinsic with the worst possible conditions:
+	// * Max files
+	// * Superseding another record
+	#[benchmark]
+	fn import_tokens_record_with_targets(t: Linear<0, { T::MaxTokensRecordTargets::get() }>) -> Result<(), BenchmarkError> {
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(0);
+		let requester: T::AccountId = account("requester", 1, SEED);
+
+		let mut target_items = Vec::with_capacity(t as usize);
+		for i in 0..t {
+			let item_id = T::CollectionItemIdFactory::collection_item_id(i as u8);
+			assert_ok!(LogionLoc::<T>::import_collection_item(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Root),
+				loc_id,
+				item_id,
+				T::Hasher::hash(&Vec::from([0u8])),
+				Vec::new(),
+				None,
+				false,
+				Vec::new(),
+			));
+			target_items.push(item_id);
+		}
+		let superseded_record_id: T::TokensRecordId = T::TokensRecordIdFactory::tokens_record_id(1);
+		assert_ok!(LogionLoc::<T>::import_tokens_record(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Root),
+			loc_id,
+			superseded_record_id,
+			T::Hasher::hash(&Vec::from([1u8])),
+			max_tokens_record_files::<T>(),
+			requester.clone(),
+		));
+
+		let record_id: T::TokensRecordId = T::TokensRecordIdFactory::tokens_record_id(0);
+		let description = T::Hasher::hash(&Vec::from([0u8]));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			loc_id,
+			record_id,
+			description,
+			max_tokens_record_files::<T>(),
+			requester,
+			target_items,
+			Some(superseded_record_id),
+		);
+
+		assert_eq!(LogionLoc::<T>::tokens_record_supersedes(&loc_id, &record_id), Some(superseded_record_id));
+
+		Ok(())
+	}
+
 	// Benchmark `import_invited_contributor_selection` extrinsic.
 	#[benchmark]
 	fn import_invited_contributor_selection() -> Result<(), BenchmarkError> {
@@ -925,46 +1290,561 @@ mod benchmarks {
 		Ok(())
 	}
 
-	impl_benchmark_test_suite! {
-		LogionLoc,
-		crate::mock::new_test_ext(),
-		crate::mock::Test,
-	}
-}
-
-fn any_legal_officer<T: pallet::Config>() -> T::AccountId {
-	let legal_officers = T::IsLegalOfficer::legal_officers();
-	legal_officers[0].clone()
-}
+	// Benchmark `delete_metadata` extrinsic with the worst possible conditions:
+	// * LOC has max metadata
+	#[benchmark]
+	fn delete_metadata() -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_empty_loc::<T>();
+		add_many_metadata::<T>(&loc_id, &requester, 0);
 
-const SEED: u32 = 0;
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			metadata_item::<T>(0, &requester).name,
+		);
 
-fn ensure_enough_funds<T: pallet::Config>(account_id: &T::AccountId) {
-	T::Currency::make_free_balance_be(account_id, BalanceOf::<T>::max_value());
-}
+		Ok(())
+	}
 
-fn max_items<T: pallet::Config>(requester: &T::AccountId) -> ItemsParamsOf<T> {
-	create_locs_to_link_to::<T>(requester);
+	// Benchmark `delete_file` extrinsic with the worst possible conditions:
+	// * LOC has max files
+	#[benchmark]
+	fn delete_file() -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_empty_loc::<T>();
+		add_many_files::<T>(&loc_id, &requester, 0);
+		ensure_enough_funds::<T>(&T::StorageFeeRefundAccount::get());
 
-	let metadata = max_metadata::<T>(requester);
-	let files = max_files::<T>(requester);
-	let links = max_loc_links::<T>(requester);
-	ItemsParams {
-		metadata,
-		files,
-		links,
-	}
-}
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			file::<T>(0, &requester).hash,
+		);
 
-fn max_metadata<T: pallet::Config>(requester: &T::AccountId) -> Vec<MetadataItemParamsOf<T>> {
-	let mut metadata: Vec<MetadataItemParamsOf<T>> = Vec::new();
-	for i in 0..T::MaxLocMetadata::get() {
-		metadata.push(metadata_item::<T>(i, requester));
+		Ok(())
 	}
-	metadata
-}
 
-fn max_files<T: pallet::Config>(requester: &T::AccountId) -> Vec<FileParamsOf<T>> {
+	// Benchmark `delete_link` extrinsic with the worst possible conditions:
+	// * LOC has max links
+	#[benchmark]
+	fn delete_link() -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+		create_locs_to_link_to::<T>(&requester);
+
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(T::MaxLocLinks::get() + 1);
+		create_loc::<T>(loc_id, &legal_officer_id, &requester);
+		add_many_links::<T>(&loc_id, &requester, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			loc_link::<T>(0, &requester).id,
+		);
+
+		Ok(())
+	}
+
+	// Benchmark `grant_fee_allowance` extrinsic.
+	#[benchmark]
+	fn grant_fee_allowance() -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_collection_loc::<T>();
+		let contributor: T::AccountId = account("invited_contributor", 1, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			contributor,
+			1u32.into(),
+		);
+
+		Ok(())
+	}
+
+	// Benchmark `revoke_fee_allowance` extrinsic.
+	#[benchmark]
+	fn revoke_fee_allowance() -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_collection_loc::<T>();
+		let contributor: T::AccountId = account("invited_contributor", 1, SEED);
+		assert_ok!(LogionLoc::<T>::grant_fee_allowance(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+			loc_id,
+			contributor.clone(),
+			1u32.into(),
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester.clone()),
+			loc_id,
+			contributor,
+		);
+
+		Ok(())
+	}
+
+	// Benchmark `register_template` extrinsic with the worst possible conditions:
+	// * Template with max metadata names, file natures and link natures.
+	#[benchmark]
+	fn register_template() -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let template_id = T::Hasher::hash(&"template".as_bytes().to_vec());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(legal_officer_id),
+			template_id,
+			LocType::Transaction,
+			max_template_hashes::<T>(T::MaxLocMetadata::get()),
+			max_template_hashes::<T>(T::MaxLocFiles::get()),
+			max_template_hashes::<T>(T::MaxLocLinks::get()),
+			0u32.into(),
+			0u32.into(),
+			0u32.into(),
+			0u32.into(),
+			None,
+			false,
+		);
+
+		assert!(LogionLoc::<T>::template(template_id).is_some());
+
+		Ok(())
+	}
+
+	// Benchmark `create_loc_from_template` extrinsic with the worst possible conditions:
+	// * Transaction LOC template.
+	// * LOC with max files, metadata and links.
+	#[benchmark]
+	fn create_loc_from_template() -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+		let items = max_items::<T>(&requester);
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(next_loc_id::<T>());
+		let template_id = T::Hasher::hash(&"template".as_bytes().to_vec());
+		assert_ok!(LogionLoc::<T>::register_template(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id.clone())),
+			template_id,
+			LocType::Transaction,
+			max_template_hashes::<T>(T::MaxLocMetadata::get()),
+			max_template_hashes::<T>(T::MaxLocFiles::get()),
+			max_template_hashes::<T>(T::MaxLocLinks::get()),
+			0u32.into(),
+			0u32.into(),
+			0u32.into(),
+			0u32.into(),
+			None,
+			false,
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester),
+			loc_id,
+			template_id,
+			items,
+		);
+
+		assert_eq!(LogionLoc::<T>::template_of_loc(loc_id), Some(template_id));
+
+		Ok(())
+	}
+
+	// Benchmark `create_polkadot_co_owned_transaction_loc` extrinsic with the worst possible conditions:
+	// * Max co-owners.
+	// * LOC with max files, metadata and links.
+	#[benchmark]
+	fn create_polkadot_co_owned_transaction_loc() -> Result<(), BenchmarkError> {
+		let legal_officer_id = any_legal_officer::<T>();
+		let co_owners: Vec<T::AccountId> = T::IsLegalOfficer::legal_officers().into_iter()
+			.filter(|legal_officer| *legal_officer != legal_officer_id)
+			.take(T::MaxLocCoOwners::get() as usize)
+			.collect();
+		if co_owners.is_empty() {
+			return Err(BenchmarkError::Stop("At least 2 legal officers are required"));
+		}
+		let threshold = co_owners.len() as u32 + 1;
+		let requester: T::AccountId = account("requester", 1, SEED);
+		create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+		ensure_enough_funds::<T>(&requester);
+		let items = max_items::<T>(&requester);
+		let loc_id: T::LocId = T::LocIdFactory::loc_id(next_loc_id::<T>());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester),
+			loc_id,
+			legal_officer_id,
+			co_owners,
+			threshold,
+			1000u32.into(),
+			items,
+		);
+
+		assert!(LogionLoc::<T>::co_ownership(loc_id).is_some());
+
+		Ok(())
+	}
+
+	// Benchmark `transfer_legal_officer_data` extrinsic with the worst possible conditions:
+	// * All owned LOCs are transferred in one call.
+	// * All verified issuer nominations are transferred in one call.
+	// * All sponsorships are pending.
+	#[benchmark]
+	fn transfer_legal_officer_data(
+		l: Linear<0, { T::MaxAccountLocs::get() - 1 }>,
+		i: Linear<0, 100>,
+		s: Linear<0, 100>,
+	) -> Result<(), BenchmarkError> {
+		let legal_officers = T::IsLegalOfficer::legal_officers();
+		if legal_officers.len() < 2 {
+			return Err(BenchmarkError::Stop("At least 2 legal officers are required"));
+		}
+		let from = legal_officers[0].clone();
+		let to = legal_officers[1].clone();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		ensure_enough_funds::<T>(&requester);
+		for i in 0..l {
+			assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+				T::LocIdFactory::loc_id(i),
+				from.clone(),
+				0u32.into(),
+				ItemsParams::empty(),
+			));
+		}
+		for j in 0..i {
+			let issuer: T::AccountId = account("issuer", j, SEED);
+			let identity_loc_id = T::LocIdFactory::loc_id(T::MaxAccountLocs::get() + j);
+			ensure_enough_funds::<T>(&issuer);
+			create_closed_polkadot_identity_loc::<T>(identity_loc_id, &from, &issuer);
+			assert_ok!(LogionLoc::<T>::nominate_issuer(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(from.clone())),
+				issuer,
+				identity_loc_id,
+			));
+		}
+		let mut sponsorship_ids = Vec::new();
+		for i in 0..s {
+			let sponsorship_id = T::SponsorshipIdFactory::sponsorship_id(i);
+			assert_ok!(LogionLoc::<T>::sponsor(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(from.clone())),
+				sponsorship_id,
+				SupportedAccountId::Other(OtherAccountId::Ethereum(T::EthereumAddressFactory::address(i as u8))),
+				from.clone(),
+			));
+			sponsorship_ids.push(sponsorship_id);
+		}
+		let origin = T::TransferOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			from.clone(),
+			to,
+			l,
+			i,
+			sponsorship_ids,
+		);
+
+		assert!(LogionLoc::<T>::verified_issuers(&from, &account::<T::AccountId>("issuer", 0, SEED)).is_none());
+
+		Ok(())
+	}
+
+	// Benchmark `revoke_collection_item` extrinsic.
+	#[benchmark]
+	fn revoke_collection_item() -> Result<(), BenchmarkError> {
+		let (loc_id, _, item_id) = setup_collection_item::<T>();
+		let legal_officer_id = any_legal_officer::<T>();
+		let reason = T::Hasher::hash(&Vec::from([1u8]));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(legal_officer_id),
+			loc_id,
+			item_id,
+			reason,
+		);
+
+		assert!(LogionLoc::<T>::is_collection_item_revoked(&loc_id, &item_id));
+
+		Ok(())
+	}
+
+	// Benchmark `update_collection_item` extrinsic with the max number of files.
+	#[benchmark]
+	fn update_collection_item() -> Result<(), BenchmarkError> {
+		let (loc_id, requester, item_id) = setup_collection_item::<T>();
+		let item_description = T::Hasher::hash(&Vec::from([1u8]));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester),
+			loc_id,
+			item_id,
+			item_description,
+			max_item_files::<T>(),
+		);
+
+		assert_eq!(LogionLoc::<T>::collection_item_latest_version(&loc_id, &item_id), Some(1));
+
+		Ok(())
+	}
+
+	// Benchmark `remove_collection_item` extrinsic with the max number of versions.
+	#[benchmark]
+	fn remove_collection_item() -> Result<(), BenchmarkError> {
+		let (loc_id, requester, item_id) = setup_collection_item::<T>();
+		for i in 0..T::MaxCollectionItemVersions::get() {
+			assert_ok!(LogionLoc::<T>::update_collection_item(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+				loc_id,
+				item_id,
+				T::Hasher::hash(&Vec::from([i as u8])),
+				max_item_files::<T>(),
+			));
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester),
+			loc_id,
+			item_id,
+		);
+
+		assert!(LogionLoc::<T>::collection_items(&loc_id, &item_id).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collection_item_owner() -> Result<(), BenchmarkError> {
+		let (loc_id, requester, item_id) = setup_collection_item::<T>();
+		let owner: T::AccountId = account("owner", 1, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(requester),
+			loc_id,
+			item_id,
+			owner.clone(),
+		);
+
+		assert_eq!(LogionLoc::<T>::collection_item_owner(&loc_id, &item_id), Some(owner));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_collection_item() -> Result<(), BenchmarkError> {
+		let (loc_id, item_id, owner) = setup_owned_collection_item::<T>();
+		let new_owner: T::AccountId = account("new_owner", 1, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner),
+			loc_id,
+			item_id,
+			new_owner.clone(),
+		);
+
+		assert_eq!(LogionLoc::<T>::collection_item_owner(&loc_id, &item_id), Some(new_owner));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_transfer_collection_item() -> Result<(), BenchmarkError> {
+		let (loc_id, item_id, _) = setup_owned_collection_item::<T>();
+		let new_owner: T::AccountId = account("new_owner", 1, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(any_legal_officer::<T>()),
+			loc_id,
+			item_id,
+			new_owner.clone(),
+		);
+
+		assert_eq!(LogionLoc::<T>::collection_item_owner(&loc_id, &item_id), Some(new_owner));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_tokens_record() -> Result<(), BenchmarkError> {
+		let (loc_id, requester) = setup_collection_loc::<T>();
+		let legal_officer_id = any_legal_officer::<T>();
+		assert_ok!(LogionLoc::<T>::close(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id.clone())),
+			loc_id,
+			None,
+			false,
+		));
+		let record_id: T::TokensRecordId = T::TokensRecordIdFactory::tokens_record_id(0);
+		assert_ok!(LogionLoc::<T>::add_tokens_record(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester)),
+			loc_id,
+			record_id,
+			T::Hasher::hash(&Vec::from([0u8])),
+			max_tokens_record_files::<T>(),
+			false,
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(legal_officer_id),
+			loc_id,
+			record_id,
+			T::Hasher::hash(&Vec::from([1u8])),
+		);
+
+		assert!(LogionLoc::<T>::tokens_record_revocation(&loc_id, &record_id).is_some());
+
+		Ok(())
+	}
+
+	// Benchmark `claim_restricted_delivery` extrinsic with the worst possible conditions:
+	// * Max number of versions
+	// * Claimed file in the latest version
+	// * Claimant is the registered owner
+	#[benchmark]
+	fn claim_restricted_delivery() -> Result<(), BenchmarkError> {
+		let (loc_id, item_id, owner) = setup_owned_restricted_collection_item::<T>();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		let mut file_hash = T::Hasher::hash(&Vec::from([0u8]));
+		for j in 0..T::MaxCollectionItemVersions::get() {
+			file_hash = T::Hasher::hash(&Vec::from([1u8, j as u8]));
+			assert_ok!(LogionLoc::<T>::update_collection_item(
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+				loc_id,
+				item_id,
+				T::Hasher::hash(&Vec::from([j as u8])),
+				Vec::from([ CollectionItemFile {
+					name: file_hash,
+					content_type: file_hash,
+					size: 0,
+					hash: file_hash,
+				} ]),
+			));
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner),
+			loc_id,
+			item_id,
+			file_hash,
+		);
+
+		Ok(())
+	}
+
+	// Benchmark `claim_tokens_record_access` extrinsic with the worst possible conditions:
+	// * Record targeting the item
+	// * Claimant is the registered owner
+	#[benchmark]
+	fn claim_tokens_record_access() -> Result<(), BenchmarkError> {
+		let (loc_id, item_id, owner) = setup_owned_restricted_collection_item::<T>();
+		let requester: T::AccountId = account("requester", 1, SEED);
+		let record_id: T::TokensRecordId = T::TokensRecordIdFactory::tokens_record_id(0);
+		assert_ok!(LogionLoc::<T>::add_tokens_record_with_targets(
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester)),
+			loc_id,
+			record_id,
+			T::Hasher::hash(&Vec::from([0u8])),
+			max_tokens_record_files::<T>(),
+			false,
+			Vec::from([ item_id ]),
+			None,
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner),
+			loc_id,
+			record_id,
+			item_id,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn mint_non_fungible_native_token() -> Result<(), BenchmarkError> {
+		let (loc_id, requester, item_id) = setup_collection_item::<T>();
+
+		#[block]
+		{
+			LogionLoc::<T>::mint_native_token(&loc_id, &item_id, NativeTokenKind::NonFungible, &requester, 1u32.into())?;
+		}
+
+		assert!(LogionLoc::<T>::collection_item_native_token(&loc_id, &item_id).is_some());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn mint_fungible_native_token() -> Result<(), BenchmarkError> {
+		let (loc_id, requester, item_id) = setup_collection_item::<T>();
+
+		#[block]
+		{
+			LogionLoc::<T>::mint_native_token(&loc_id, &item_id, NativeTokenKind::Fungible, &requester, 1000u32.into())?;
+		}
+
+		assert!(LogionLoc::<T>::collection_item_native_token(&loc_id, &item_id).is_some());
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite! {
+		LogionLoc,
+		crate::mock::new_test_ext(),
+		crate::mock::Test,
+	}
+}
+
+fn any_legal_officer<T: pallet::Config>() -> T::AccountId {
+	let legal_officers = T::IsLegalOfficer::legal_officers();
+	legal_officers[0].clone()
+}
+
+const SEED: u32 = 0;
+
+fn ensure_enough_funds<T: pallet::Config>(account_id: &T::AccountId) {
+	T::Currency::make_free_balance_be(account_id, BalanceOf::<T>::max_value());
+}
+
+fn max_items<T: pallet::Config>(requester: &T::AccountId) -> ItemsParamsOf<T> {
+	create_locs_to_link_to::<T>(requester);
+
+	let metadata = max_metadata::<T>(requester);
+	let files = max_files::<T>(requester);
+	let links = max_loc_links::<T>(requester);
+	ItemsParams {
+		metadata,
+		files,
+		links,
+	}
+}
+
+fn max_metadata<T: pallet::Config>(requester: &T::AccountId) -> Vec<MetadataItemParamsOf<T>> {
+	let mut metadata: Vec<MetadataItemParamsOf<T>> = Vec::new();
+	for i in 0..T::MaxLocMetadata::get() {
+		metadata.push(metadata_item::<T>(i, requester));
+	}
+	metadata
+}
+
+fn max_files<T: pallet::Config>(requester: &T::AccountId) -> Vec<FileParamsOf<T>> {
 	let mut files: Vec<FileParamsOf<T>> = Vec::new();
 	for i in 0..T::MaxLocFiles::get() {
 		files.push(file::<T>(i, requester));
@@ -982,16 +1862,16 @@ fn max_loc_links<T: pallet::Config>(requester: &T::AccountId) -> Vec<LocLinkPara
 
 fn metadata_item<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> MetadataItemParamsOf<T> {
 	MetadataItemParams {
-		name: T::Hasher::hash(&Vec::from([i as u8])),
-		value: T::Hasher::hash(&Vec::from([i as u8])),
+		name: T::Hasher::hash(&i.to_le_bytes().to_vec()),
+		value: T::Hasher::hash(&i.to_le_bytes().to_vec()),
 		submitter: SupportedAccountId::Polkadot(submitter.clone()),
 	}
 }
 
 fn file<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> FileParamsOf<T> {
 	FileParams {
-		hash: T::Hasher::hash(&Vec::from([i as u8])),
-		nature: T::Hasher::hash(&Vec::from([i as u8])),
+		hash: T::Hasher::hash(&i.to_le_bytes().to_vec()),
+		nature: T::Hasher::hash(&i.to_le_bytes().to_vec()),
 		submitter: SupportedAccountId::Polkadot(submitter.clone()),
 		size: 0,
 	}
@@ -1000,11 +1880,15 @@ fn file<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> FileParamsOf<T>
 fn loc_link<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> LocLinkParamsOf<T> {
 	LocLinkParams {
 		id: T::LocIdFactory::loc_id(i),
-		nature: T::Hasher::hash(&Vec::from([i as u8])),
+		nature: T::Hasher::hash(&i.to_le_bytes().to_vec()),
 		submitter: SupportedAccountId::Polkadot(submitter.clone()),
 	}
 }
 
+fn max_template_hashes<T: pallet::Config>(max: u32) -> Vec<<T as pallet::Config>::Hash> {
+	(0..max).map(|i| T::Hasher::hash(&i.to_le_bytes().to_vec())).collect()
+}
+
 fn create_locs_to_link_to<T: pallet::Config>(requester: &T::AccountId) {
 	let legal_officer_id = any_legal_officer::<T>();
 	for i in 0..T::MaxLocLinks::get() {
@@ -1068,6 +1952,105 @@ fn setup_empty_loc<T: pallet::Config>() -> (T::LocId, T::AccountId) {
 	(loc_id, requester)
 }
 
+fn setup_collection_loc<T: pallet::Config>() -> (T::LocId, T::AccountId) {
+	let legal_officer_id = any_legal_officer::<T>();
+	let requester: T::AccountId = account("requester", 1, SEED);
+	create_closed_polkadot_identity_loc::<T>(T::LocIdFactory::loc_id(requester_identity_loc::<T>()), &legal_officer_id, &requester);
+	ensure_enough_funds::<T>(&requester);
+	let loc_id: T::LocId = T::LocIdFactory::loc_id(0);
+	assert_ok!(LogionLoc::<T>::create_collection_loc(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+		loc_id,
+		legal_officer_id.clone(),
+		None,
+		Some(100),
+		true,
+		0u32.into(),
+		0u32.into(),
+		0u32.into(),
+		0u32.into(),
+		ItemsParams {
+			metadata: Vec::new(),
+			files: Vec::new(),
+			links: Vec::new(),
+		},
+	));
+	(loc_id, requester)
+}
+
+fn setup_owned_collection_item<T: pallet::Config>() -> (T::LocId, T::CollectionItemId, T::AccountId) {
+	let (loc_id, requester, item_id) = setup_collection_item::<T>();
+	let owner: T::AccountId = account("owner", 1, SEED);
+	assert_ok!(LogionLoc::<T>::set_collection_item_owner(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester)),
+		loc_id,
+		item_id,
+		owner.clone(),
+	));
+	(loc_id, item_id, owner)
+}
+
+fn setup_owned_restricted_collection_item<T: pallet::Config>() -> (T::LocId, T::CollectionItemId, T::AccountId) {
+	let (loc_id, requester) = setup_collection_loc::<T>();
+	let legal_officer_id = any_legal_officer::<T>();
+	assert_ok!(LogionLoc::<T>::close(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id)),
+		loc_id,
+		None,
+		false,
+	));
+	let item_id: T::CollectionItemId = T::CollectionItemIdFactory::collection_item_id(0);
+	let items: BoundedVec<CollectionItemParamsOf<T>, T::MaxCollectionItemsPerBatch> = Vec::from([ CollectionItemParams {
+		item_id,
+		item_description: T::Hasher::hash(&Vec::from([0u8])),
+		item_files: max_item_files::<T>(),
+		item_token: Some(CollectionItemToken {
+			token_type: T::Hasher::hash(&Vec::from([0u8])),
+			token_id: T::Hasher::hash(&Vec::from([0u8])),
+			token_issuance: 1u32.into(),
+		}),
+		restricted_delivery: true,
+		terms_and_conditions: Vec::new(),
+		native_token: None,
+	} ]).try_into().unwrap();
+	assert_ok!(LogionLoc::<T>::add_collection_items(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+		loc_id,
+		items,
+	));
+	let owner: T::AccountId = account("owner", 1, SEED);
+	assert_ok!(LogionLoc::<T>::set_collection_item_owner(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester)),
+		loc_id,
+		item_id,
+		owner.clone(),
+	));
+	(loc_id, item_id, owner)
+}
+
+fn setup_collection_item<T: pallet::Config>() -> (T::LocId, T::AccountId, T::CollectionItemId) {
+	let (loc_id, requester) = setup_collection_loc::<T>();
+	let legal_officer_id = any_legal_officer::<T>();
+	assert_ok!(LogionLoc::<T>::close(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(legal_officer_id)),
+		loc_id,
+		None,
+		false,
+	));
+	let item_id: T::CollectionItemId = T::CollectionItemIdFactory::collection_item_id(0);
+	assert_ok!(LogionLoc::<T>::add_collection_item(
+		<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(requester.clone())),
+		loc_id,
+		item_id,
+		T::Hasher::hash(&Vec::from([0u8])),
+		Vec::new(),
+		None,
+		false,
+		Vec::new(),
+	));
+	(loc_id, requester, item_id)
+}
+
 fn add_many_metadata<T: pallet::Config>(loc_id: &T::LocId, requester: &T::AccountId, reserve: u32) {
 	for i in 0..T::MaxLocMetadata::get() - reserve {
 		assert_ok!(LogionLoc::<T>::add_metadata(
@@ -1159,11 +2142,14 @@ fn many_metadata_import<T: pallet::Config>(requester: &T::AccountId) -> Vec<Meta
 
 fn metadata_item_import<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> MetadataItemOf<T> {
 	MetadataItem {
-		name: T::Hasher::hash(&Vec::from([i as u8])),
-		value: T::Hasher::hash(&Vec::from([i as u8])),
+		name: T::Hasher::hash(&i.to_le_bytes().to_vec()),
+		value: T::Hasher::hash(&i.to_le_bytes().to_vec()),
 		submitter: SupportedAccountId::Polkadot(submitter.clone()),
 		acknowledged_by_owner: false,
 		acknowledged_by_verified_issuer: false,
+		submitted_on: None,
+		acknowledged_by_owner_on: None,
+		acknowledged_by_verified_issuer_on: None,
 	}
 }
 
@@ -1177,12 +2163,15 @@ fn many_files_import<T: pallet::Config>(requester: &T::AccountId) -> Vec<FileOf<
 
 fn file_import<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> FileOf<T> {
 	File {
-		hash: T::Hasher::hash(&Vec::from([i as u8])),
-		nature: T::Hasher::hash(&Vec::from([i as u8])),
+		hash: T::Hasher::hash(&i.to_le_bytes().to_vec()),
+		nature: T::Hasher::hash(&i.to_le_bytes().to_vec()),
 		submitter: SupportedAccountId::Polkadot(submitter.clone()),
 		size: 0,
 		acknowledged_by_owner: false,
 		acknowledged_by_verified_issuer: false,
+		submitted_on: None,
+		acknowledged_by_owner_on: None,
+		acknowledged_by_verified_issuer_on: None,
 	}
 }
 
@@ -1197,9 +2186,12 @@ fn many_loc_links_import<T: pallet::Config>(requester: &T::AccountId) -> Vec<Loc
 fn loc_link_import<T: pallet::Config>(i: u32, submitter: &T::AccountId) -> LocLinkOf<T> {
 	LocLink {
 		id: T::LocIdFactory::loc_id(i),
-		nature: T::Hasher::hash(&Vec::from([i as u8])),
+		nature: T::Hasher::hash(&i.to_le_bytes().to_vec()),
 		submitter: SupportedAccountId::Polkadot(submitter.clone()),
 		acknowledged_by_owner: false,
 		acknowledged_by_verified_issuer: false,
+		submitted_on: None,
+		acknowledged_by_owner_on: None,
+		acknowledged_by_verified_issuer_on: None,
 	}
 }
@@ -204,8 +204,8 @@ impl Fees {
             }));
         }
 
-        let percent_to_loc_owner: Percent = RecurentFeeDistributionKey::get().loc_owner_percent;
         if self.collection_item_fee > 0 {
+            let percent_to_loc_owner: Percent = CollectionItemFeeDistributionKey::get().loc_owner_percent;
             System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::CollectionItemFeeWithdrawn {
                     0: previous_balances.payer_account,
                     1: self.collection_item_fee,
@@ -215,6 +215,7 @@ impl Fees {
         }
 
         if self.tokens_record_fee > 0 {
+            let percent_to_loc_owner: Percent = TokensRecordFeeDistributionKey::get().loc_owner_percent;
             System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordFeeWithdrawn {
                     0: previous_balances.payer_account,
                     1: self.tokens_record_fee,
@@ -3,6 +3,7 @@
 pub use pallet::*;
 
 pub mod migrations;
+pub mod native_tokens;
 pub mod runtime_api;
 
 #[cfg(test)]
@@ -29,10 +30,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::Get;
 #[cfg(feature = "runtime-benchmarks")]
 use sp_core::H160;
-use sp_std::{
-    collections::btree_set::BTreeSet,
-    vec::Vec,
-};
+use sp_std::vec::Vec;
 use sp_runtime::traits::Zero;
 #[cfg(feature = "runtime-benchmarks")]
 use benchmarking::{
@@ -56,19 +54,69 @@ impl Default for LocType {
     }
 }
 
+impl LocType {
+    pub const ALL: [LocType; 3] = [LocType::Transaction, LocType::Identity, LocType::Collection];
+}
+
+/// The kinds of fees charged by the pallet, each one being distributed with its own key.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum FeeKind {
+    Storage,
+    Legal,
+    Certificate,
+    Value,
+    CollectionItem,
+    TokensRecord,
+}
+
+/// How storage of LOC data is paid.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum StoragePaymentMode {
+    /// A fee is withdrawn and distributed
+    Fee,
+    /// A deposit is reserved and released when data are removed
+    Deposit,
+}
+
+/// The account charged with the fees of an item added to a LOC.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum FeePayer {
+    /// The account signing the transaction
+    Signer,
+    /// The requester of the LOC, it must be a Polkadot account
+    Requester,
+    /// The sponsor of the LOC, only when the LOC was created with a sponsorship
+    Sponsor,
+}
+
+impl FeeKind {
+    pub const ALL: [FeeKind; 6] = [
+        FeeKind::Storage,
+        FeeKind::Legal,
+        FeeKind::Certificate,
+        FeeKind::Value,
+        FeeKind::CollectionItem,
+        FeeKind::TokensRecord,
+    ];
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct MetadataItem<AccountId, EthereumAddress, Hash> {
+pub struct MetadataItem<AccountId, EthereumAddress, Hash, BlockNumber> {
     name: Hash,
     value: Hash,
     submitter: SupportedAccountId<AccountId, EthereumAddress>,
     acknowledged_by_owner: bool,
     acknowledged_by_verified_issuer: bool,
+    submitted_on: Option<BlockNumber>,
+    acknowledged_by_owner_on: Option<BlockNumber>,
+    acknowledged_by_verified_issuer_on: Option<BlockNumber>,
 }
 
 pub type MetadataItemOf<T> = MetadataItem<
     <T as frame_system::Config>::AccountId,
     <T as pallet::Config>::EthereumAddress,
     <T as pallet::Config>::Hash,
+    BlockNumberFor<T>,
 >;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -85,12 +133,15 @@ pub type MetadataItemParamsOf<T> = MetadataItemParams<
 >;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct LocLink<LocId, Hash, AccountId, EthereumAddress> {
+pub struct LocLink<LocId, Hash, AccountId, EthereumAddress, BlockNumber> {
     id: LocId,
     nature: Hash,
     submitter: SupportedAccountId<AccountId, EthereumAddress>,
     acknowledged_by_owner: bool,
     acknowledged_by_verified_issuer: bool,
+    submitted_on: Option<BlockNumber>,
+    acknowledged_by_owner_on: Option<BlockNumber>,
+    acknowledged_by_verified_issuer_on: Option<BlockNumber>,
 }
 
 pub type LocLinkOf<T> = LocLink<
@@ -98,6 +149,7 @@ pub type LocLinkOf<T> = LocLink<
     <T as pallet::Config>::Hash,
     <T as frame_system::Config>::AccountId,
     <T as pallet::Config>::EthereumAddress,
+    BlockNumberFor<T>,
 >;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -114,22 +166,131 @@ pub type LocLinkParamsOf<T> = LocLinkParams<
 	<T as pallet::Config>::EthereumAddress,
 >;
 
+/// A constraint on the target of a link.
+#[derive(Clone, PartialEq, Eq, Debug, Copy)]
+pub enum LinkRule {
+    /// The target LOC must be closed
+    TargetClosed,
+    /// The target LOC must not be void
+    TargetNotVoid,
+    /// The target LOC must have the same owner as the source LOC
+    SameOwner,
+    /// The target LOC must be of the given type
+    TargetType(LocType),
+}
+
+/// Provides the rules a link must comply with, given its nature.
+pub trait LinkRules<Hash> {
+
+    fn rules(nature: &Hash) -> Vec<LinkRule>;
+}
+
+impl<Hash> LinkRules<Hash> for () {
+
+    fn rules(_nature: &Hash) -> Vec<LinkRule> {
+        Vec::new()
+    }
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct File<Hash, AccountId, EthereumAddress> {
+pub struct File<Hash, AccountId, EthereumAddress, BlockNumber> {
     hash: Hash,
     nature: Hash,
     submitter: SupportedAccountId<AccountId, EthereumAddress>,
     size: u32,
     acknowledged_by_owner: bool,
     acknowledged_by_verified_issuer: bool,
+    submitted_on: Option<BlockNumber>,
+    acknowledged_by_owner_on: Option<BlockNumber>,
+    acknowledged_by_verified_issuer_on: Option<BlockNumber>,
 }
 
 pub type FileOf<T> = File<
     <T as pallet::Config>::Hash,
     <T as frame_system::Config>::AccountId,
     <T as pallet::Config>::EthereumAddress,
+    BlockNumberFor<T>,
 >;
 
+trait AcknowledgeableItem<AccountId, EthereumAddress, BlockNumber> {
+    fn submitter(&self) -> &SupportedAccountId<AccountId, EthereumAddress>;
+    fn acknowledged_by_owner(&self) -> bool;
+    fn acknowledged_by_verified_issuer(&self) -> bool;
+    fn acknowledge(&mut self, by_owner: bool, block_number: BlockNumber);
+}
+
+impl<AccountId, EthereumAddress, Hash, BlockNumber> AcknowledgeableItem<AccountId, EthereumAddress, BlockNumber> for MetadataItem<AccountId, EthereumAddress, Hash, BlockNumber> {
+    fn submitter(&self) -> &SupportedAccountId<AccountId, EthereumAddress> {
+        &self.submitter
+    }
+
+    fn acknowledged_by_owner(&self) -> bool {
+        self.acknowledged_by_owner
+    }
+
+    fn acknowledged_by_verified_issuer(&self) -> bool {
+        self.acknowledged_by_verified_issuer
+    }
+
+    fn acknowledge(&mut self, by_owner: bool, block_number: BlockNumber) {
+        if by_owner {
+            self.acknowledged_by_owner = true;
+            self.acknowledged_by_owner_on = Some(block_number);
+        } else {
+            self.acknowledged_by_verified_issuer = true;
+            self.acknowledged_by_verified_issuer_on = Some(block_number);
+        }
+    }
+}
+
+impl<Hash, AccountId, EthereumAddress, BlockNumber> AcknowledgeableItem<AccountId, EthereumAddress, BlockNumber> for File<Hash, AccountId, EthereumAddress, BlockNumber> {
+    fn submitter(&self) -> &SupportedAccountId<AccountId, EthereumAddress> {
+        &self.submitter
+    }
+
+    fn acknowledged_by_owner(&self) -> bool {
+        self.acknowledged_by_owner
+    }
+
+    fn acknowledged_by_verified_issuer(&self) -> bool {
+        self.acknowledged_by_verified_issuer
+    }
+
+    fn acknowledge(&mut self, by_owner: bool, block_number: BlockNumber) {
+        if by_owner {
+            self.acknowledged_by_owner = true;
+            self.acknowledged_by_owner_on = Some(block_number);
+        } else {
+            self.acknowledged_by_verified_issuer = true;
+            self.acknowledged_by_verified_issuer_on = Some(block_number);
+        }
+    }
+}
+
+impl<LocId, Hash, AccountId, EthereumAddress, BlockNumber> AcknowledgeableItem<AccountId, EthereumAddress, BlockNumber> for LocLink<LocId, Hash, AccountId, EthereumAddress, BlockNumber> {
+    fn submitter(&self) -> &SupportedAccountId<AccountId, EthereumAddress> {
+        &self.submitter
+    }
+
+    fn acknowledged_by_owner(&self) -> bool {
+        self.acknowledged_by_owner
+    }
+
+    fn acknowledged_by_verified_issuer(&self) -> bool {
+        self.acknowledged_by_verified_issuer
+    }
+
+    fn acknowledge(&mut self, by_owner: bool, block_number: BlockNumber) {
+        if by_owner {
+            self.acknowledged_by_owner = true;
+            self.acknowledged_by_owner_on = Some(block_number);
+        } else {
+            self.acknowledged_by_verified_issuer = true;
+            self.acknowledged_by_verified_issuer_on = Some(block_number);
+        }
+    }
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct FileParams<Hash, AccountId, EthereumAddress> {
     hash: Hash,
@@ -144,6 +305,18 @@ pub type FileParamsOf<T> = FileParams<
 	<T as pallet::Config>::EthereumAddress,
 >;
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum LocItemKey<LocId, Hash> {
+    Metadata(Hash),
+    File(Hash),
+    Link(LocId),
+}
+
+pub type LocItemKeyOf<T> = LocItemKey<
+    <T as pallet::Config>::LocId,
+    <T as pallet::Config>::Hash,
+>;
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct ItemsParams<LocId, AccountId, EthereumAddress, Hash> {
     metadata: Vec<MetadataItemParams<AccountId, EthereumAddress, Hash>>,
@@ -194,10 +367,10 @@ pub type ItemsParamsOf<T> = ItemsParams<
 >;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct Items<LocId, AccountId, EthereumAddress, Hash> {
-    metadata: Vec<MetadataItem<AccountId, EthereumAddress, Hash>>,
-    files: Vec<File<Hash, AccountId, EthereumAddress>>,
-    links: Vec<LocLink<LocId, Hash, AccountId, EthereumAddress>>,
+pub struct Items<LocId, AccountId, EthereumAddress, Hash, BlockNumber> {
+    metadata: Vec<MetadataItem<AccountId, EthereumAddress, Hash, BlockNumber>>,
+    files: Vec<File<Hash, AccountId, EthereumAddress, BlockNumber>>,
+    links: Vec<LocLink<LocId, Hash, AccountId, EthereumAddress, BlockNumber>>,
 }
 
 pub type ItemsOf<T> = Items<
@@ -205,6 +378,7 @@ pub type ItemsOf<T> = Items<
     <T as frame_system::Config>::AccountId,
     <T as pallet::Config>::EthereumAddress,
     <T as pallet::Config>::Hash,
+    BlockNumberFor<T>,
 >;
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -251,15 +425,14 @@ impl<AccountId, EthereumAddress> Default for SupportedAccountId<AccountId, Ether
 pub type CollectionSize = u32;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance,
-	MaxLocMetadata: Get<u32>, MaxLocFiles: Get<u32>, MaxLocLinks: Get<u32>> {
+pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance> {
     owner: AccountId,
     requester: Requester<AccountId, LocId, EthereumAddress>,
-    metadata: BoundedVec<MetadataItem<AccountId, EthereumAddress, Hash>, MaxLocMetadata>,
-    files: BoundedVec<File<Hash, AccountId, EthereumAddress>, MaxLocFiles>,
+    metadata_count: u32,
+    files_count: u32,
     closed: bool,
     loc_type: LocType,
-    links: BoundedVec<LocLink<LocId, Hash, AccountId, EthereumAddress>, MaxLocLinks>,
+    links_count: u32,
     void_info: Option<LocVoidInfo<LocId>>,
     replacer_of: Option<LocId>,
     collection_last_block_submission: Option<BlockNumber>,
@@ -272,28 +445,18 @@ pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, EthereumAddress
     collection_item_fee: Balance,
     tokens_record_fee: Balance,
     imported: bool,
+    created_on: Option<BlockNumber>,
+    closed_on: Option<BlockNumber>,
+    voided_on: Option<BlockNumber>,
 }
 
-impl<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance, MaxLocMetadata, MaxLocFiles, MaxLocLinks>
-LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance, MaxLocMetadata, MaxLocFiles, MaxLocLinks>
+impl<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance>
+LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance>
 where
     AccountId: PartialEq + Clone,
-    Hash: PartialEq + Copy + Ord,
-    LocId: PartialEq + Copy + Ord,
     EthereumAddress: PartialEq + Clone,
-	MaxLocMetadata: Get<u32>,
-	MaxLocFiles: Get<u32>,
-	MaxLocLinks: Get<u32>,
 {
 
-    pub fn ensure_can_add<T: pallet::Config>(&self, items: &ItemsParams<LocId, AccountId, EthereumAddress, Hash>) -> Result<(), sp_runtime::DispatchError> {
-        self.ensure_requester_submits::<T>(&items)?;
-        self.ensure_can_add_metadata::<T>(&items.metadata.iter().map(|item| item.name).collect())?;
-        self.ensure_can_add_files::<T>(&items.files.iter().map(|item| item.hash).collect())?;
-        self.ensure_can_add_links::<T>(&items.links.iter().map(|item| item.id).collect())?;
-        Ok(())
-    }
-
     pub fn ensure_requester_submits<T: pallet::Config>(&self, items: &ItemsParams<LocId, AccountId, EthereumAddress, Hash>) -> Result<(), sp_runtime::DispatchError> {
         if items.metadata.iter().find(|item| !self.is_requester(&item.submitter)).is_some()
             || items.files.iter().find(|item| !self.is_requester(&item.submitter)).is_some()
@@ -321,126 +484,16 @@ where
         }
     }
 
-    pub fn ensure_can_add_metadata<T: pallet::Config>(&self, metadata_names: &Vec<Hash>) -> Result<(), sp_runtime::DispatchError> {
-        let mut keys = BTreeSet::new();
-        metadata_names.iter().for_each(|name| { keys.insert(*name); });
-        self.metadata.iter().for_each(|item| { keys.insert(item.name); });
-
-        if keys.len() < self.metadata.len() + metadata_names.len() {
-            Err(Error::<T>::DuplicateLocMetadata)?
-        }
-        Ok(())
-    }
-
-    pub fn ensure_can_add_files<T: pallet::Config>(&self, file_hashes: &Vec<Hash>) -> Result<(), sp_runtime::DispatchError> {
-        let mut keys = BTreeSet::new();
-        file_hashes.iter().for_each(|hash| { keys.insert(*hash); });
-        self.files.iter().for_each(|item| { keys.insert(item.hash); });
-
-        if keys.len() < self.files.len() + file_hashes.len() {
-            Err(Error::<T>::DuplicateLocFile)?
-        }
-        Ok(())
-    }
-
-    pub fn ensure_can_add_links<T: pallet::Config>(&self, link_ids: &Vec<LocId>) -> Result<(), sp_runtime::DispatchError> {
-        let mut keys = BTreeSet::new();
-        link_ids.iter().for_each(|id| { keys.insert(*id); });
-        self.links.iter().for_each(|item| { keys.insert(item.id); });
-
-        if keys.len() < self.links.len() + link_ids.len() {
-            Err(Error::<T>::DuplicateLocLink)?
-        }
-        Ok(())
-    }
-
-    pub fn add_items<T: pallet::Config>(&mut self, origin: &AccountId, items: &ItemsParams<LocId, AccountId, EthereumAddress, Hash>) -> Result<(), sp_runtime::DispatchError> {
-		for item in items.metadata.iter() {
-			self.add_metadata::<T>(origin, item)?;
-		}
-		for item in items.files.iter() {
-			self.add_file::<T>(origin, item)?;
-		}
-		for item in items.links.iter() {
-			self.add_link::<T>(origin, item)?;
-		}
-		Ok(())
-    }
-
-    pub fn add_metadata<T: pallet::Config>(&mut self, origin: &AccountId, item: &MetadataItemParams<AccountId, EthereumAddress, Hash>) -> Result<(), sp_runtime::DispatchError> {
-        self.metadata.try_push(MetadataItem {
-            name: item.name,
-            value: item.value,
-            submitter: item.submitter.clone(),
-            acknowledged_by_owner: self.is_owner(origin),
-            acknowledged_by_verified_issuer: false,
-        }).map_err(|_| Error::<T>::LocMetadataTooMuchData)?;
-		Ok(())
-    }
-
     pub fn is_owner(&self, origin: &AccountId) -> bool {
         self.owner == *origin
     }
 
-    pub fn add_file<T: pallet::Config>(&mut self, origin: &AccountId, file: &FileParams<Hash, AccountId, EthereumAddress>) -> Result<(), sp_runtime::DispatchError> {
-        self.files.try_push(File {
-            hash: file.hash,
-            nature: file.nature,
-            submitter: file.submitter.clone(),
-            size: file.size,
-            acknowledged_by_owner: self.is_owner(origin),
-            acknowledged_by_verified_issuer: false,
-		}).map_err(|_| Error::<T>::LocFilesTooMuchData)?;
-		Ok(())
-    }
-
-    pub fn add_link<T: pallet::Config>(&mut self, origin: &AccountId, link: &LocLinkParams<LocId, Hash, AccountId, EthereumAddress>) -> Result<(), sp_runtime::DispatchError> {
-        self.links.try_push(LocLink {
-            id: link.id,
-            nature: link.nature,
-            submitter: link.submitter.clone(),
-            acknowledged_by_owner: self.is_owner(origin),
-            acknowledged_by_verified_issuer: false,
-        }).map_err(|_| Error::<T>::LocLinksTooMuchData)?;
-		Ok(())
-    }
-
-    pub fn has_items_unacknowledged_by_owner(&self) -> bool {
-        self.files.iter().find(|file| { !file.acknowledged_by_owner }).is_some()
-            || self.metadata.iter().find(|item| { !item.acknowledged_by_owner }).is_some()
-            || self.links.iter().find(|link| { !link.acknowledged_by_owner }).is_some()
-    }
-
-    pub fn has_items_unacknowledged_by_verified_issuer(&self) -> bool {
-        self.files.iter().find(|file| { self.is_submitted_by_verified_issuer(&file.submitter) && !file.acknowledged_by_verified_issuer }).is_some()
-            || self.metadata.iter().find(|item| { self.is_submitted_by_verified_issuer(&item.submitter) && !item.acknowledged_by_verified_issuer }).is_some()
-            || self.links.iter().find(|link| { self.is_submitted_by_verified_issuer( &link.submitter) && !link.acknowledged_by_verified_issuer }).is_some()
-    }
-
-    fn is_submitted_by_verified_issuer(&self, submitter: &SupportedAccountId<AccountId, EthereumAddress>) -> bool {
+    pub fn is_submitted_by_verified_issuer(&self, submitter: &SupportedAccountId<AccountId, EthereumAddress>) -> bool {
         match submitter {
             SupportedAccountId::Polkadot(polkadot_submitter) => !self.is_owner(polkadot_submitter) && !self.is_requester(submitter),
             _ => false
         }
     }
-
-    pub fn ensure_can_import<T: pallet::Config>(&self, items: &Items<LocId, AccountId, EthereumAddress, Hash>) -> Result<(), sp_runtime::DispatchError> {
-        self.ensure_can_add_metadata::<T>(&items.metadata.iter().map(|item| item.name).collect())?;
-        Ok(())
-    }
-
-    pub fn import_items<T: pallet::Config>(&mut self, items: &Items<LocId, AccountId, EthereumAddress, Hash>) -> Result<(), sp_runtime::DispatchError> {
-        for item in items.metadata.iter() {
-            self.metadata.try_push(item.clone()).map_err(|_| Error::<T>::LocMetadataTooMuchData)?;
-        }
-        for item in items.files.iter() {
-            self.files.try_push(item.clone()).map_err(|_| Error::<T>::LocFilesTooMuchData)?;
-        }
-        for item in items.links.iter() {
-            self.links.try_push(item.clone()).map_err(|_| Error::<T>::LocLinksTooMuchData)?;
-        }
-        Ok(())
-    }
 }
 
 pub type LegalOfficerCaseOf<T> = LegalOfficerCase<
@@ -450,12 +503,56 @@ pub type LegalOfficerCaseOf<T> = LegalOfficerCase<
     BlockNumberFor<T>,
     <T as pallet::Config>::EthereumAddress,
     <T as pallet::Config>::SponsorshipId,
+    BalanceOf<T>,
+>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LocTemplate<AccountId, Hash, Balance, MaxLocMetadata: Get<u32>, MaxLocFiles: Get<u32>, MaxLocLinks: Get<u32>> {
+    owner: AccountId,
+    loc_type: LocType,
+    metadata_names: BoundedVec<Hash, MaxLocMetadata>,
+    file_natures: BoundedVec<Hash, MaxLocFiles>,
+    link_natures: BoundedVec<Hash, MaxLocLinks>,
+    legal_fee: Balance,
+    value_fee: Balance,
+    collection_item_fee: Balance,
+    tokens_record_fee: Balance,
+    collection_max_size: Option<u32>,
+    collection_can_upload: bool,
+}
+
+pub type LocTemplateOf<T> = LocTemplate<
+    <T as frame_system::Config>::AccountId,
+    <T as pallet::Config>::Hash,
     BalanceOf<T>,
 	<T as pallet::Config>::MaxLocMetadata,
 	<T as pallet::Config>::MaxLocFiles,
 	<T as pallet::Config>::MaxLocLinks,
 >;
 
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CoOwnership<AccountId, MaxLocCoOwners: Get<u32>> {
+    co_owners: BoundedVec<AccountId, MaxLocCoOwners>,
+    threshold: u32,
+}
+
+pub type CoOwnershipOf<T> = CoOwnership<
+    <T as frame_system::Config>::AccountId,
+    <T as pallet::Config>::MaxLocCoOwners,
+>;
+
+/// An action on a co-owned LOC requiring the approval of its owners.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum OwnerAction<Hash, LocId> {
+    Close { seal: Option<Hash>, auto_ack: bool },
+    Void { replacer: Option<LocId>, refund_legal_fee: bool },
+}
+
+pub type OwnerActionOf<T> = OwnerAction<
+    <T as pallet::Config>::Hash,
+    <T as pallet::Config>::LocId,
+>;
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct TermsAndConditionsElement<LocId, Hash> {
     tc_type: Hash,
@@ -491,6 +588,24 @@ pub type CollectionItemOf<T> = CollectionItem<
 	>,
 >;
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct CollectionItemParams<CollectionItemId, Hash, TokenIssuance, LocId> {
+    item_id: CollectionItemId,
+    item_description: Hash,
+    item_files: Vec<CollectionItemFile<Hash>>,
+    item_token: Option<CollectionItemToken<TokenIssuance, Hash>>,
+    restricted_delivery: bool,
+    terms_and_conditions: Vec<TermsAndConditionsElement<LocId, Hash>>,
+    native_token: Option<NativeTokenKind>,
+}
+
+pub type CollectionItemParamsOf<T> = CollectionItemParams<
+    <T as pallet::Config>::CollectionItemId,
+    <T as pallet::Config>::Hash,
+    <T as pallet::Config>::TokenIssuance,
+    <T as pallet::Config>::LocId,
+>;
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionItemFile<Hash> {
     name: Hash,
@@ -508,6 +623,101 @@ pub struct CollectionItemToken<TokenIssuance, Hash> {
     token_issuance: TokenIssuance,
 }
 
+/// Tells if an account holds the token underlying a collection item. Runtimes may implement it
+/// against their assets or NFT pallets, tokens living on other chains being out of its reach.
+pub trait TokenOwnershipOracle<AccountId, Hash, TokenIssuance> {
+
+    fn owns_token(account: &AccountId, token_type: &Hash, token_id: &Hash, token_issuance: &TokenIssuance) -> bool;
+}
+
+impl<AccountId, Hash, TokenIssuance> TokenOwnershipOracle<AccountId, Hash, TokenIssuance> for () {
+
+    fn owns_token(_account: &AccountId, _token_type: &Hash, _token_id: &Hash, _token_issuance: &TokenIssuance) -> bool {
+        false
+    }
+}
+
+/// The kind of token minted on the logion chain for a collection item.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum NativeTokenKind {
+    /// A single non-fungible token
+    NonFungible,
+    /// A fungible asset whose supply is the item's token issuance
+    Fungible,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct NativeToken<Hash> {
+    kind: NativeTokenKind,
+    id: Hash,
+}
+
+pub type NativeTokenOf<T> = NativeToken<<T as pallet::Config>::Hash>;
+
+/// Mints the native token of a collection item and tells who holds it. See [`native_tokens`] for
+/// implementations relying on the `nonfungibles_v2` and `fungibles` traits.
+pub trait NativeTokenMinter<AccountId, Hash, TokenIssuance> {
+
+    /// Mints a token with given ID, `owner` receiving the whole issuance.
+    fn mint(kind: NativeTokenKind, token_id: &Hash, owner: &AccountId, issuance: TokenIssuance) -> frame_support::dispatch::DispatchResult;
+
+    /// Tells if `account` holds the token with given ID, or a part of it for fungible tokens.
+    fn holds(kind: NativeTokenKind, token_id: &Hash, account: &AccountId) -> bool;
+}
+
+impl<AccountId, Hash, TokenIssuance> NativeTokenMinter<AccountId, Hash, TokenIssuance> for () {
+
+    fn mint(_kind: NativeTokenKind, _token_id: &Hash, _owner: &AccountId, _issuance: TokenIssuance) -> frame_support::dispatch::DispatchResult {
+        Err(sp_runtime::DispatchError::Other("Native tokens not supported"))
+    }
+
+    fn holds(_kind: NativeTokenKind, _token_id: &Hash, _account: &AccountId) -> bool {
+        false
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionItemVersion<Hash, BlockNumber, BoundedCollectionItemFilesList> {
+    description: Hash,
+    files: BoundedCollectionItemFilesList,
+    updated_on: BlockNumber,
+}
+
+pub type CollectionItemVersionOf<T> = CollectionItemVersion<
+    <T as pallet::Config>::Hash,
+    BlockNumberFor<T>,
+    BoundedVec<
+        CollectionItemFileOf<T>,
+        <T as pallet::Config>::MaxCollectionItemFiles
+    >,
+>;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionItemRevocation<AccountId, Hash, BlockNumber> {
+    revoked_by: AccountId,
+    reason: Hash,
+    revoked_on: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TokensRecordRevocation<AccountId, Hash, BlockNumber> {
+    revoked_by: AccountId,
+    reason: Hash,
+    revoked_on: BlockNumber,
+}
+
+pub type TokensRecordRevocationOf<T> = TokensRecordRevocation<
+    <T as frame_system::Config>::AccountId,
+    <T as pallet::Config>::Hash,
+    BlockNumberFor<T>,
+>;
+
+pub type CollectionItemRevocationOf<T> = CollectionItemRevocation<
+    <T as frame_system::Config>::AccountId,
+    <T as pallet::Config>::Hash,
+    BlockNumberFor<T>,
+>;
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct VerifiedIssuer<LocId> {
     identity_loc: LocId,
@@ -579,15 +789,18 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use frame_support::{
         dispatch::DispatchResultWithPostInfo,
-        pallet_prelude::*, traits::tokens::Balance,
+        pallet_prelude::*, traits::{tokens::Balance, ExistenceRequirement},
     };
-    use codec::HasCompact;
+    use codec::{FullCodec, HasCompact};
     use frame_support::traits::Currency;
     use logion_shared::{
-        LocQuery, LocValidity, IsLegalOfficer, RewardDistributor,
+        LocQuery, LocValidity, LocOwnership, IsLegalOfficer, RewardDistributor,
         DistributionKey, Beneficiary,
     };
     use crate::SupportedAccountId::Polkadot;
+    use sp_runtime::Percent;
+    use frame_support::weights::WeightMeter;
+    use crate::migrations::{SteppedMigration, MigrationStatus};
     use super::*;
     pub use crate::weights::WeightInfo;
 
@@ -620,13 +833,13 @@ pub mod pallet {
 		/// The maximum number of LOCs per account
 		type MaxAccountLocs: Get<u32>;
 
-        /// The maximum number of metadata items per LOC
+        /// The maximum number of metadata items per LOC (bounds the iterations over the items of a LOC, not the size of the LOC)
         type MaxLocMetadata: Get<u32> + TypeInfo;
 
-        /// The maximum number of files per LOC
+        /// The maximum number of files per LOC (bounds the iterations over the items of a LOC, not the size of the LOC)
         type MaxLocFiles: Get<u32> + TypeInfo;
 
-        /// The maximum number of links per LOC
+        /// The maximum number of links per LOC (bounds the iterations over the items of a LOC, not the size of the LOC)
         type MaxLocLinks: Get<u32> + TypeInfo;
 
         /// The maximum number of files per collection item
@@ -635,9 +848,21 @@ pub mod pallet {
         /// The maximum number of files per collection item
         type MaxCollectionItemTCs: Get<u32>;
 
+        /// The maximum number of collection items added in a single batch
+        type MaxCollectionItemsPerBatch: Get<u32>;
+
+        /// The maximum number of updates of a collection item
+        type MaxCollectionItemVersions: Get<u32>;
+
         /// The maximum number of files per token record
         type MaxTokensRecordFiles: Get<u32>;
 
+        /// The maximum number of collection items a tokens record may target
+        type MaxTokensRecordTargets: Get<u32>;
+
+        /// The maximum number of LOCs linking to a voided LOC reported by the `VoidedLocLinkedBy` event
+        type MaxReportedLinkingLocs: Get<u32>;
+
         /// The currency trait.
         type Currency: ReservableCurrency<Self::AccountId>;
 
@@ -653,6 +878,16 @@ pub mod pallet {
         /// Used to compute storage fees rewards
         type FileStorageFeeDistributionKey: Get<DistributionKey>;
 
+        /// Tells if storage is paid with a one-shot fee or with a deposit released when data are removed
+        type FileStoragePaymentMode: Get<StoragePaymentMode>;
+
+        /// The share of a file's storage deposit or fee given back when its submitter deletes it. Files deleted by
+        /// the LOC owner are fully refunded, like the files of a void LOC.
+        type DeletedFileStorageRefund: Get<Percent>;
+
+        /// The account storage fees of deleted files are refunded from
+        type StorageFeeRefundAccount: Get<Self::AccountId>;
+
         /// Ethereum Address type
         type EthereumAddress: Member + Parameter + Default + Copy + MaxEncodedLen;
 
@@ -686,6 +921,33 @@ pub mod pallet {
         /// Used to payout legal fees of a Collection LOC
         type CollectionLocLegalFeeDistributionKey: Get<DistributionKey>;
 
+        /// Validation rules applied to links, by link nature
+        type LinkRules: LinkRules<<Self as pallet::Config>::Hash>;
+
+        /// The maximum number of co-owners of a LOC, the main owner excluded
+        type MaxLocCoOwners: Get<u32> + TypeInfo;
+
+        /// The origin which can transfer the LOCs of a legal officer to another legal officer
+        type TransferOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Tells if the requester of a collection may revoke its items, collection owners always can
+        type RequesterCanRevokeCollectionItems: Get<bool>;
+
+        /// The number of blocks after its addition during which a collection item may be removed
+        type CollectionItemRemovalGracePeriod: Get<BlockNumberFor<Self>>;
+
+        /// The storage migration executed over several blocks after a runtime upgrade
+        type SteppedMigration: SteppedMigration;
+
+        /// The maximum weight a stepped migration may consume in a single block
+        type MaxMigrationWeightPerBlock: Get<Weight>;
+
+        /// Tells who holds the tokens of collection items, used to gate restricted deliveries and tokens records access
+        type TokenOwnershipOracle: TokenOwnershipOracle<Self::AccountId, <Self as pallet::Config>::Hash, Self::TokenIssuance>;
+
+        /// Mints the tokens of collection items on the logion chain itself, if requested on item addition
+        type NativeTokenMinter: NativeTokenMinter<Self::AccountId, <Self as pallet::Config>::Hash, Self::TokenIssuance>;
+
 		/// Loc ID factory for benchmark
 		#[cfg(feature = "runtime-benchmarks")]
 		type LocIdFactory: LocIdFactory<Self::LocId>;
@@ -707,7 +969,11 @@ pub mod pallet {
 		type SponsorshipIdFactory: SponsorshipIdFactory<Self::SponsorshipId>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(27);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// All LOCs indexed by ID.
@@ -715,6 +981,59 @@ pub mod pallet {
     #[pallet::getter(fn loc)]
     pub type LocMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseOf<T>>;
 
+	/// LOC metadata items by LOC ID and item name
+	#[pallet::storage]
+	#[pallet::getter(fn loc_metadata)]
+	pub type LocMetadataMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::Hash, // name
+		MetadataItemOf<T>,
+	>;
+
+	/// LOC files by LOC ID and file hash
+	#[pallet::storage]
+	#[pallet::getter(fn loc_file)]
+	pub type LocFilesMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::Hash, // hash
+		FileOf<T>,
+	>;
+
+	/// LOC links by LOC ID and target LOC ID
+	#[pallet::storage]
+	#[pallet::getter(fn loc_link)]
+	pub type LocLinksMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId, // source
+		Blake2_128Concat,
+		<T as Config>::LocId, // target
+		LocLinkOf<T>,
+	>;
+
+	/// The position of LOC items in order of addition, by LOC ID and item key
+	#[pallet::storage]
+	#[pallet::getter(fn loc_item_position)]
+	pub type LocItemPositionsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		LocItemKeyOf<T>,
+		u32,
+	>;
+
+	/// The position of the next item added to a LOC
+	#[pallet::storage]
+	#[pallet::getter(fn next_loc_item_position)]
+	pub type NextLocItemPositionMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, u32, ValueQuery>;
+
     /// Requested LOCs by account ID.
     #[pallet::storage]
     #[pallet::getter(fn account_locs)]
@@ -725,22 +1044,176 @@ pub mod pallet {
     #[pallet::getter(fn collection_items)]
     pub type CollectionItemsMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::CollectionItemId, CollectionItemOf<T>>;
 
-    /// Collection size by LOC ID.
-    #[pallet::storage]
-    #[pallet::getter(fn collection_size)]
-    pub type CollectionSizeMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, CollectionSize>;
+	/// Revoked collection items by LOC ID and item ID.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_revocation)]
+	pub type CollectionItemRevocationsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::CollectionItemId,
+		CollectionItemRevocationOf<T>,
+	>;
 
-    /// Collection tokens records by LOC ID and record ID.
-    #[pallet::storage]
-    #[pallet::getter(fn tokens_records)]
-    pub type TokensRecordsMap<T> = StorageDoubleMap<
-        _,
-        Blake2_128Concat,
+	/// Collection item updates by LOC ID and item ID, then by version starting at 1. The item as added is kept in CollectionItemsMap.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_version)]
+	pub type CollectionItemVersionsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(<T as Config>::LocId, <T as Config>::CollectionItemId),
+		Twox64Concat,
+		u32, // version
+		CollectionItemVersionOf<T>,
+	>;
+
+	/// The latest version of a collection item, by LOC ID and item ID. Items never updated have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_latest_version)]
+	pub type CollectionItemLatestVersionMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::CollectionItemId,
+		u32,
+	>;
+
+	/// The current owner of a collection item, by LOC ID and item ID. Items not registered have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_owner)]
+	pub type CollectionItemOwnersMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::CollectionItemId,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	/// The native token minted for a collection item, by LOC ID and item ID.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_native_token)]
+	pub type CollectionItemNativeTokensMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::CollectionItemId,
+		NativeTokenOf<T>,
+	>;
+
+	/// The collection item a native token was minted for, by token ID.
+	#[pallet::storage]
+	#[pallet::getter(fn native_token_item)]
+	pub type NativeTokenItemsMap<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::Hash,
+		(<T as Config>::LocId, <T as Config>::CollectionItemId),
+	>;
+
+	/// The block at which a collection item was added, by LOC ID and item ID. Imported items have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_added_on)]
+	pub type CollectionItemsAddedOnMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::CollectionItemId,
+		BlockNumberFor<T>,
+	>;
+
+	/// The block at which a collection item was removed, by LOC ID and item ID. The ID of a removed item cannot be reused.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_removed_on)]
+	pub type RemovedCollectionItemsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::CollectionItemId,
+		BlockNumberFor<T>,
+	>;
+
+    /// Collection size by LOC ID.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_size)]
+    pub type CollectionSizeMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, CollectionSize>;
+
+    /// Collection tokens records by LOC ID and record ID.
+    #[pallet::storage]
+    #[pallet::getter(fn tokens_records)]
+    pub type TokensRecordsMap<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
         <T as Config>::LocId, Blake2_128Concat,
         <T as Config>::TokensRecordId,
         TokensRecordOf<T>
     >;
 
+	/// The collection items a tokens record is restricted to, by LOC ID and record ID. Records applying
+	/// to the whole collection have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn tokens_record_targets)]
+	pub type TokensRecordTargetsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::TokensRecordId,
+		BoundedVec<<T as Config>::CollectionItemId, <T as Config>::MaxTokensRecordTargets>,
+	>;
+
+	/// Reverse index of `TokensRecordTargetsMap`, by (LOC ID, item ID) and record ID.
+	#[pallet::storage]
+	pub type CollectionItemTokensRecordsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(<T as Config>::LocId, <T as Config>::CollectionItemId),
+		Blake2_128Concat,
+		<T as Config>::TokensRecordId,
+		(),
+	>;
+
+	/// Tokens records revocations, by LOC ID and record ID.
+	#[pallet::storage]
+	#[pallet::getter(fn tokens_record_revocation)]
+	pub type TokensRecordRevocationsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::TokensRecordId,
+		TokensRecordRevocationOf<T>,
+	>;
+
+	/// The record superseded by a tokens record, by LOC ID and record ID.
+	#[pallet::storage]
+	#[pallet::getter(fn tokens_record_supersedes)]
+	pub type TokensRecordSupersedesMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::TokensRecordId,
+		<T as Config>::TokensRecordId,
+	>;
+
+	/// The record superseding a tokens record, by LOC ID and record ID.
+	#[pallet::storage]
+	#[pallet::getter(fn tokens_record_superseded_by)]
+	pub type TokensRecordSupersededByMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::TokensRecordId,
+		<T as Config>::TokensRecordId,
+	>;
+
     /// Verified Issuers by owner
     #[pallet::storage]
     #[pallet::getter(fn verified_issuers)]
@@ -795,6 +1268,148 @@ pub mod pallet {
         ()
 	>;
 
+	/// Storage deposits held by LOC ID and depositor
+	#[pallet::storage]
+	#[pallet::getter(fn storage_deposit)]
+	pub type StorageDepositsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId, // depositor
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Depositor and amount of the storage deposit of each LOC file
+	#[pallet::storage]
+	#[pallet::getter(fn loc_file_deposit)]
+	pub type LocFileDepositsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::Hash, // file hash
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+	>;
+
+	/// Payer and amount of the storage fee of each LOC file
+	#[pallet::storage]
+	#[pallet::getter(fn loc_file_storage_fee)]
+	pub type LocFileStorageFeesMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::Hash, // file hash
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+	>;
+
+	/// Depositor and amount of the storage deposit of each collection item version, by LOC ID, item ID
+	/// and version. The files of the item as added are version 0.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_deposit)]
+	pub type CollectionItemDepositsMap<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+			NMapKey<Blake2_128Concat, <T as Config>::CollectionItemId>,
+			NMapKey<Twox64Concat, u32>, // version
+		),
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+	>;
+
+	/// Depositor and amount of the storage deposit of each tokens record, by LOC ID and record ID
+	#[pallet::storage]
+	#[pallet::getter(fn tokens_record_deposit)]
+	pub type TokensRecordDepositsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as Config>::TokensRecordId,
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+	>;
+
+	/// Fee allowances granted by LOC requesters or sponsors to contributors, by LOC
+	#[pallet::storage]
+	#[pallet::getter(fn fee_allowance)]
+	pub type FeeAllowancesMap<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+			NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>, // payer
+			NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>, // contributor
+		),
+		BalanceOf<T>,
+	>;
+
+	/// LOCs having a link to a given LOC, by target LOC ID
+	#[pallet::storage]
+	#[pallet::getter(fn link_to_loc)]
+	pub type LinksToLocMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId, // target
+		Blake2_128Concat,
+		<T as Config>::LocId, // source
+		(),
+	>;
+
+	/// LOC templates indexed by ID.
+	#[pallet::storage]
+	#[pallet::getter(fn template)]
+	pub type TemplatesMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::Hash, LocTemplateOf<T>>;
+
+	/// The template a LOC was created from, by LOC ID.
+	#[pallet::storage]
+	#[pallet::getter(fn template_of_loc)]
+	pub type LocTemplateMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, <T as Config>::Hash>;
+
+	/// Co-owners of a Polkadot Transaction LOC and the number of owner approvals required to close or void it
+	/// and to acknowledge its items, by LOC ID.
+	#[pallet::storage]
+	#[pallet::getter(fn co_ownership)]
+	pub type CoOwnershipMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, CoOwnershipOf<T>>;
+
+	/// Acknowledgements of the items of co-owned LOCs by each of their owners, an item being acknowledged
+	/// by owners when the approval threshold is reached
+	#[pallet::storage]
+	#[pallet::getter(fn owner_acknowledgement)]
+	pub type OwnerAcknowledgementsMap<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+			NMapKey<Blake2_128Concat, LocItemKeyOf<T>>,
+			NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>, // owner
+		),
+		(),
+	>;
+
+	/// LOCs by owner or co-owner
+	#[pallet::storage]
+	#[pallet::getter(fn loc_of_owner)]
+	pub type LocsByOwnerMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId, // owner or co-owner
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		(),
+	>;
+
+	/// Pending owner approvals of actions on co-owned LOCs, the value being the hash of the approved action
+	#[pallet::storage]
+	#[pallet::getter(fn owner_approval)]
+	pub type OwnerApprovalsMap<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::LocId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId, // approver
+		<T as Config>::Hash,
+	>;
+
 	#[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -828,8 +1443,81 @@ pub mod pallet {
         ItemImported(T::LocId, T::CollectionItemId),
         /// Issued upon tokens record import. [locId, recordId]
         TokensRecordImported(T::LocId, T::TokensRecordId),
+        /// Issued when a tokens record is revoked. [locId, recordId, reason]
+        TokensRecordRevoked(T::LocId, T::TokensRecordId, <T as Config>::Hash),
+        /// Issued when a tokens record supersedes another one. [locId, recordId, supersededRecordId]
+        TokensRecordSuperseded(T::LocId, T::TokensRecordId, T::TokensRecordId),
         /// Issued upon sponsorship import. [sponsorshipId]
-        SponsorshipImported(T::SponsorshipId)
+        SponsorshipImported(T::SponsorshipId),
+        /// Issued when the Value Fee reserved for a voided collection is released. [requesterAccountId, valueFee]
+        ValueFeeUnreserved(T::AccountId, BalanceOf<T>),
+        /// Issued when the share of Legal Fee received by LOC owner is refunded. [payerAccountId, refundedFee]
+        LegalFeeRefunded(T::AccountId, BalanceOf<T>),
+        /// Issued when a requester or sponsor grants a fee allowance to a contributor. [locId, payer, contributor, allowance]
+        FeeAllowanceGranted(T::LocId, T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Issued when a requester or sponsor revokes the fee allowance of a contributor. [locId, payer, contributor]
+        FeeAllowanceRevoked(T::LocId, T::AccountId, T::AccountId),
+        /// Issued when a storage deposit is reserved. [depositorAccountId, locId, deposit]
+        StorageDepositReserved(T::AccountId, T::LocId, BalanceOf<T>),
+        /// Issued when a storage deposit is released. [depositorAccountId, locId, deposit]
+        StorageDepositReleased(T::AccountId, T::LocId, BalanceOf<T>),
+        /// Issued when the storage fee of a deleted file is refunded. [payerAccountId, locId, refundedFee]
+        StorageFeeRefunded(T::AccountId, T::LocId, BalanceOf<T>),
+        /// Issued when metadata is deleted from a LOC. [locId, name]
+        MetadataDeleted(T::LocId, <T as Config>::Hash),
+        /// Issued when a file is deleted from a LOC. [locId, hash]
+        FileDeleted(T::LocId, <T as Config>::Hash),
+        /// Issued when a link is deleted from a LOC. [locId, target]
+        LinkDeleted(T::LocId, T::LocId),
+        /// Issued when items are added to a LOC in batch. [locId, metadataNames, fileHashes, linkTargets]
+        ItemsAdded(T::LocId, Vec<<T as Config>::Hash>, Vec<<T as Config>::Hash>, Vec<T::LocId>),
+        /// Issued when a metadata item is added to a LOC. [locId, name, submitter]
+        MetadataAdded(T::LocId, <T as Config>::Hash, SupportedAccountId<T::AccountId, T::EthereumAddress>),
+        /// Issued when a file is added to a LOC. [locId, hash, submitter]
+        FileAdded(T::LocId, <T as Config>::Hash, SupportedAccountId<T::AccountId, T::EthereumAddress>),
+        /// Issued when a link is added to a LOC. [locId, target, submitter]
+        LinkAdded(T::LocId, T::LocId, SupportedAccountId<T::AccountId, T::EthereumAddress>),
+        /// Issued when a LOC item is acknowledged by the owner or a verified issuer. [locId, item, acknowledger]
+        ItemAcknowledged(T::LocId, LocItemKeyOf<T>, T::AccountId),
+        /// Issued when a voided LOC is the target of links from other LOCs. Only the first linking LOCs
+        /// are reported, the flag tells if there are more. [locId, linkingLocIds, more]
+        VoidedLocLinkedBy(T::LocId, BoundedVec<T::LocId, T::MaxReportedLinkingLocs>, bool),
+        /// Issued upon LOC template registration. [templateId, legalOfficer]
+        TemplateRegistered(<T as Config>::Hash, T::AccountId),
+        /// Issued when an owner of a co-owned LOC approves an action. [locId, action, approver]
+        OwnerActionApproved(T::LocId, OwnerActionOf<T>, T::AccountId),
+        /// Issued when a LOC is transferred from a legal officer to another. [locId, from, to]
+        LocOwnershipTransferred(T::LocId, T::AccountId, T::AccountId),
+        /// Issued when a verified issuer nomination is transferred from a legal officer to another. [issuer, from, to]
+        VerifiedIssuerTransferred(T::AccountId, T::AccountId, T::AccountId),
+        /// Issued when the nomination of a verified issuer is dropped because the target legal officer already nominated the issuer. [issuer, from, to]
+        VerifiedIssuerAlreadyNominated(T::AccountId, T::AccountId, T::AccountId),
+        /// Issued when a pending sponsorship is transferred from a legal officer to another. [sponsorshipId, from, to]
+        SponsorshipTransferred(T::SponsorshipId, T::AccountId, T::AccountId),
+        /// Issued when a collection item is revoked. [locId, collectionItemId, reason]
+        ItemRevoked(T::LocId, T::CollectionItemId, <T as Config>::Hash),
+        /// Issued when a collection item is removed. [locId, collectionItemId]
+        ItemRemoved(T::LocId, T::CollectionItemId),
+        /// Issued when a new version of a collection item is added. [locId, collectionItemId, version]
+        ItemUpdated(T::LocId, T::CollectionItemId, u32),
+        /// Issued when the native token of a collection item is minted. [locId, collectionItemId, tokenId]
+        NativeTokenMinted(T::LocId, T::CollectionItemId, <T as Config>::Hash),
+        /// Issued when the initial owner of a collection item is registered. [locId, collectionItemId, owner]
+        ItemOwnerSet(T::LocId, T::CollectionItemId, T::AccountId),
+        /// Issued when a collection item is transferred by its owner. [locId, collectionItemId, from, to]
+        ItemTransferred(T::LocId, T::CollectionItemId, T::AccountId, T::AccountId),
+        /// Issued when a collection item is transferred by the collection owner, from its registered owner if any. [locId, collectionItemId, from, to]
+        ItemForceTransferred(T::LocId, T::CollectionItemId, Option<T::AccountId>, T::AccountId),
+        /// Issued when the delivery of a file of a restricted delivery item is claimed. [locId, collectionItemId, fileHash, claimant]
+        RestrictedDeliveryClaimed(T::LocId, T::CollectionItemId, <T as Config>::Hash, T::AccountId),
+        /// Issued when access to a tokens record is claimed through a collection item. [locId, recordId, collectionItemId, claimant]
+        TokensRecordAccessClaimed(T::LocId, T::TokensRecordId, T::CollectionItemId, T::AccountId),
+        /// Issued when a stepped storage migration starts. [targetVersion]
+        MigrationStarted(u16),
+        /// Issued when a stepped storage migration progressed but is not yet complete. [targetVersion, processedEntries]
+        MigrationAdvanced(u16, u32),
+        /// Issued when a stepped storage migration completes. [targetVersion]
+        MigrationCompleted(u16),
     }
 
     #[pallet::error]
@@ -945,64 +1633,174 @@ pub mod pallet {
 		CollectionItemTCsTooMuchData,
 		/// There are too much LOCs linked to account
 		AccountLocsTooMuchData,
+		/// Legal fee can be refunded only when voiding an open LOC
+		CannotRefundClosedLoc,
+		/// The selected fee payer did not agree to pay for this LOC
+		InvalidFeePayer,
+		/// The fees exceed the allowance granted by the requester to the contributor
+		InsufficientFeeAllowance,
+		/// There is no fee allowance to revoke
+		NoFeeAllowance,
+		/// The nature of the link requires the target LOC to be closed
+		LinkedLocNotClosed,
+		/// The nature of the link requires the target LOC to be non-void
+		LinkedLocVoid,
+		/// The nature of the link requires the target LOC to have the same owner
+		LinkedLocOwnerMismatch,
+		/// The nature of the link requires the target LOC to be of another type
+		LinkedLocTypeMismatch,
+		/// A template with the same ID already exists
+		TemplateAlreadyExists,
+		/// The template does not exist
+		TemplateNotFound,
+		/// Value fee, collection item fee, tokens record fee and collection parameters are only allowed in collection templates
+		UnexpectedTemplateCollectionParams,
+		/// Some items required by the LOC template are missing or unacknowledged by owner
+		CannotCloseMissingTemplateItems,
+		/// Co-owners must be distinct legal officers other than the LOC owner
+		InvalidCoOwners,
+		/// The approval threshold must be between 1 and the number of owners
+		InvalidThreshold,
+		/// There are too much co-owners
+		LocCoOwnersTooMuchData,
+		/// The owner already approved the action
+		AlreadyApproved,
+		/// LOCs can only be transferred to another legal officer
+		InvalidTransferTarget,
+		/// The sponsorship does not exist, is already used or is not granted by the source legal officer
+		CannotTransferSponsorship,
+		/// A storage migration is in progress, calls are rejected until it completes
+		MigrationInProgress,
+		/// The collection item does not exist
+		CollectionItemNotFound,
+		/// The collection item was already revoked
+		CollectionItemAlreadyRevoked,
+		/// The collection item can no longer be removed
+		CollectionItemRemovalGracePeriodExpired,
+		/// The collection item cannot be updated
+		CannotUpdateCollectionItem,
+		/// The collection item has reached the maximum number of versions
+		CollectionItemVersionsTooMuchData,
+		/// The collection item cannot be removed because its native token was minted
+		CannotRemoveItemWithNativeToken,
+		/// The owner of the collection item has already been registered
+		CollectionItemOwnerAlreadySet,
+		/// The collection item has no registered owner
+		CollectionItemHasNoOwner,
+		/// The caller is not the registered owner of the collection item
+		NotCollectionItemOwner,
+		/// The collection item cannot be transferred
+		CannotTransferCollectionItem,
+		/// The owner of the collection item is the holder of its native token
+		CollectionItemHasNativeToken,
+		/// The tokens record does not exist
+		TokensRecordNotFound,
+		/// The tokens record has already been revoked
+		TokensRecordAlreadyRevoked,
+		/// The tokens record has already been superseded by another record
+		TokensRecordAlreadySuperseded,
+		/// The tokens record targets too many collection items
+		TokensRecordTooManyTargets,
+		/// The tokens record targets the same collection item several times
+		DuplicateTokensRecordTarget,
+		/// The collection item cannot be removed because it is targeted by non-revoked tokens records
+		CannotRemoveItemWithTokensRecords,
+		/// Storage deposits are only released once the LOC is void
+		LocNotVoid,
+		/// The storage fee of a deleted file could not be refunded
+		CannotRefundStorageFee,
+		/// The caller may not claim the delivery of the file
+		CannotClaimRestrictedDelivery,
+		/// The caller may not access the tokens record
+		CannotAccessTokensRecord,
+		/// A revoked tokens record cannot be superseded
+		CannotSupersedeRevokedTokensRecord,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 
         fn integrity_test() {
-            assert!(T::FileStorageFeeDistributionKey::get().is_valid());
-            assert!(T::CertificateFeeDistributionKey::get().is_valid());
-            assert!(T::ValueFeeDistributionKey::get().is_valid());
-            assert!(T::CollectionItemFeeDistributionKey::get().is_valid());
-            assert!(T::TokensRecordFeeDistributionKey::get().is_valid());
+            for fee_kind in FeeKind::ALL {
+                for loc_type in LocType::ALL {
+                    assert!(
+                        Self::fee_distribution_key(fee_kind, loc_type).is_valid(),
+                        "Invalid distribution key for {:?} fee of {:?} LOC", fee_kind, loc_type
+                    );
+                }
+            }
+            assert!(
+                T::MaxMigrationWeightPerBlock::get().all_gte(
+                    Self::migration_step_base_weight().saturating_add(T::SteppedMigration::max_entry_weight())
+                ),
+                "MaxMigrationWeightPerBlock does not allow the stepped migration to process a single entry per block"
+            );
         }
 
-        #[cfg(feature = "try-runtime")]
-        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
-            assert_eq!(PalletStorageVersion::<T>::get(), StorageVersion::default());
-            Ok(())
+        fn on_runtime_upgrade() -> Weight {
+            // The legacy storage version must have been replaced before the stepped migration is started,
+            // it is replaced here if the runtime did not execute `MigrateToFrameStorageVersion` (see `migrations::Migrations`).
+            let legacy_weight = if crate::migrations::legacy::PalletStorageVersion::<T>::exists() {
+                log::warn!("⚠️ Legacy storage version still present, MigrateToFrameStorageVersion should run first");
+                <crate::migrations::MigrateToFrameStorageVersion<T> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade()
+            } else {
+                T::DbWeight::get().reads(1)
+            };
+
+            let from_version = T::SteppedMigration::FROM_VERSION;
+            let to_version = T::SteppedMigration::TO_VERSION;
+            if from_version == to_version
+                || StorageVersion::get::<Pallet<T>>() != from_version
+                || MigrationInProgress::<T>::exists() {
+                return legacy_weight.saturating_add(T::DbWeight::get().reads(2));
+            }
+
+            MigrationInProgress::<T>::put(MigrationStatus::default());
+            Self::deposit_event(Event::MigrationStarted(to_version));
+            log::info!("🚚 Stepped migration to version {} started", to_version);
+
+            #[cfg(feature = "try-runtime")]
+            while MigrationInProgress::<T>::exists() {
+                Self::step_migration(Weight::MAX);
+            }
+
+            legacy_weight.saturating_add(T::DbWeight::get().reads_writes(2, 2))
         }
-    }
 
-    #[derive(Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
-    pub enum StorageVersion {
-        V1,
-        V2MakeLocVoid,
-        V3RequesterEnum,
-        V4ItemSubmitter,
-        V5Collection,
-        V6ItemUpload,
-        V7ItemToken,
-        V8AddSeal,
-        V9TermsAndConditions,
-        V10AddLocFileSize,
-        V11EnableEthereumSubmitter,
-        V12Sponsorship,
-        V13AcknowledgeItems,
-        V14HashLocPublicData,
-        V15AddTokenIssuance,
-        V16MoveTokenIssuance,
-        V17HashItemRecordPublicData,
-        V18AddValueFee,
-        V19AcknowledgeItemsByIssuer,
-        V20AddCustomLegalFee,
-        V21EnableRequesterLinks,
-        V22AddRecurrentFees,
-        V23RemoveUselessMapsAddImported,
-    }
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::step_migration(T::MaxMigrationWeightPerBlock::get())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let state = if StorageVersion::get::<Pallet<T>>() == T::SteppedMigration::FROM_VERSION {
+                Some(T::SteppedMigration::pre_upgrade()?)
+            } else {
+                None
+            };
+            Ok(state.encode())
+        }
 
-    impl Default for StorageVersion {
-        fn default() -> StorageVersion {
-            return StorageVersion::V23RemoveUselessMapsAddImported;
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let state: Option<Vec<u8>> = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            if let Some(migration_state) = state {
+                T::SteppedMigration::post_upgrade(migration_state)?;
+            }
+            assert!(!MigrationInProgress::<T>::exists());
+            assert_eq!(StorageVersion::get::<Pallet<T>>(), STORAGE_VERSION);
+            Ok(())
         }
     }
 
-    /// Storage version
+    /// The status of the stepped storage migration being executed, if any
     #[pallet::storage]
-    #[pallet::getter(fn pallet_storage_version)]
-    pub type PalletStorageVersion<T> = StorageValue<_, StorageVersion, ValueQuery>;
+    #[pallet::getter(fn migration_in_progress)]
+    pub type MigrationInProgress<T> = StorageValue<_, MigrationStatus, OptionQuery>;
 
+	/// No genesis storage, kept so that chain specifications including the pallet's genesis still decode.
+	/// The on-chain storage version is set to the in-code version at genesis by FRAME.
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config>(PhantomData<T>);
 
@@ -1015,9 +1813,7 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 
-		fn build(&self) {
-			PalletStorageVersion::<T>::put(StorageVersion::default());
-		}
+		fn build(&self) {}
 	}
 
     #[pallet::call]
@@ -1033,6 +1829,7 @@ pub mod pallet {
             legal_fee: BalanceOf<T>,
             items: ItemsParamsOf<T>,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let requester_account_id = ensure_signed(origin)?;
 
             if !T::IsLegalOfficer::is_legal_officer(&legal_officer) {
@@ -1042,17 +1839,16 @@ pub mod pallet {
             } else {
                 let requester = RequesterOf::<T>::Account(requester_account_id.clone());
                 let mut loc = Self::build_open_loc(&legal_officer, &requester, LocType::Identity, None, legal_fee);
-                loc.ensure_can_add::<T>(&items)?;
-                Self::ensure_valid_links(&items.links)?;
-                let tot_size = items.files.iter()
-                    .map(|file| file.size)
-                    .fold(0, |tot, current| tot + current);
-                Self::apply_file_storage_fee(&requester_account_id, items.files.len(), tot_size)?;
-                loc.add_items::<T>(&requester_account_id, &items)?;
-
-                Self::apply_legal_fee(&loc)?;
+                Self::ensure_can_add(&loc_id, &loc, &items)?;
+                Self::ensure_valid_links(&legal_officer, &items.links)?;
+                Self::apply_loc_files_storage_fee(&requester_account_id, &loc_id, loc.loc_type, &items.files)?;
+                Self::insert_items(&loc_id, &mut loc, &requester_account_id, &items, <frame_system::Pallet<T>>::block_number())?;
+
+                Self::apply_legal_fee(&loc_id, &loc)?;
+                <LocsByOwnerMap<T>>::insert(&loc.owner, loc_id, ());
                 <LocMap<T>>::insert(loc_id, loc);
                 Self::link_with_account(&requester_account_id, &loc_id)?;
+                Self::index_links(&loc_id, items.links.iter().map(|link| link.id));
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -1067,6 +1863,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             if <LocMap<T>>::contains_key(&loc_id) {
@@ -1074,6 +1871,7 @@ pub mod pallet {
             } else {
                 let requester = RequesterOf::<T>::None;
                 let loc = Self::build_open_loc(&who, &requester, LocType::Identity, None, BalanceOf::<T>::zero());
+                <LocsByOwnerMap<T>>::insert(&loc.owner, loc_id, ());
                 <LocMap<T>>::insert(loc_id, loc);
 
                 Self::deposit_event(Event::LocCreated(loc_id));
@@ -1091,6 +1889,7 @@ pub mod pallet {
             legal_fee: BalanceOf<T>,
             items: ItemsParamsOf<T>,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let requester_account_id = ensure_signed(origin)?;
 
             if !T::IsLegalOfficer::is_legal_officer(&legal_officer) {
@@ -1102,17 +1901,16 @@ pub mod pallet {
             } else {
                 let requester = RequesterOf::<T>::Account(requester_account_id.clone());
                 let mut loc = Self::build_open_loc(&legal_officer, &requester, LocType::Transaction, None, legal_fee);
-                loc.ensure_can_add::<T>(&items)?;
-                Self::ensure_valid_links(&items.links)?;
-                let tot_size = items.files.iter()
-                    .map(|file| file.size)
-                    .fold(0, |tot, current| tot + current);
-                Self::apply_file_storage_fee(&requester_account_id, items.files.len(), tot_size)?;
-                loc.add_items::<T>(&requester_account_id, &items)?;
-
-                Self::apply_legal_fee(&loc)?;
+                Self::ensure_can_add(&loc_id, &loc, &items)?;
+                Self::ensure_valid_links(&legal_officer, &items.links)?;
+                Self::apply_loc_files_storage_fee(&requester_account_id, &loc_id, loc.loc_type, &items.files)?;
+                Self::insert_items(&loc_id, &mut loc, &requester_account_id, &items, <frame_system::Pallet<T>>::block_number())?;
+
+                Self::apply_legal_fee(&loc_id, &loc)?;
+                <LocsByOwnerMap<T>>::insert(&loc.owner, loc_id, ());
                 <LocMap<T>>::insert(loc_id, loc);
                 Self::link_with_account(&requester_account_id, &loc_id)?;
+                Self::index_links(&loc_id, items.links.iter().map(|link| link.id));
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -1128,6 +1926,7 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             requester_loc_id: T::LocId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             if <LocMap<T>>::contains_key(&loc_id) {
@@ -1142,6 +1941,7 @@ pub mod pallet {
                         } else {
                             let requester = RequesterOf::<T>::Loc(requester_loc_id.clone());
                             let new_loc = Self::build_open_loc(&who, &requester, LocType::Transaction, None, BalanceOf::<T>::zero());
+                            <LocsByOwnerMap<T>>::insert(&who, loc_id, ());
                             <LocMap<T>>::insert(loc_id, new_loc);
                         },
                 }
@@ -1167,6 +1967,7 @@ pub mod pallet {
             tokens_record_fee: BalanceOf<T>,
             items: ItemsParamsOf<T>,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let requester_account_id = ensure_signed(origin)?;
 
             if !T::IsLegalOfficer::is_legal_officer(&legal_officer) {
@@ -1192,28 +1993,27 @@ pub mod pallet {
                     collection_item_fee,
                     tokens_record_fee,
                 );
-                loc.ensure_can_add::<T>(&items)?;
-                Self::ensure_valid_links(&items.links)?;
-                let tot_size = items.files.iter()
-                    .map(|file| file.size)
-                    .fold(0, |tot, current| tot + current);
-                Self::apply_file_storage_fee(&requester_account_id, items.files.len(), tot_size)?;
-                loc.add_items::<T>(&requester_account_id, &items)?;
-
-                Self::apply_legal_fee(&loc)?;
+                Self::ensure_can_add(&loc_id, &loc, &items)?;
+                Self::ensure_valid_links(&legal_officer, &items.links)?;
+                Self::apply_loc_files_storage_fee(&requester_account_id, &loc_id, loc.loc_type, &items.files)?;
+                Self::insert_items(&loc_id, &mut loc, &requester_account_id, &items, <frame_system::Pallet<T>>::block_number())?;
+
+                Self::apply_legal_fee(&loc_id, &loc)?;
                 if value_fee > 0_u32.into() {
                     ensure!(T::Currency::can_reserve(&requester_account_id, value_fee), Error::<T>::InsufficientFunds);
                     T::Currency::reserve(&requester_account_id, value_fee)?
                 }
+                <LocsByOwnerMap<T>>::insert(&loc.owner, loc_id, ());
                 <LocMap<T>>::insert(loc_id, loc);
                 Self::link_with_account(&requester_account_id, &loc_id)?;
+                Self::index_links(&loc_id, items.links.iter().map(|link| link.id));
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
             }
         }
 
-        /// Add LOC metadata
+        /// Add LOC metadata. Metadata are not charged any storage fee, there is no fee payer to choose.
         #[pallet::call_index(5)]
         #[pallet::weight(T::WeightInfo::add_metadata())]
         pub fn add_metadata(
@@ -1221,6 +2021,7 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             item: MetadataItemParamsOf<T>
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = ensure_signed(origin)?;
 
             if !<LocMap<T>>::contains_key(&loc_id) {
@@ -1235,17 +2036,18 @@ pub mod pallet {
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
                 } else {
-                    loc.ensure_can_add_metadata::<T>(&Vec::from([item.name]))?;
+                    Self::ensure_can_add_metadata(&loc_id, &loc, &Vec::from([item.name]))?;
                     <LocMap<T>>::try_mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
-                        mutable_loc.add_metadata::<T>(&who, &item)
+                        Self::insert_metadata(&loc_id, mutable_loc, &who, &item, <frame_system::Pallet<T>>::block_number())
                     })?;
                     Ok(().into())
                 }
             }
         }
 
-        /// Add file to LOC
+        /// Add file to LOC, storage fees being charged to the sponsor, the requester or the owner. Another fee payer
+        /// is chosen by adding the file with `add_items`.
         #[pallet::call_index(6)]
         #[pallet::weight(T::WeightInfo::add_file())]
         pub fn add_file(
@@ -1253,42 +2055,11 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             file: FileParamsOf<T>
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-
-            if !<LocMap<T>>::contains_key(&loc_id) {
-                Err(Error::<T>::NotFound)?
-            } else {
-                let loc = <LocMap<T>>::get(&loc_id).unwrap();
-                let published_by_owner: bool = Self::is_published_by_owner(&loc, &who)?;
-                if !Self::is_valid_submitter(&loc_id, &loc, &file.submitter, published_by_owner) {
-                    Err(Error::<T>::CannotSubmit)?
-                } else if loc.closed {
-                    Err(Error::<T>::CannotMutate)?
-                } else if loc.void_info.is_some() {
-                    Err(Error::<T>::CannotMutateVoid)?
-                } else {
-                    loc.ensure_can_add_files::<T>(&Vec::from([file.hash]))?;
-                    let fee_payer;
-                    if loc.sponsorship_id.is_some() {
-                        let sponsorship = <SponsorshipMap<T>>::get(loc.sponsorship_id.unwrap()).unwrap();
-                        fee_payer = sponsorship.sponsor;
-                    } else {
-                        fee_payer = match loc.requester {
-                            Account(requester_account) => requester_account,
-                            _ => loc.owner
-                        };
-                    }
-                    Self::apply_file_storage_fee(&fee_payer, 1, file.size)?;
-                    <LocMap<T>>::try_mutate(loc_id, |loc| {
-                        let mutable_loc = loc.as_mut().unwrap();
-                        mutable_loc.add_file::<T>(&who, &file)
-                    })?;
-                    Ok(().into())
-                }
-            }
+            Self::ensure_no_migration_in_progress()?;
+            Self::do_add_file(origin, loc_id, file)
         }
 
-        /// Add a link to LOC
+        /// Add a link to LOC. Links are not charged any storage fee, there is no fee payer to choose.
         #[pallet::call_index(7)]
         #[pallet::weight(T::WeightInfo::add_link())]
         pub fn add_link(
@@ -1296,6 +2067,7 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             link: LocLinkParamsOf<T>,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = ensure_signed(origin)?;
 
             if !<LocMap<T>>::contains_key(&loc_id) {
@@ -1309,14 +2081,14 @@ pub mod pallet {
                     Err(Error::<T>::CannotMutate)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
-                } else if !<LocMap<T>>::contains_key(&link.id) {
-                    Err(Error::<T>::LinkedLocNotFound)?
                 } else {
-                    loc.ensure_can_add_links::<T>(&Vec::from([link.id]))?;
+                    Self::ensure_valid_link(&loc.owner, &link.id, &link.nature)?;
+                    Self::ensure_can_add_links(&loc_id, &loc, &Vec::from([link.id]))?;
                     <LocMap<T>>::try_mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
-                        mutable_loc.add_link::<T>(&who, &link)
+                        Self::insert_link(&loc_id, mutable_loc, &who, &link, <frame_system::Pallet<T>>::block_number())
                     })?;
+                    <LinksToLocMap<T>>::insert(link.id, loc_id, ());
                     Ok(().into())
                 }
             }
@@ -1329,7 +2101,8 @@ pub mod pallet {
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
         ) -> DispatchResultWithPostInfo {
-            Self::do_make_void(origin, loc_id, None)
+            Self::ensure_no_migration_in_progress()?;
+            Self::do_make_void(origin, loc_id, None, false)
         }
 
         /// Make a LOC void and provide a replacer.
@@ -1340,12 +2113,13 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             #[pallet::compact] replacer_loc_id: T::LocId,
         ) -> DispatchResultWithPostInfo {
-            Self::do_make_void(origin, loc_id, Some(replacer_loc_id))
+            Self::ensure_no_migration_in_progress()?;
+            Self::do_make_void(origin, loc_id, Some(replacer_loc_id), false)
         }
 
         /// Adds an item to a collection
         #[pallet::call_index(12)]
-        #[pallet::weight(T::WeightInfo::add_collection_item())]
+        #[pallet::weight(T::WeightInfo::add_collection_item().saturating_add(Pallet::<T>::terms_and_conditions_weight()))]
         pub fn add_collection_item(
             origin: OriginFor<T>,
             #[pallet::compact] collection_loc_id: T::LocId,
@@ -1355,7 +2129,10 @@ pub mod pallet {
             item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
             restricted_delivery: bool,
             terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
-        ) -> DispatchResultWithPostInfo { Self::do_add_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions) }
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            Self::do_add_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions)
+        }
 
         /// Nominate an issuer
         #[pallet::call_index(14)]
@@ -1365,6 +2142,7 @@ pub mod pallet {
             issuer: T::AccountId,
             #[pallet::compact] identity_loc_id: T::LocId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             let maybe_identity_loc = Self::loc(identity_loc_id);
@@ -1396,6 +2174,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             issuer: T::AccountId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             let existing_issuer = Self::verified_issuers(&who, &issuer);
@@ -1423,6 +2202,7 @@ pub mod pallet {
             issuer: T::AccountId,
             selected: bool,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             if !<LocMap<T>>::contains_key(&loc_id) {
@@ -1460,77 +2240,22 @@ pub mod pallet {
             files: Vec<TokensRecordFileOf<T>>,
             charge_submitter: bool,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-
-            let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
-            match collection_loc_option {
-                None => Err(Error::<T>::WrongCollectionLoc)?,
-                Some(collection_loc) => {
-                    if <TokensRecordsMap<T>>::contains_key(&collection_loc_id, &record_id) {
-                        Err(Error::<T>::TokensRecordAlreadyExists)?
-                    }
-                    if !Self::can_add_record(&who, &collection_loc_id, &collection_loc) {
-                        Err(Error::<T>::CannotAddRecord)?
-                    }
-                    if files.len() == 0 {
-                        Err(Error::<T>::MustUpload)?
-                    } else {
-                        let files_hashes: Vec<<T as Config>::Hash> = files.iter()
-                            .map(|file| file.hash)
-                            .collect();
-                        if !Self::has_unique_elements(&files_hashes) {
-                            Err(Error::<T>::DuplicateFile)?
-                        }
-                    }
-
-                    let mut bounded_files: BoundedVec<TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(files.len());
-                    for file in files.iter() {
-                        bounded_files.try_push(file.clone()).map_err(|_| Error::<T>::TokensRecordTooMuchData)?;
-                    }
-                    let fee_payer = if charge_submitter { who.clone() } else {
-                        match collection_loc.requester {
-                            Account(requester_account) => requester_account,
-                            _ => panic!("Requester cannot pay the fees")
-                        }
-                    };
+            Self::ensure_no_migration_in_progress()?;
+            Self::do_add_tokens_record(origin, collection_loc_id, record_id, description, files, charge_submitter, Vec::new(), None)
+        }
 
-                    let tot_size = files.iter()
-                        .map(|file| file.size)
-                        .fold(0, |tot, current| tot + current);
-                    Self::apply_file_storage_fee(&fee_payer, files.len(), tot_size)?;
-
-                    let fee = collection_loc.tokens_record_fee;
-                    if fee > 0_u32.into() {
-                        let (beneficiary, amount) = Self::slash_and_distribute(&fee_payer, fee, &|credit| {
-                            T::RewardDistributor::distribute_with_loc_owner(credit, T::TokensRecordFeeDistributionKey::get(), &collection_loc.owner)
-                        })?;
-                        Self::deposit_event(Event::TokensRecordFeeWithdrawn(fee_payer, fee, beneficiary, amount));
-                    }
-
-                    let record = TokensRecord {
-                        description,
-                        files: bounded_files,
-                        submitter: who.clone(),
-                        imported: false,
-                    };
-                    <TokensRecordsMap<T>>::insert(collection_loc_id, record_id, record);
-                },
-            }
-
-            Ok(().into())
-        }
-
-        /// Creates a new Identity LOC whose requester is another address (Currently only Ethereum address is supported).
-        #[pallet::call_index(18)]
-        #[pallet::weight(T::WeightInfo::create_other_identity_loc())]
-        pub fn create_other_identity_loc(
-            origin: OriginFor<T>,
-            #[pallet::compact] loc_id: T::LocId,
-            requester_account_id: OtherAccountId<T::EthereumAddress>,
-            #[pallet::compact] sponsorship_id: T::SponsorshipId,
-            legal_fee: BalanceOf<T>,
-        ) -> DispatchResultWithPostInfo {
-            let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
+        /// Creates a new Identity LOC whose requester is another address (Currently only Ethereum address is supported).
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::create_other_identity_loc())]
+        pub fn create_other_identity_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            requester_account_id: OtherAccountId<T::EthereumAddress>,
+            #[pallet::compact] sponsorship_id: T::SponsorshipId,
+            legal_fee: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             if <LocMap<T>>::contains_key(&loc_id) {
                 Err(Error::<T>::AlreadyExists)?
@@ -1540,7 +2265,8 @@ pub mod pallet {
                 let requester = RequesterOf::<T>::OtherAccount(requester_account_id.clone());
                 let loc = Self::build_open_loc(&who, &requester, LocType::Identity, Some(sponsorship_id), legal_fee);
 
-                Self::apply_legal_fee(&loc)?;
+                Self::apply_legal_fee(&loc_id, &loc)?;
+                <LocsByOwnerMap<T>>::insert(&loc.owner, loc_id, ());
                 <LocMap<T>>::insert(loc_id, loc);
                 Self::link_sponsorship_to_loc(&sponsorship_id, &loc_id);
 
@@ -1558,6 +2284,7 @@ pub mod pallet {
             sponsored_account: SupportedAccountId<T::AccountId, T::EthereumAddress>,
             legal_officer: T::AccountId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let sponsor = ensure_signed(origin)?;
 
             if !T::IsLegalOfficer::is_legal_officer(&legal_officer) {
@@ -1586,6 +2313,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             #[pallet::compact] sponsorship_id: T::SponsorshipId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let sponsor = ensure_signed(origin)?;
 
             let maybe_sponsorship = <SponsorshipMap<T>>::get(&sponsorship_id);
@@ -1613,53 +2341,9 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             name: <T as pallet::Config>::Hash,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = ensure_signed(origin)?;
-
-            if !<LocMap<T>>::contains_key(&loc_id) {
-                Err(Error::<T>::NotFound)?
-            } else {
-                let loc = <LocMap<T>>::get(&loc_id).unwrap();
-                let ack_by_owner = loc.owner == who;
-                let ack_by_verified_issuer = Self::selected_verified_issuers(loc_id, &who).is_some();
-                if !ack_by_owner && !ack_by_verified_issuer {
-                    Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
-                    Err(Error::<T>::CannotMutate)?
-                } else if loc.void_info.is_some() {
-                    Err(Error::<T>::CannotMutateVoid)?
-                }
-                let option_item_index = loc.metadata.iter().position(|item| item.name == name);
-                if option_item_index.is_none() {
-                    Err(Error::<T>::ItemNotFound)?
-                } else {
-                    let item_index = option_item_index.unwrap();
-                    if ack_by_owner && loc.metadata[item_index].acknowledged_by_owner {
-                        Err(Error::<T>::ItemAlreadyAcknowledged)?
-                    }
-                    if ack_by_verified_issuer {
-                        if loc.metadata[item_index].acknowledged_by_verified_issuer {
-                            Err(Error::<T>::ItemAlreadyAcknowledged)?
-                        }
-                        match &loc.metadata[item_index].submitter {
-                            Polkadot(polkadot_submitter) => {
-                                if *polkadot_submitter != who {
-                                    Err(Error::<T>::Unauthorized)?
-                                }
-                            },
-                            _ => Err(Error::<T>::Unauthorized)?
-                        }
-                    }
-                    <LocMap<T>>::mutate(loc_id, |loc| {
-                        let mutable_loc = loc.as_mut().unwrap();
-                        if ack_by_owner {
-                            mutable_loc.metadata[item_index].acknowledged_by_owner = true;
-                        } else {
-                            mutable_loc.metadata[item_index].acknowledged_by_verified_issuer = true;
-                        }
-                    });
-                    Ok(().into())
-                }
-            }
+            Self::do_acknowledge_items(&who, loc_id, &[name], &[], &[])
         }
 
         /// Acknowledge a file.
@@ -1670,53 +2354,9 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             hash: <T as pallet::Config>::Hash,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = ensure_signed(origin)?;
-
-            if !<LocMap<T>>::contains_key(&loc_id) {
-                Err(Error::<T>::NotFound)?
-            } else {
-                let loc = <LocMap<T>>::get(&loc_id).unwrap();
-                let ack_by_owner = loc.owner == who;
-                let ack_by_verified_issuer = Self::selected_verified_issuers(loc_id, &who).is_some();
-                if !ack_by_owner && !ack_by_verified_issuer {
-                    Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
-                    Err(Error::<T>::CannotMutate)?
-                } else if loc.void_info.is_some() {
-                    Err(Error::<T>::CannotMutateVoid)?
-                }
-                let option_item_index = loc.files.iter().position(|item| item.hash == hash);
-                if option_item_index.is_none() {
-                    Err(Error::<T>::ItemNotFound)?
-                } else {
-                    let item_index = option_item_index.unwrap();
-                    if ack_by_owner && loc.files[item_index].acknowledged_by_owner {
-                        Err(Error::<T>::ItemAlreadyAcknowledged)?
-                    }
-                    if ack_by_verified_issuer {
-                        if loc.files[item_index].acknowledged_by_verified_issuer {
-                            Err(Error::<T>::ItemAlreadyAcknowledged)?
-                        }
-                        match &loc.files[item_index].submitter {
-                            Polkadot(polkadot_submitter) => {
-                                if *polkadot_submitter != who {
-                                    Err(Error::<T>::Unauthorized)?
-                                }
-                            },
-                            _ => Err(Error::<T>::Unauthorized)?
-                        }
-                    }
-                    <LocMap<T>>::mutate(loc_id, |loc| {
-                        let mutable_loc = loc.as_mut().unwrap();
-                        if ack_by_owner {
-                            mutable_loc.files[item_index].acknowledged_by_owner = true;
-                        } else {
-                            mutable_loc.files[item_index].acknowledged_by_verified_issuer = true;
-                        }
-                    });
-                    Ok(().into())
-                }
-            }
+            Self::do_acknowledge_items(&who, loc_id, &[], &[hash], &[])
         }
 
         /// Acknowledge a link.
@@ -1727,103 +2367,65 @@ pub mod pallet {
             #[pallet::compact] loc_id: T::LocId,
             #[pallet::compact] target: T::LocId,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = ensure_signed(origin)?;
-
-            if !<LocMap<T>>::contains_key(&loc_id) {
-                Err(Error::<T>::NotFound)?
-            } else {
-                let loc = <LocMap<T>>::get(&loc_id).unwrap();
-                let ack_by_owner = loc.owner == who;
-                let ack_by_verified_issuer = Self::selected_verified_issuers(loc_id, &who).is_some();
-                if !ack_by_owner && !ack_by_verified_issuer {
-                    Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
-                    Err(Error::<T>::CannotMutate)?
-                } else if loc.void_info.is_some() {
-                    Err(Error::<T>::CannotMutateVoid)?
-                }
-                let option_item_index = loc.links.iter().position(|item| item.id == target);
-                if option_item_index.is_none() {
-                    Err(Error::<T>::ItemNotFound)?
-                } else {
-                    let item_index = option_item_index.unwrap();
-                    if ack_by_owner && loc.links[item_index].acknowledged_by_owner {
-                        Err(Error::<T>::ItemAlreadyAcknowledged)?
-                    }
-                    if ack_by_verified_issuer {
-                        if loc.links[item_index].acknowledged_by_verified_issuer {
-                            Err(Error::<T>::ItemAlreadyAcknowledged)?
-                        }
-                        match &loc.links[item_index].submitter {
-                            Polkadot(polkadot_submitter) => {
-                                if *polkadot_submitter != who {
-                                    Err(Error::<T>::Unauthorized)?
-                                }
-                            },
-                            _ => Err(Error::<T>::Unauthorized)?
-                        }
-                    }
-                    <LocMap<T>>::mutate(loc_id, |loc| {
-                        let mutable_loc = loc.as_mut().unwrap();
-                        if ack_by_owner {
-                            mutable_loc.links[item_index].acknowledged_by_owner = true;
-                        } else {
-                            mutable_loc.links[item_index].acknowledged_by_verified_issuer = true;
-                        }
-                    });
-                    Ok(().into())
-                }
-            }
+            Self::do_acknowledge_items(&who, loc_id, &[], &[], &[target])
         }
 
-        /// Close LOC.
+        /// Close LOC. The walks over LOC items are bounded by the item counters of the LOC,
+        /// the weight of the actual number of items being charged.
         #[pallet::call_index(24)]
-        #[pallet::weight(T::WeightInfo::close())]
+        #[pallet::weight(T::WeightInfo::close(T::MaxLocMetadata::get() + T::MaxLocFiles::get() + T::MaxLocLinks::get()))]
         pub fn close(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
             seal: Option<<T as Config>::Hash>,
             auto_ack: bool,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
             let who = ensure_signed(origin)?;
 
             if ! <LocMap<T>>::contains_key(&loc_id) {
                 Err(Error::<T>::NotFound)?
             } else {
                 let loc = <LocMap<T>>::get(&loc_id).unwrap();
-                if loc.owner != who {
+                if !Self::is_owner_or_co_owner(&loc_id, &loc, &who) {
                     Err(Error::<T>::Unauthorized)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
                 } else if loc.closed {
                     Err(Error::<T>::AlreadyClosed)?
-                } else if !auto_ack && loc.has_items_unacknowledged_by_owner() {
+                } else if !auto_ack && Self::has_items_unacknowledged_by_owner(&loc_id, &loc) {
                     Err(Error::<T>::CannotCloseUnacknowledgedByVerifiedIssuer)?
-                } else if loc.has_items_unacknowledged_by_verified_issuer() {
+                } else if Self::has_items_unacknowledged_by_verified_issuer(&loc_id, &loc) {
                     Err(Error::<T>::CannotCloseUnacknowledgedByVerifiedIssuer)?
+                } else if Self::template_of_loc(&loc_id)
+                    .and_then(|template_id| Self::template(template_id))
+                    .map_or(false, |template| Self::has_missing_template_items(&loc_id, &loc, &template)) {
+                    Err(Error::<T>::CannotCloseMissingTemplateItems)?
+                } else if !Self::approve_owner_action(&loc_id, OwnerAction::Close { seal, auto_ack }, &who)? {
+                    Ok(Some(T::WeightInfo::close(Self::items_count(&loc))).into())
                 } else {
+                    let items_count = Self::items_count(&loc);
+                    let current_block = <frame_system::Pallet<T>>::block_number();
+                    if auto_ack {
+                        Self::acknowledge_all_by_owner::<LocMetadataMap<T>, _, _>(&loc_id, loc.metadata_count, current_block);
+                        Self::acknowledge_all_by_owner::<LocFilesMap<T>, _, _>(&loc_id, loc.files_count, current_block);
+                        Self::acknowledge_all_by_owner::<LocLinksMap<T>, _, _>(&loc_id, loc.links_count, current_block);
+                    }
                     <LocMap<T>>::mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
-                        if auto_ack {
-                            mutable_loc.metadata.iter_mut()
-                                .filter(|item| !item.acknowledged_by_owner)
-                                .for_each(|item| item.acknowledged_by_owner = true);
-                            mutable_loc.files.iter_mut()
-                                .filter(|item| !item.acknowledged_by_owner)
-                                .for_each(|item| item.acknowledged_by_owner = true);
-                            mutable_loc.links.iter_mut()
-                                .filter(|item| !item.acknowledged_by_owner)
-                                .for_each(|item| item.acknowledged_by_owner = true);
-                        }
                         mutable_loc.closed = true;
+                        mutable_loc.closed_on = Some(current_block);
                         mutable_loc.seal = seal;
                     });
+                    Self::clear_owner_approvals(&loc_id);
 
                     if loc.loc_type == LocType::Collection && loc.value_fee > 0_u32.into() {
                         match loc.requester {
                             Account(requester_account) => {
                                 let (credit, _) = T::Currency::slash_reserved(&requester_account, loc.value_fee);
-                                T::RewardDistributor::distribute_with_loc_owner(credit, T::ValueFeeDistributionKey::get(), &loc.owner);
+                                T::RewardDistributor::distribute_with_loc_owner(credit, Self::fee_distribution_key(FeeKind::Value, loc.loc_type), &loc.owner);
                                 Self::deposit_event(Event::ValueFeeWithdrawn(requester_account, loc.value_fee));
                             },
                             _ => {},
@@ -1831,7 +2433,7 @@ pub mod pallet {
                     }
 
                     Self::deposit_event(Event::LocClosed(loc_id));
-                    Ok(().into())
+                    Ok(Some(T::WeightInfo::close(items_count)).into())
                 }
             }
         }
@@ -1845,6 +2447,7 @@ pub mod pallet {
 			invited_contributor: T::AccountId,
 			selected: bool,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			let who = ensure_signed(origin)?;
 			if !<LocMap<T>>::contains_key(&loc_id) {
 				Err(Error::<T>::NotFound)?
@@ -1873,606 +2476,2164 @@ pub mod pallet {
 			}
 		}
 
-		/// Import LOC data.
-        #[pallet::call_index(26)]
-        #[pallet::weight(T::WeightInfo::import_loc())]
-        pub fn import_loc(
-            origin: OriginFor<T>,
-            #[pallet::compact] loc_id: T::LocId,
-            requester: RequesterOf<T>,
-            legal_officer: T::AccountId,
-            loc_type: LocType,
-            items: ItemsOf<T>,
-            collection_last_block_submission: Option<BlockNumberFor<T>>,
-            collection_max_size: Option<u32>,
-            collection_can_upload: bool,
-            value_fee: BalanceOf<T>,
-            legal_fee: BalanceOf<T>,
-            collection_item_fee: BalanceOf<T>,
-            tokens_record_fee: BalanceOf<T>,
-            sponsorship_id: Option<T::SponsorshipId>,
-            seal: Option<<T as crate::pallet::Config>::Hash>,
-            void_info: Option<LocVoidInfo<T::LocId>>,
-            replacer_of: Option<T::LocId>,
-            closed: bool,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+		/// Grant a fee allowance to a contributor of a LOC, replacing any previous allowance. The signer,
+		/// the LOC requester or its sponsor, pays the fees charged to it by the contributor.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::grant_fee_allowance())]
+		pub fn grant_fee_allowance(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			contributor: T::AccountId,
+			allowance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			let loc = Self::ensure_can_grant_fee_allowance(&loc_id, &who)?;
+			if loc.void_info.is_some() {
+				Err(Error::<T>::CannotMutateVoid)?
+			}
+			<FeeAllowancesMap<T>>::insert((loc_id, &who, &contributor), allowance);
+			Self::deposit_event(Event::FeeAllowanceGranted(loc_id, who, contributor, allowance));
+			Ok(().into())
+		}
 
-            if <crate::pallet::LocMap<T>>::contains_key(&loc_id) {
-                Err(Error::<T>::AlreadyExists)?
-            } else {
-                let mut loc;
-                if loc_type == LocType::Identity || loc_type == LocType::Transaction {
-                    loc = Self::build_open_loc(&legal_officer, &requester, loc_type, sponsorship_id, legal_fee);
-                } else {
-                    loc = Self::build_open_collection_loc(
-                        &legal_officer,
-                        &requester,
-                        collection_last_block_submission,
-                        collection_max_size,
-                        collection_can_upload,
-                        value_fee,
-                        legal_fee,
-                        collection_item_fee,
-                        tokens_record_fee,
-                    );
-                }
-                loc.ensure_can_import::<T>(&items)?;
-                loc.import_items::<T>(&items)?;
-                loc.closed = closed;
-                loc.seal = seal;
-                loc.void_info = void_info;
-                loc.replacer_of = replacer_of;
-                loc.imported = true;
+		/// Revoke the fee allowance granted by the signer to a contributor of a LOC
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::revoke_fee_allowance())]
+		pub fn revoke_fee_allowance(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			contributor: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			Self::ensure_can_grant_fee_allowance(&loc_id, &who)?;
+			if !<FeeAllowancesMap<T>>::contains_key((loc_id, &who, &contributor)) {
+				Err(Error::<T>::NoFeeAllowance)?
+			}
+			<FeeAllowancesMap<T>>::remove((loc_id, &who, &contributor));
+			Self::deposit_event(Event::FeeAllowanceRevoked(loc_id, who, contributor));
+			Ok(().into())
+		}
 
-                <LocMap<T>>::insert(loc_id, loc);
-                match requester {
-                    Requester::Account(requester_account_id) => Self::link_with_account(&requester_account_id, &loc_id)?,
-                    _ => {},
-                };
+		/// Delete metadata from an open LOC
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::delete_metadata())]
+		pub fn delete_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			name: <T as pallet::Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			let loc = Self::loc(&loc_id).ok_or(Error::<T>::NotFound)?;
+			let item = Self::loc_metadata(loc_id, name).ok_or(Error::<T>::ItemNotFound)?;
+			Self::ensure_can_delete(&loc, &who, &item.submitter, item.acknowledged_by_owner || item.acknowledged_by_verified_issuer)?;
+			<LocMetadataMap<T>>::remove(loc_id, name);
+			Self::forget_item(&loc_id, LocItemKey::Metadata(name));
+			<LocMap<T>>::mutate(loc_id, |loc| {
+				let mutable_loc = loc.as_mut().unwrap();
+				mutable_loc.metadata_count = mutable_loc.metadata_count.saturating_sub(1);
+			});
+			Self::deposit_event(Event::MetadataDeleted(loc_id, name));
+			Ok(().into())
+		}
 
-                Self::deposit_event(Event::LocImported(loc_id));
-                Ok(().into())
-            }
+		/// Delete a file from an open LOC
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::delete_file())]
+		pub fn delete_file(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			hash: <T as pallet::Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			let loc = Self::loc(&loc_id).ok_or(Error::<T>::NotFound)?;
+			let item = Self::loc_file(loc_id, hash).ok_or(Error::<T>::ItemNotFound)?;
+			Self::ensure_can_delete(&loc, &who, &item.submitter, item.acknowledged_by_owner || item.acknowledged_by_verified_issuer)?;
+			let refund = if item.submitter == SupportedAccountId::Polkadot(who.clone()) { T::DeletedFileStorageRefund::get() } else { Percent::from_percent(100) };
+			Self::refund_file_storage(&loc_id, loc.loc_type, &hash, refund)?;
+			<LocFilesMap<T>>::remove(loc_id, hash);
+			Self::forget_item(&loc_id, LocItemKey::File(hash));
+			<LocMap<T>>::mutate(loc_id, |loc| {
+				let mutable_loc = loc.as_mut().unwrap();
+				mutable_loc.files_count = mutable_loc.files_count.saturating_sub(1);
+			});
+			Self::deposit_event(Event::FileDeleted(loc_id, hash));
+			Ok(().into())
 		}
 
-        /// Imports a collection item
-        #[pallet::call_index(27)]
-        #[pallet::weight(T::WeightInfo::import_collection_item())]
-        pub fn import_collection_item(
-            origin: OriginFor<T>,
-            #[pallet::compact] collection_loc_id: T::LocId,
-            item_id: T::CollectionItemId,
-            item_description: <T as Config>::Hash,
-            item_files: Vec<CollectionItemFileOf<T>>,
-            item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
-            restricted_delivery: bool,
-            terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+		/// Delete a link from an open LOC
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::delete_link())]
+		pub fn delete_link(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			#[pallet::compact] target: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			let loc = Self::loc(&loc_id).ok_or(Error::<T>::NotFound)?;
+			let item = Self::loc_link(loc_id, target).ok_or(Error::<T>::ItemNotFound)?;
+			Self::ensure_can_delete(&loc, &who, &item.submitter, item.acknowledged_by_owner || item.acknowledged_by_verified_issuer)?;
+			<LocLinksMap<T>>::remove(loc_id, target);
+			Self::forget_item(&loc_id, LocItemKey::Link(target));
+			<LocMap<T>>::mutate(loc_id, |loc| {
+				let mutable_loc = loc.as_mut().unwrap();
+				mutable_loc.links_count = mutable_loc.links_count.saturating_sub(1);
+			});
+			<LinksToLocMap<T>>::remove(target, loc_id);
+			Self::deposit_event(Event::LinkDeleted(loc_id, target));
+			Ok(().into())
+		}
 
-            if restricted_delivery && item_token.is_none() {
-                Err(Error::<T>::MissingToken)?
-            }
+		/// Acknowledge several items of a LOC at once, either as owner or as verified issuer.
+		/// Either all items are acknowledged or none.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::acknowledge_items(
+			metadata.len().saturating_add(files.len()).saturating_add(links.len()) as u32
+		))]
+		pub fn acknowledge_items(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			metadata: Vec<<T as pallet::Config>::Hash>,
+			files: Vec<<T as pallet::Config>::Hash>,
+			links: Vec<T::LocId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			Self::do_acknowledge_items(&who, loc_id, &metadata, &files, &links)
+		}
 
-            if restricted_delivery && item_files.len() == 0 {
-                Err(Error::<T>::MissingFiles)?
-            }
+		/// Add several items to an open LOC at once, storage fees being charged once for all files. Unless the
+		/// signer pays, the requester or the sponsor must have granted the signer a fee allowance covering the fees.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::add_items(
+			items.metadata.len() as u32,
+			items.files.len() as u32,
+			items.links.len() as u32,
+		))]
+		pub fn add_items(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			items: ItemsParamsOf<T>,
+			fee_payer: FeePayer,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			let mut loc = Self::loc(&loc_id).ok_or(Error::<T>::NotFound)?;
+			let published_by_owner: bool = Self::is_published_by_owner(&loc, &who)?;
+			let valid_submitters = items.metadata.iter().map(|item| &item.submitter)
+				.chain(items.files.iter().map(|item| &item.submitter))
+				.chain(items.links.iter().map(|item| &item.submitter))
+				.all(|submitter| Self::is_valid_submitter(&loc_id, &loc, submitter, published_by_owner));
+			if !valid_submitters {
+				Err(Error::<T>::CannotSubmit)?
+			} else if loc.closed {
+				Err(Error::<T>::CannotMutate)?
+			} else if loc.void_info.is_some() {
+				Err(Error::<T>::CannotMutateVoid)?
+			}
+			Self::ensure_can_add_items(&loc_id, &loc, &items)?;
+			Self::ensure_valid_links(&loc.owner, &items.links)?;
+			if !items.files.is_empty() {
+				let fees = Self::calculate_fee(items.files.len() as u32, items.files.iter().map(|file| file.size).sum());
+				let fee_payer_account = Self::fee_payer_account(&loc_id, &loc, &who, fee_payer, fees)?;
+				Self::apply_loc_files_storage_fee(&fee_payer_account, &loc_id, loc.loc_type, &items.files)?;
+			}
+			Self::insert_items(&loc_id, &mut loc, &who, &items, <frame_system::Pallet<T>>::block_number())?;
+			<LocMap<T>>::insert(loc_id, loc);
+			Self::index_links(&loc_id, items.links.iter().map(|link| link.id));
+			Self::deposit_event(Event::ItemsAdded(
+				loc_id,
+				items.metadata.iter().map(|item| item.name).collect(),
+				items.files.iter().map(|item| item.hash).collect(),
+				items.links.iter().map(|item| item.id).collect(),
+			));
+			Ok(().into())
+		}
 
-            let files_hashes: Vec<<T as Config>::Hash> = item_files.iter()
-                .map(|file| file.hash)
-                .collect();
-            if !Self::has_unique_elements(&files_hashes) {
-                Err(Error::<T>::DuplicateFile)?
-            }
+		/// Registers a LOC template. Collection templates must define a maximum size as the last block
+		/// for submission cannot be known in advance.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::register_template())]
+		pub fn register_template(
+			origin: OriginFor<T>,
+			template_id: <T as Config>::Hash,
+			loc_type: LocType,
+			metadata_names: Vec<<T as Config>::Hash>,
+			file_natures: Vec<<T as Config>::Hash>,
+			link_natures: Vec<<T as Config>::Hash>,
+			legal_fee: BalanceOf<T>,
+			value_fee: BalanceOf<T>,
+			collection_item_fee: BalanceOf<T>,
+			tokens_record_fee: BalanceOf<T>,
+			collection_max_size: Option<u32>,
+			collection_can_upload: bool,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
+
+			if <TemplatesMap<T>>::contains_key(&template_id) {
+				Err(Error::<T>::TemplateAlreadyExists)?
+			} else if loc_type == LocType::Collection && collection_max_size.is_none() {
+				Err(Error::<T>::CollectionHasNoLimit)?
+			} else if loc_type != LocType::Collection
+				&& (value_fee != 0_u32.into()
+					|| collection_item_fee != 0_u32.into()
+					|| tokens_record_fee != 0_u32.into()
+					|| collection_max_size.is_some()
+					|| collection_can_upload) {
+				Err(Error::<T>::UnexpectedTemplateCollectionParams)?
+			} else {
+				let template = LocTemplateOf::<T> {
+					owner: who.clone(),
+					loc_type,
+					metadata_names: BoundedVec::try_from(metadata_names).map_err(|_| Error::<T>::LocMetadataTooMuchData)?,
+					file_natures: BoundedVec::try_from(file_natures).map_err(|_| Error::<T>::LocFilesTooMuchData)?,
+					link_natures: BoundedVec::try_from(link_natures).map_err(|_| Error::<T>::LocLinksTooMuchData)?,
+					legal_fee,
+					value_fee,
+					collection_item_fee,
+					tokens_record_fee,
+					collection_max_size,
+					collection_can_upload,
+				};
+				<TemplatesMap<T>>::insert(template_id, template);
 
-            let bounded_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = BoundedVec::try_from(item_files)
-                .map_err(|_| Error::<T>::CollectionItemFilesTooMuchData)?;
-            let bounded_tcs: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTCs> = BoundedVec::try_from(terms_and_conditions)
-                .map_err(|_| Error::<T>::CollectionItemTCsTooMuchData)?;
-            if <CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
-                Err(Error::<T>::CollectionItemAlreadyExists)?
-            }
-            let item = CollectionItem {
-                description: item_description,
-                files: bounded_files,
-                token: item_token.clone(),
-                restricted_delivery,
-                terms_and_conditions: bounded_tcs,
-                imported: true,
-            };
-            <CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
+				Self::deposit_event(Event::TemplateRegistered(template_id, who));
+				Ok(().into())
+			}
+		}
+
+		/// Creates a new Polkadot LOC from a template. The legal officer, the LOC type, the fees and the collection
+		/// parameters are the ones of the template. The LOC cannot be closed until all items required by the template
+		/// are present and acknowledged by the owner.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::create_loc_from_template())]
+		pub fn create_loc_from_template(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			template_id: <T as Config>::Hash,
+			items: ItemsParamsOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let template = Self::template(&template_id).ok_or(Error::<T>::TemplateNotFound)?;
+			match template.loc_type {
+				LocType::Identity => Self::create_polkadot_identity_loc(origin, loc_id, template.owner, template.legal_fee, items)?,
+				LocType::Transaction => Self::create_polkadot_transaction_loc(origin, loc_id, template.owner, template.legal_fee, items)?,
+				LocType::Collection => Self::create_collection_loc(
+					origin,
+					loc_id,
+					template.owner,
+					None,
+					template.collection_max_size,
+					template.collection_can_upload,
+					template.value_fee,
+					template.legal_fee,
+					template.collection_item_fee,
+					template.tokens_record_fee,
+					items,
+				)?,
+			};
+			<LocTemplateMap<T>>::insert(loc_id, template_id);
+			Ok(().into())
+		}
+
+		/// Creates a new Polkadot Transaction LOC owned by several legal officers. Closing or voiding the LOC
+		/// as well as acknowledging its items requires the approval of `threshold` owners. The legal fee is
+		/// split between owners. Co-ownership is restricted to Polkadot Transaction LOCs, the LOCs certifying
+		/// high-value transactions: identity and collection LOCs always have a single owner.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::create_polkadot_co_owned_transaction_loc())]
+		pub fn create_polkadot_co_owned_transaction_loc(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			legal_officer: T::AccountId,
+			co_owners: Vec<T::AccountId>,
+			threshold: u32,
+			legal_fee: BalanceOf<T>,
+			items: ItemsParamsOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let distinct_co_owners: BTreeSet<&T::AccountId> = co_owners.iter().collect();
+			if co_owners.is_empty()
+				|| distinct_co_owners.len() != co_owners.len()
+				|| co_owners.contains(&legal_officer)
+				|| !co_owners.iter().all(|co_owner| T::IsLegalOfficer::is_legal_officer(co_owner)) {
+				Err(Error::<T>::InvalidCoOwners)?
+			} else if threshold == 0 || threshold > co_owners.len() as u32 + 1 {
+				Err(Error::<T>::InvalidThreshold)?
+			}
+			let co_ownership = CoOwnershipOf::<T> {
+				co_owners: BoundedVec::try_from(co_owners).map_err(|_| Error::<T>::LocCoOwnersTooMuchData)?,
+				threshold,
+			};
+			let co_owners = co_ownership.co_owners.clone();
+			<CoOwnershipMap<T>>::insert(loc_id, co_ownership);
+			Self::create_polkadot_transaction_loc(origin, loc_id, legal_officer, legal_fee, items)?;
+			for co_owner in co_owners.iter() {
+				<LocsByOwnerMap<T>>::insert(co_owner, loc_id, ());
+			}
+			Ok(().into())
+		}
+
+		/// Transfers up to `max_locs` LOCs owned or co-owned by a legal officer to another legal officer,
+		/// up to `max_issuers` of its verified issuer nominations and LOC issuer selections, as well as
+		/// the given pending sponsorships. The call may be repeated until all data are transferred.
+		/// On LOCs already owned or co-owned by the target, the source legal officer is merged into
+		/// the target: it is dropped from the co-owners and the approval threshold is capped by the
+		/// remaining number of owners.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::transfer_legal_officer_data(*max_locs, *max_issuers, sponsorship_ids.len() as u32))]
+		pub fn transfer_legal_officer_data(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			max_locs: u32,
+			max_issuers: u32,
+			sponsorship_ids: Vec<T::SponsorshipId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			T::TransferOrigin::ensure_origin(origin)?;
+			if from == to || !T::IsLegalOfficer::is_legal_officer(&to) {
+				Err(Error::<T>::InvalidTransferTarget)?
+			}
+
+			let loc_ids: Vec<T::LocId> = <LocsByOwnerMap<T>>::iter_key_prefix(&from)
+				.take(max_locs as usize)
+				.collect();
+			let mut remaining_issuers = max_issuers;
+			for loc_id in loc_ids.iter() {
+				if !Self::transfer_loc(loc_id, &from, &to, &mut remaining_issuers)? {
+					break;
+				}
+			}
+
+			let verified_issuers: Vec<(T::AccountId, VerifiedIssuerOf<T>)> = <VerifiedIssuersMap<T>>::iter_prefix(&from)
+				.take(remaining_issuers as usize)
+				.collect();
+			for (issuer, verified_issuer) in verified_issuers {
+				<VerifiedIssuersMap<T>>::remove(&from, &issuer);
+				if <VerifiedIssuersMap<T>>::contains_key(&to, &issuer) {
+					Self::deposit_event(Event::VerifiedIssuerAlreadyNominated(issuer, from.clone(), to.clone()));
+				} else {
+					<VerifiedIssuersMap<T>>::insert(&to, &issuer, verified_issuer);
+					Self::deposit_event(Event::VerifiedIssuerTransferred(issuer, from.clone(), to.clone()));
+				}
+			}
+
+			for sponsorship_id in sponsorship_ids.iter() {
+				<SponsorshipMap<T>>::try_mutate(sponsorship_id, |maybe_sponsorship| {
+					match maybe_sponsorship {
+						Some(sponsorship) if sponsorship.legal_officer == from && sponsorship.loc_id.is_none() => {
+							sponsorship.legal_officer = to.clone();
+							Ok(())
+						},
+						_ => Err(Error::<T>::CannotTransferSponsorship),
+					}
+				})?;
+				Self::deposit_event(Event::SponsorshipTransferred(*sponsorship_id, from.clone(), to.clone()));
+			}
+			Ok(().into())
+		}
+
+		/// Revokes a collection item. The item is kept but flagged as revoked for the given reason.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::revoke_collection_item())]
+		pub fn revoke_collection_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			reason: <T as Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			if !<CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemNotFound)?
+			}
+			if !Self::can_revoke_item(&collection_loc_id, &collection_loc, &who) {
+				Err(Error::<T>::Unauthorized)?
+			}
+			if <CollectionItemRevocationsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemAlreadyRevoked)?
+			}
+
+			<CollectionItemRevocationsMap<T>>::insert(collection_loc_id, item_id, CollectionItemRevocation {
+				revoked_by: who,
+				reason,
+				revoked_on: frame_system::Pallet::<T>::block_number(),
+			});
+
+			Self::deposit_event(Event::ItemRevoked(collection_loc_id, item_id, reason));
+			Ok(().into())
+		}
+
+		/// Removes a collection item added by mistake. Only the requester of the collection may remove
+		/// items, during the configured grace period following their addition. The collection must not be
+		/// void and the item not revoked. The item ID cannot be reused and the item still counts in the
+		/// collection size.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::remove_collection_item())]
+		pub fn remove_collection_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			if !<CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemNotFound)?
+			}
+			if collection_loc.requester != Requester::Account(who) {
+				Err(Error::<T>::Unauthorized)?
+			}
+			if collection_loc.void_info.is_some() {
+				Err(Error::<T>::CannotMutateVoid)?
+			}
+			if Self::is_collection_item_revoked(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemAlreadyRevoked)?
+			}
+			if <CollectionItemNativeTokensMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CannotRemoveItemWithNativeToken)?
+			}
+			if <CollectionItemTokensRecordsMap<T>>::iter_key_prefix((collection_loc_id, item_id))
+				.any(|record_id| !<TokensRecordRevocationsMap<T>>::contains_key(&collection_loc_id, &record_id)) {
+				Err(Error::<T>::CannotRemoveItemWithTokensRecords)?
+			}
+			let current_block = frame_system::Pallet::<T>::block_number();
+			match Self::collection_item_added_on(&collection_loc_id, &item_id) {
+				Some(added_on) if current_block <= added_on.saturating_add(T::CollectionItemRemovalGracePeriod::get()) => (),
+				_ => Err(Error::<T>::CollectionItemRemovalGracePeriodExpired)?,
+			}
+
+			Self::remove_collection_item_data(&collection_loc_id, &item_id);
+			<RemovedCollectionItemsMap<T>>::insert(&collection_loc_id, &item_id, current_block);
+
+			Self::deposit_event(Event::ItemRemoved(collection_loc_id, item_id));
+			Ok(().into())
+		}
+
+		/// Adds a new version of a collection item i.e. a new description and files. Previous versions
+		/// are kept. The collection must still accept submissions.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::update_collection_item())]
+		pub fn update_collection_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			item_description: <T as Config>::Hash,
+			item_files: Vec<CollectionItemFileOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			let item = Self::collection_items(&collection_loc_id, &item_id).ok_or(Error::<T>::CollectionItemNotFound)?;
+			if !Self::can_add_item(&who, &collection_loc) {
+				Err(Error::<T>::WrongCollectionLoc)?
+			}
+			if Self::is_collection_item_revoked(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CannotUpdateCollectionItem)?
+			}
+			if Self::collection_submission_closed(&collection_loc) {
+				Err(Error::<T>::CannotUpload)?
+			}
+			if item.restricted_delivery && item_files.len() == 0 {
+				Err(Error::<T>::MissingFiles)?
+			}
+			if !collection_loc.collection_can_upload && item_files.len() > 0 {
+				Err(Error::<T>::CannotUpload)?
+			}
+			let files_hashes: Vec<<T as Config>::Hash> = item_files.iter()
+				.map(|file| file.hash)
+				.collect();
+			if !Self::has_unique_elements(&files_hashes) {
+				Err(Error::<T>::DuplicateFile)?
+			}
+			let version = Self::collection_item_latest_version(&collection_loc_id, &item_id).unwrap_or(0) + 1;
+			if version > T::MaxCollectionItemVersions::get() {
+				Err(Error::<T>::CollectionItemVersionsTooMuchData)?
+			}
+
+			let tot_size = item_files.iter()
+				.map(|file| file.size)
+				.fold(0, |tot, current| tot + current);
+			Self::apply_file_storage_fee(&who, &collection_loc_id, collection_loc.loc_type, item_files.len(), tot_size)?;
+			if let Some(deposit) = Self::storage_deposit(item_files.len(), tot_size) {
+				<CollectionItemDepositsMap<T>>::insert((collection_loc_id, item_id, version), (who.clone(), deposit));
+			}
+
+			let bounded_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = BoundedVec::try_from(item_files)
+				.map_err(|_| Error::<T>::CollectionItemFilesTooMuchData)?;
+			<CollectionItemVersionsMap<T>>::insert((collection_loc_id, item_id), version, CollectionItemVersion {
+				description: item_description,
+				files: bounded_files,
+				updated_on: frame_system::Pallet::<T>::block_number(),
+			});
+			<CollectionItemLatestVersionMap<T>>::insert(&collection_loc_id, &item_id, version);
+
+			Self::deposit_event(Event::ItemUpdated(collection_loc_id, item_id, version));
+			Ok(().into())
+		}
+
+		/// Adds several items to a collection. Fees are charged once for the whole batch.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::add_collection_items(items.len() as u32)
+			.saturating_add(Pallet::<T>::terms_and_conditions_weight().saturating_mul(items.len() as u64))
+			.saturating_add(Pallet::<T>::native_token_weight().saturating_mul(items.len() as u64)))]
+		pub fn add_collection_items(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			items: BoundedVec<CollectionItemParamsOf<T>, T::MaxCollectionItemsPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			Self::do_add_collection_items(origin, collection_loc_id, items.into_inner())
+		}
+
+		/// Registers the initial owner of a collection item. Only the requester of the collection may
+		/// register owners, once per item. Items with a native token are owned by the holder of the token
+		/// and cannot be registered.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::set_collection_item_owner())]
+		pub fn set_collection_item_owner(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			if !<CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemNotFound)?
+			}
+			if collection_loc.requester != Requester::Account(who) {
+				Err(Error::<T>::Unauthorized)?
+			}
+			Self::ensure_can_change_item_owner(&collection_loc_id, &collection_loc, &item_id)?;
+			if <CollectionItemOwnersMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemOwnerAlreadySet)?
+			}
+
+			<CollectionItemOwnersMap<T>>::insert(&collection_loc_id, &item_id, owner.clone());
+
+			Self::deposit_event(Event::ItemOwnerSet(collection_loc_id, item_id, owner));
+			Ok(().into())
+		}
+
+		/// Transfers a collection item to a new owner. Only the current owner may transfer the item.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::transfer_collection_item())]
+		pub fn transfer_collection_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let current_owner = Self::collection_item_owner(&collection_loc_id, &item_id).ok_or(Error::<T>::CollectionItemHasNoOwner)?;
+			if current_owner != who {
+				Err(Error::<T>::NotCollectionItemOwner)?
+			}
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			Self::ensure_can_change_item_owner(&collection_loc_id, &collection_loc, &item_id)?;
+
+			<CollectionItemOwnersMap<T>>::insert(&collection_loc_id, &item_id, new_owner.clone());
+
+			Self::deposit_event(Event::ItemTransferred(collection_loc_id, item_id, current_owner, new_owner));
+			Ok(().into())
+		}
+
+		/// Transfers a collection item on legal order, registering its owner if it had none. Only the owner
+		/// of the collection or one of its co-owners may force a transfer.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::force_transfer_collection_item())]
+		pub fn force_transfer_collection_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			if !<CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+				Err(Error::<T>::CollectionItemNotFound)?
+			}
+			if !Self::is_owner_or_co_owner(&collection_loc_id, &collection_loc, &who) {
+				Err(Error::<T>::Unauthorized)?
+			}
+			Self::ensure_can_change_item_owner(&collection_loc_id, &collection_loc, &item_id)?;
+			let current_owner = Self::collection_item_owner(&collection_loc_id, &item_id);
+
+			<CollectionItemOwnersMap<T>>::insert(&collection_loc_id, &item_id, new_owner.clone());
+
+			Self::deposit_event(Event::ItemForceTransferred(collection_loc_id, item_id, current_owner, new_owner));
+			Ok(().into())
+		}
+
+		/// Revokes a tokens record. The record is kept but flagged as revoked for the given reason.
+		/// Only the submitter of the record, the owner of the collection or one of its co-owners may revoke it.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::revoke_tokens_record())]
+		pub fn revoke_tokens_record(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			record_id: T::TokensRecordId,
+			reason: <T as Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let collection_loc = Self::loc(&collection_loc_id).ok_or(Error::<T>::WrongCollectionLoc)?;
+			let record = Self::tokens_records(&collection_loc_id, &record_id).ok_or(Error::<T>::TokensRecordNotFound)?;
+			if record.submitter != who && !Self::is_owner_or_co_owner(&collection_loc_id, &collection_loc, &who) {
+				Err(Error::<T>::Unauthorized)?
+			}
+			if <TokensRecordRevocationsMap<T>>::contains_key(&collection_loc_id, &record_id) {
+				Err(Error::<T>::TokensRecordAlreadyRevoked)?
+			}
+
+			<TokensRecordRevocationsMap<T>>::insert(collection_loc_id, record_id, TokensRecordRevocation {
+				revoked_by: who,
+				reason,
+				revoked_on: frame_system::Pallet::<T>::block_number(),
+			});
+
+			Self::deposit_event(Event::TokensRecordRevoked(collection_loc_id, record_id, reason));
+			Ok(().into())
+		}
+
+		/// Claims the delivery of a file of a restricted delivery item. The claim succeeds only if the caller
+		/// holds the token of the item or is its registered owner, delivery services serving the file upon the event.
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::claim_restricted_delivery())]
+		pub fn claim_restricted_delivery(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			file_hash: <T as Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !Self::can_claim_restricted_delivery(&collection_loc_id, &item_id, &file_hash, &who)? {
+				Err(Error::<T>::CannotClaimRestrictedDelivery)?
+			}
+
+			Self::deposit_event(Event::RestrictedDeliveryClaimed(collection_loc_id, item_id, file_hash, who));
+			Ok(().into())
+		}
+
+		/// Claims access to the files of a tokens record through given collection item. The claim succeeds only
+		/// if the caller holds the token of the item or is its registered owner, and the record applies to the item.
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::claim_tokens_record_access())]
+		pub fn claim_tokens_record_access(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			record_id: T::TokensRecordId,
+			item_id: T::CollectionItemId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !Self::can_access_tokens_record(&collection_loc_id, &record_id, &item_id, &who)? {
+				Err(Error::<T>::CannotAccessTokensRecord)?
+			}
+
+			Self::deposit_event(Event::TokensRecordAccessClaimed(collection_loc_id, record_id, item_id, who));
+			Ok(().into())
+		}
+
+		/// Adds a tokens record which applies to given collection items only (all items if none given) and
+		/// optionally supersedes a previous record of the same collection.
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::add_tokens_record_with_targets(target_items.len() as u32))]
+		pub fn add_tokens_record_with_targets(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			record_id: T::TokensRecordId,
+			description: <T as Config>::Hash,
+			files: Vec<TokensRecordFileOf<T>>,
+			charge_submitter: bool,
+			target_items: Vec<T::CollectionItemId>,
+			supersedes: Option<T::TokensRecordId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			Self::do_add_tokens_record(origin, collection_loc_id, record_id, description, files, charge_submitter, target_items, supersedes)
+		}
+
+		/// Imports a tokens record with its target items and the record it supersedes
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::import_tokens_record_with_targets(target_items.len() as u32))]
+		pub fn import_tokens_record_with_targets(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			record_id: T::TokensRecordId,
+			description: <T as Config>::Hash,
+			files: Vec<TokensRecordFileOf<T>>,
+			submitter: T::AccountId,
+			target_items: Vec<T::CollectionItemId>,
+			supersedes: Option<T::TokensRecordId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			ensure_root(origin)?;
+			Self::do_import_tokens_record(collection_loc_id, record_id, description, files, submitter, target_items, supersedes)
+		}
+
+		/// Releases the storage deposits held for the files, collection items and tokens records of a void LOC,
+		/// removing them from state. At most `max_entries` files, items or records are removed, the call being
+		/// repeated until the LOC holds no more deposits. As the evidence held by the void LOC is erased, only
+		/// its owner may decide to release the deposits.
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::release_storage_deposits(*max_entries))]
+		pub fn release_storage_deposits(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			max_entries: u32,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+			let loc = Self::loc(&loc_id).ok_or(Error::<T>::NotFound)?;
+			if loc.owner != who {
+				Err(Error::<T>::Unauthorized)?
+			}
+			if loc.void_info.is_none() {
+				Err(Error::<T>::LocNotVoid)?
+			}
+			let mut remaining = max_entries as usize;
+
+			let files: Vec<(<T as Config>::Hash, (T::AccountId, BalanceOf<T>))> = <LocFileDepositsMap<T>>::iter_prefix(&loc_id)
+				.take(remaining)
+				.collect();
+			remaining = remaining.saturating_sub(files.len());
+			for (hash, (depositor, deposit)) in files.iter() {
+				<LocFileDepositsMap<T>>::remove(&loc_id, hash);
+				<LocFilesMap<T>>::remove(&loc_id, hash);
+				Self::forget_item(&loc_id, LocItemKey::File(*hash));
+				Self::release_deposit(&loc_id, depositor, *deposit);
+				Self::deposit_event(Event::FileDeleted(loc_id, *hash));
+			}
+			if !files.is_empty() {
+				<LocMap<T>>::mutate(&loc_id, |loc| {
+					let mutable_loc = loc.as_mut().unwrap();
+					mutable_loc.files_count = mutable_loc.files_count.saturating_sub(files.len() as u32);
+				});
+			}
+
+			let records: Vec<T::TokensRecordId> = <TokensRecordDepositsMap<T>>::iter_key_prefix(&loc_id)
+				.take(remaining)
+				.collect();
+			remaining = remaining.saturating_sub(records.len());
+			for record_id in records.iter() {
+				Self::remove_tokens_record_data(&loc_id, record_id);
+			}
+
+			let mut items: Vec<T::CollectionItemId> = Vec::new();
+			for (item_id, _) in <CollectionItemDepositsMap<T>>::iter_key_prefix((loc_id,)) {
+				if items.last() != Some(&item_id) {
+					if items.len() == remaining {
+						break;
+					}
+					items.push(item_id);
+				}
+			}
+			for item_id in items.iter() {
+				Self::remove_collection_item_data(&loc_id, item_id);
+				Self::deposit_event(Event::ItemRemoved(loc_id, *item_id));
+			}
+			Ok(().into())
+		}
+
+		/// Make a LOC void and refund the share of legal fee received by its owners. The LOC must not be
+		/// closed. Imported LOCs are voided without refund, their legal fee not having been charged by this chain.
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::make_void_with_refund())]
+		pub fn make_void_with_refund(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			Self::do_make_void(origin, loc_id, None, true)
+		}
+
+		/// Import LOC data.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::import_loc())]
+        pub fn import_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            requester: RequesterOf<T>,
+            legal_officer: T::AccountId,
+            loc_type: LocType,
+            items: ItemsOf<T>,
+            collection_last_block_submission: Option<BlockNumberFor<T>>,
+            collection_max_size: Option<u32>,
+            collection_can_upload: bool,
+            value_fee: BalanceOf<T>,
+            legal_fee: BalanceOf<T>,
+            collection_item_fee: BalanceOf<T>,
+            tokens_record_fee: BalanceOf<T>,
+            sponsorship_id: Option<T::SponsorshipId>,
+            seal: Option<<T as crate::pallet::Config>::Hash>,
+            void_info: Option<LocVoidInfo<T::LocId>>,
+            replacer_of: Option<T::LocId>,
+            closed: bool,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+
+            if <crate::pallet::LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::AlreadyExists)?
+            } else {
+                let mut loc;
+                if loc_type == LocType::Identity || loc_type == LocType::Transaction {
+                    loc = Self::build_open_loc(&legal_officer, &requester, loc_type, sponsorship_id, legal_fee);
+                } else {
+                    loc = Self::build_open_collection_loc(
+                        &legal_officer,
+                        &requester,
+                        collection_last_block_submission,
+                        collection_max_size,
+                        collection_can_upload,
+                        value_fee,
+                        legal_fee,
+                        collection_item_fee,
+                        tokens_record_fee,
+                    );
+                }
+                Self::ensure_can_import(&loc_id, &items)?;
+                Self::ensure_valid_imported_links(&legal_officer, &items.links)?;
+                Self::import_items(&loc_id, &mut loc, &items)?;
+                loc.closed = closed;
+                loc.seal = seal;
+                loc.void_info = void_info;
+                loc.replacer_of = replacer_of;
+                loc.imported = true;
+                loc.created_on = None;
+
+                <LocsByOwnerMap<T>>::insert(&loc.owner, loc_id, ());
+                <LocMap<T>>::insert(loc_id, loc);
+                Self::index_links(&loc_id, items.links.iter().map(|link| link.id));
+                match requester {
+                    Requester::Account(requester_account_id) => Self::link_with_account(&requester_account_id, &loc_id)?,
+                    _ => {},
+                };
+
+                Self::deposit_event(Event::LocImported(loc_id));
+                Ok(().into())
+            }
+		}
+
+        /// Imports a collection item
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::import_collection_item())]
+        pub fn import_collection_item(
+            origin: OriginFor<T>,
+            #[pallet::compact] collection_loc_id: T::LocId,
+            item_id: T::CollectionItemId,
+            item_description: <T as Config>::Hash,
+            item_files: Vec<CollectionItemFileOf<T>>,
+            item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
+            restricted_delivery: bool,
+            terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+
+            if restricted_delivery && item_token.is_none() {
+                Err(Error::<T>::MissingToken)?
+            }
+
+            if restricted_delivery && item_files.len() == 0 {
+                Err(Error::<T>::MissingFiles)?
+            }
+
+            let files_hashes: Vec<<T as Config>::Hash> = item_files.iter()
+                .map(|file| file.hash)
+                .collect();
+            if !Self::has_unique_elements(&files_hashes) {
+                Err(Error::<T>::DuplicateFile)?
+            }
+
+            let bounded_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = BoundedVec::try_from(item_files)
+                .map_err(|_| Error::<T>::CollectionItemFilesTooMuchData)?;
+            let bounded_tcs: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTCs> = BoundedVec::try_from(terms_and_conditions)
+                .map_err(|_| Error::<T>::CollectionItemTCsTooMuchData)?;
+            if Self::collection_item_id_used(&collection_loc_id, &item_id) {
+                Err(Error::<T>::CollectionItemAlreadyExists)?
+            }
+            let item = CollectionItem {
+                description: item_description,
+                files: bounded_files,
+                token: item_token.clone(),
+                restricted_delivery,
+                terms_and_conditions: bounded_tcs,
+                imported: true,
+            };
+            <CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
             let collection_size = <CollectionSizeMap<T>>::get(&collection_loc_id).unwrap_or(0);
             <CollectionSizeMap<T>>::insert(&collection_loc_id, collection_size + 1);
 
-            Self::deposit_event(Event::ItemImported(collection_loc_id, item_id));
-            Ok(().into())
+            Self::deposit_event(Event::ItemImported(collection_loc_id, item_id));
+            Ok(().into())
+        }
+
+        /// Imports a tokens record
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::import_tokens_record())]
+        pub fn import_tokens_record(
+            origin: OriginFor<T>,
+            #[pallet::compact] collection_loc_id: T::LocId,
+            record_id: T::TokensRecordId,
+            description: <T as Config>::Hash,
+            files: Vec<crate::TokensRecordFileOf<T>>,
+            submitter: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+            Self::do_import_tokens_record(collection_loc_id, record_id, description, files, submitter, Vec::new(), None)
+        }
+
+        /// Imports an invited contributor selection
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::import_invited_contributor_selection())]
+        pub fn import_invited_contributor_selection(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            invited_contributor: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+
+            let already_invited_contributor = Self::selected_invited_contributors(loc_id, &invited_contributor);
+            if already_invited_contributor.is_some() {
+                Err(Error::<T>::AlreadyExists)?
+            } else {
+                <InvitedContributorsByLocMap<T>>::insert(loc_id, &invited_contributor, ());
+            }
+            Ok(().into())
+        }
+
+        /// Import a verified issuer
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::import_verified_issuer())]
+        pub fn import_verified_issuer(
+            origin: OriginFor<T>,
+            legal_officer: T::AccountId,
+            issuer: T::AccountId,
+            #[pallet::compact] identity_loc_id: T::LocId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+
+            let existing_issuer = Self::verified_issuers(&legal_officer, &issuer);
+            if existing_issuer.is_some() {
+                Err(Error::<T>::AlreadyExists)?
+            } else {
+                <VerifiedIssuersMap<T>>::insert(&legal_officer, &issuer, VerifiedIssuer {
+                    identity_loc: identity_loc_id,
+                    imported: true,
+                });
+            }
+            Ok(().into())
+        }
+
+        /// Import a verified issuer selection
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::import_verified_issuer_selection())]
+        pub fn import_verified_issuer_selection(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            issuer: T::AccountId,
+            loc_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+
+            let already_issuer = Self::selected_verified_issuers(loc_id, &issuer);
+            if already_issuer.is_some() {
+                Err(Error::<T>::AlreadyExists)?
+            } else {
+                <VerifiedIssuersByLocMap<T>>::insert(loc_id, &issuer, ());
+                <LocsByVerifiedIssuerMap<T>>::insert((&issuer, loc_owner, loc_id), ());
+            }
+            Ok(().into())
+        }
+
+        /// Import a sponsorship.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::import_sponsorship())]
+        pub fn import_sponsorship(
+            origin: OriginFor<T>,
+            #[pallet::compact] sponsorship_id: T::SponsorshipId,
+            sponsor: T::AccountId,
+            sponsored_account: SupportedAccountId<T::AccountId, T::EthereumAddress>,
+            legal_officer: T::AccountId,
+            loc_id: Option<T::LocId>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_no_migration_in_progress()?;
+            ensure_root(origin)?;
+
+            if <SponsorshipMap<T>>::contains_key(&sponsorship_id) {
+                Err(Error::<T>::AlreadyExists)?
+            } else {
+                let sponsorship = Sponsorship {
+                    sponsor: sponsor.clone(),
+                    sponsored_account: sponsored_account.clone(),
+                    legal_officer,
+                    loc_id,
+                    imported: true,
+                };
+                <SponsorshipMap<T>>::insert(sponsorship_id, sponsorship);
+
+                Self::deposit_event(Event::SponsorshipImported(sponsorship_id));
+                Ok(().into())
+            }
+        }
+    }
+
+    impl<T: Config> LocQuery<T::LocId, <T as frame_system::Config>::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn has_closed_identity_locs(
+            account: &<T as frame_system::Config>::AccountId,
+            legal_officers: &Vec<<T as frame_system::Config>::AccountId>
+        ) -> Result<bool, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            Ok(Self::has_closed_identity_loc(account, &legal_officers[0]) && Self::has_closed_identity_loc(account, &legal_officers[1]))
+        }
+
+        fn get_loc(loc_id: &T::LocId) -> Result<Option<LegalOfficerCaseSummary<T::AccountId, BlockNumberFor<T>>>, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            let option_loc = <LocMap<T>>::get(&loc_id);
+
+            Ok(match option_loc {
+                Some(loc) => Some(LegalOfficerCaseSummary {
+                    owner: loc.owner,
+                    requester: match loc.requester {
+                        Account(account) => Some(account),
+                        _ => None
+                    },
+                    created_on: loc.created_on,
+                    closed_on: loc.closed_on,
+                    voided_on: loc.voided_on,
+                }),
+                _ => None
+            })
+        }
+    }
+
+    impl<T: Config> LocOwnership<<T as frame_system::Config>::AccountId> for Pallet<T> {
+        fn owns_locs(legal_officer: &<T as frame_system::Config>::AccountId) -> bool {
+            <LocsByOwnerMap<T>>::iter_key_prefix(legal_officer).next().is_some()
+        }
+    }
+
+    impl<T: Config> LocValidity<T::LocId, <T as frame_system::Config>::AccountId> for Pallet<T> {
+        fn loc_valid_with_owner(
+            loc_id: &<T as pallet::Config>::LocId,
+            legal_officer: &<T as frame_system::Config>::AccountId,
+        ) -> Result<bool, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            Ok(Self::loc_valid_with_owner(&loc_id, &legal_officer))
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+
+        fn ensure_no_migration_in_progress() -> Result<(), Error<T>> {
+            if MigrationInProgress::<T>::exists() {
+                Err(Error::<T>::MigrationInProgress)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// The weight of a migration step, excluding the entries it processes: the status is read and
+        /// written, the storage version being written on completion.
+        fn migration_step_base_weight() -> Weight {
+            T::DbWeight::get().reads_writes(1, 2)
+        }
+
+        fn step_migration(limit: Weight) -> Weight {
+            let maybe_status = MigrationInProgress::<T>::get();
+            if maybe_status.is_none() {
+                return T::DbWeight::get().reads(1);
+            }
+            let mut status = maybe_status.unwrap();
+            let mut meter = WeightMeter::with_limit(limit);
+            meter.consume(Self::migration_step_base_weight());
+
+            let to_version = T::SteppedMigration::TO_VERSION;
+            let completed = T::SteppedMigration::step(&mut status, &mut meter);
+            if completed {
+                MigrationInProgress::<T>::kill();
+                StorageVersion::new(to_version).put::<Pallet<T>>();
+                Self::deposit_event(Event::MigrationCompleted(to_version));
+                log::info!("✅ Stepped migration to version {} completed, {} entries processed", to_version, status.processed);
+            } else {
+                Self::deposit_event(Event::MigrationAdvanced(to_version, status.processed));
+                MigrationInProgress::<T>::put(status);
+            }
+            meter.consumed()
+        }
+
+        fn do_add_file(
+            origin: OriginFor<T>,
+            loc_id: T::LocId,
+            file: FileParamsOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                let published_by_owner: bool = Self::is_published_by_owner(&loc, &who)?;
+                if !Self::is_valid_submitter(&loc_id, &loc, &file.submitter, published_by_owner) {
+                    Err(Error::<T>::CannotSubmit)?
+                } else if loc.closed {
+                    Err(Error::<T>::CannotMutate)?
+                } else if loc.void_info.is_some() {
+                    Err(Error::<T>::CannotMutateVoid)?
+                } else {
+                    Self::ensure_can_add_files(&loc_id, &loc, &Vec::from([file.hash]))?;
+                    let fees = Self::calculate_fee(1, file.size);
+                    let fee_payer_account = Self::default_fee_payer_account(&loc)?;
+                    if fee_payer_account != who {
+                        Self::consume_fee_allowance(&loc_id, &fee_payer_account, &who, fees, false)?;
+                    }
+                    Self::apply_loc_files_storage_fee(&fee_payer_account, &loc_id, loc.loc_type, &Vec::from([file.clone()]))?;
+                    <LocMap<T>>::try_mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        Self::insert_file(&loc_id, mutable_loc, &who, &file, <frame_system::Pallet<T>>::block_number())
+                    })?;
+                    Ok(().into())
+                }
+            }
+        }
+
+        fn do_make_void(
+            origin: OriginFor<T>,
+            loc_id: T::LocId,
+            replacer_loc_id: Option<T::LocId>,
+            refund_legal_fee: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if !Self::is_owner_or_co_owner(&loc_id, &loc, &who) {
+                    Err(Error::<T>::Unauthorized)?
+                }
+                if loc.void_info.is_some() {
+                    Err(Error::<T>::AlreadyVoid)?
+                }
+                if refund_legal_fee && loc.closed {
+                    Err(Error::<T>::CannotRefundClosedLoc)?
+                }
+
+                if replacer_loc_id.is_some() {
+                    let replacer = replacer_loc_id.unwrap();
+                    if !<LocMap<T>>::contains_key(&replacer) {
+                        Err(Error::<T>::ReplacerLocNotFound)?
+                    } else {
+                        let replacer_loc = <LocMap<T>>::get(&replacer).unwrap();
+                        if replacer_loc.void_info.is_some() {
+                            Err(Error::<T>::ReplacerLocAlreadyVoid)?
+                        }
+                        if replacer_loc.replacer_of.is_some() {
+                            Err(Error::<T>::ReplacerLocAlreadyReplacing)?
+                        }
+                        if !replacer_loc.loc_type.eq(&loc.loc_type) {
+                            Err(Error::<T>::ReplacerLocWrongType)?
+                        }
+                    }
+                }
+
+                if !Self::approve_owner_action(&loc_id, OwnerAction::Void { replacer: replacer_loc_id, refund_legal_fee }, &who)? {
+                    return Ok(().into());
+                }
+
+                let loc_void_info = LocVoidInfo {
+                    replacer:replacer_loc_id
+                };
+                <LocMap<T>>::mutate(loc_id, |loc| {
+                    let mutable_loc = loc.as_mut().unwrap();
+                    mutable_loc.void_info = Some(loc_void_info);
+                    mutable_loc.voided_on = Some(<frame_system::Pallet<T>>::block_number());
+                });
+                Self::clear_owner_approvals(&loc_id);
+                if replacer_loc_id.is_some() {
+                    <LocMap<T>>::mutate(replacer_loc_id.unwrap(), |replacer_loc| {
+                        let mutable_replacer_loc = replacer_loc.as_mut().unwrap();
+                        mutable_replacer_loc.replacer_of = Some(loc_id);
+                    });
+                }
+
+                if refund_legal_fee && !loc.imported {
+                    Self::refund_legal_fee(&loc_id, &loc)?;
+                }
+
+                if loc.loc_type == LocType::Collection && !loc.closed && loc.value_fee > 0_u32.into() {
+                    match loc.requester {
+                        Account(requester_account) => {
+                            T::Currency::unreserve(&requester_account, loc.value_fee);
+                            Self::deposit_event(Event::ValueFeeUnreserved(requester_account, loc.value_fee));
+                        },
+                        _ => {},
+                    }
+                }
+
+                Self::deposit_event(Event::LocVoid(loc_id));
+                let max_reported = T::MaxReportedLinkingLocs::get() as usize;
+                let mut linking_locs: Vec<T::LocId> = <LinksToLocMap<T>>::iter_key_prefix(&loc_id)
+                    .take(max_reported.saturating_add(1))
+                    .collect();
+                if !linking_locs.is_empty() {
+                    let more = linking_locs.len() > max_reported;
+                    linking_locs.truncate(max_reported);
+                    Self::deposit_event(Event::VoidedLocLinkedBy(loc_id, BoundedVec::truncate_from(linking_locs), more));
+                }
+                Ok(().into())
+            }
+        }
+
+        fn has_closed_identity_loc(
+            account: &<T as frame_system::Config>::AccountId,
+            legal_officer: &<T as frame_system::Config>::AccountId
+        ) -> bool {
+            let value = <AccountLocsMap<T>>::get(account);
+            match value {
+                Some(loc_ids) => {
+                    return loc_ids.iter().map(|id| <LocMap<T>>::get(id))
+                        .filter(|option| option.is_some())
+                        .map(|some| some.unwrap())
+                        .find(|loc| loc.owner == *legal_officer && loc.loc_type == LocType::Identity && loc.closed)
+                        .is_some();
+                }
+                None => false
+            }
+        }
+
+        fn loc_valid_with_owner(
+            loc_id: &<T as Config>::LocId,
+            legal_officer: &<T as frame_system::Config>::AccountId
+        ) -> bool {
+            let loc = <LocMap<T>>::get(loc_id);
+            match loc {
+                Some(loc) => {
+                    return loc.closed && loc.void_info.is_none() && loc.owner == *legal_officer;
+                }
+                None => false
+            }
+        }
+
+        fn link_with_account(
+            account_id: &<T as frame_system::Config>::AccountId,
+            loc_id: &<T as Config>::LocId,
+        ) -> Result<(), sp_runtime::DispatchError> {
+            if <AccountLocsMap<T>>::contains_key(account_id) {
+                <AccountLocsMap<T>>::mutate(account_id, |locs| {
+                    let list = locs.as_mut().unwrap();
+                    list.try_push(loc_id.clone())
+                }).map_err(|_| Error::<T>::AccountLocsTooMuchData)?;
+				Ok(())
+            } else {
+				let mut list: BoundedVec<<T as Config>::LocId, <T as Config>::MaxAccountLocs> = BoundedVec::new();
+				list.try_push(loc_id.clone())
+					.map_err(|_| Error::<T>::AccountLocsTooMuchData)?;
+				<AccountLocsMap<T>>::insert(account_id, list);
+				Ok(())
+            }
+        }
+
+        fn is_valid_logion_id(loc: &LegalOfficerCaseOf<T>) -> bool {
+            loc.loc_type != LocType::Identity
+                || match loc.requester { RequesterOf::<T>::None => false, _ => true }
+                || !loc.closed
+                || loc.void_info.is_some()
+        }
+
+        fn build_open_loc(
+            legal_officer: &T::AccountId,
+            requester: &RequesterOf<T>,
+            loc_type: LocType,
+            sponsorship_id: Option<T::SponsorshipId>,
+            legal_fee: BalanceOf<T>,
+        ) -> LegalOfficerCaseOf<T> {
+            LegalOfficerCaseOf::<T> {
+                owner: legal_officer.clone(),
+                requester: requester.clone(),
+                metadata_count: 0,
+                files_count: 0,
+                closed: false,
+                loc_type: loc_type.clone(),
+                links_count: 0,
+                void_info: None,
+                replacer_of: None,
+                collection_last_block_submission: None,
+                collection_max_size: None,
+                collection_can_upload: false,
+                seal: None,
+                sponsorship_id: sponsorship_id.clone(),
+                value_fee: 0u32.into(),
+                legal_fee: legal_fee.clone(),
+                collection_item_fee: 0u32.into(),
+                tokens_record_fee: 0u32.into(),
+                imported: false,
+                created_on: Some(<frame_system::Pallet<T>>::block_number()),
+                closed_on: None,
+                voided_on: None,
+            }
+        }
+
+        fn build_open_collection_loc(
+            who: &T::AccountId,
+            requester: &RequesterOf<T>,
+            collection_last_block_submission: Option<BlockNumberFor<T>>,
+            collection_max_size: Option<CollectionSize>,
+            collection_can_upload: bool,
+            value_fee: BalanceOf<T>,
+            legal_fee: BalanceOf<T>,
+            collection_item_fee: BalanceOf<T>,
+            tokens_record_fee: BalanceOf<T>,
+        ) -> LegalOfficerCaseOf<T> {
+            LegalOfficerCaseOf::<T> {
+                owner: who.clone(),
+                requester: requester.clone(),
+                metadata_count: 0,
+                files_count: 0,
+                closed: false,
+                loc_type: LocType::Collection,
+                links_count: 0,
+                void_info: None,
+                replacer_of: None,
+                collection_last_block_submission: collection_last_block_submission.clone(),
+                collection_max_size: collection_max_size.clone(),
+                collection_can_upload,
+                seal: None,
+                sponsorship_id: None,
+                value_fee,
+                legal_fee,
+                collection_item_fee,
+                tokens_record_fee,
+                imported: false,
+                created_on: Some(<frame_system::Pallet<T>>::block_number()),
+                closed_on: None,
+                voided_on: None,
+            }
+        }
+
+        fn ensure_can_add(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, items: &ItemsParamsOf<T>) -> DispatchResult {
+            loc.ensure_requester_submits::<T>(items)?;
+            Self::ensure_can_add_items(loc_id, loc, items)
+        }
+
+        fn ensure_can_add_items(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, items: &ItemsParamsOf<T>) -> DispatchResult {
+            Self::ensure_can_add_metadata(loc_id, loc, &items.metadata.iter().map(|item| item.name).collect())?;
+            Self::ensure_can_add_files(loc_id, loc, &items.files.iter().map(|item| item.hash).collect())?;
+            Self::ensure_can_add_links(loc_id, loc, &items.links.iter().map(|item| item.id).collect())?;
+            Ok(())
+        }
+
+        fn ensure_can_add_metadata(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, metadata_names: &Vec<<T as Config>::Hash>) -> DispatchResult {
+            let keys: BTreeSet<&<T as Config>::Hash> = metadata_names.iter().collect();
+            if keys.len() < metadata_names.len()
+                || metadata_names.iter().any(|name| <LocMetadataMap<T>>::contains_key(loc_id, name)) {
+                Err(Error::<T>::DuplicateLocMetadata)?
+            }
+            ensure!(loc.metadata_count.saturating_add(metadata_names.len() as u32) <= T::MaxLocMetadata::get(), Error::<T>::LocMetadataTooMuchData);
+            Ok(())
+        }
+
+        fn ensure_can_add_files(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, file_hashes: &Vec<<T as Config>::Hash>) -> DispatchResult {
+            let keys: BTreeSet<&<T as Config>::Hash> = file_hashes.iter().collect();
+            if keys.len() < file_hashes.len()
+                || file_hashes.iter().any(|hash| <LocFilesMap<T>>::contains_key(loc_id, hash)) {
+                Err(Error::<T>::DuplicateLocFile)?
+            }
+            ensure!(loc.files_count.saturating_add(file_hashes.len() as u32) <= T::MaxLocFiles::get(), Error::<T>::LocFilesTooMuchData);
+            Ok(())
+        }
+
+        fn ensure_can_add_links(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, link_ids: &Vec<T::LocId>) -> DispatchResult {
+            let keys: BTreeSet<&T::LocId> = link_ids.iter().collect();
+            if keys.len() < link_ids.len()
+                || link_ids.iter().any(|id| <LocLinksMap<T>>::contains_key(loc_id, id)) {
+                Err(Error::<T>::DuplicateLocLink)?
+            }
+            ensure!(loc.links_count.saturating_add(link_ids.len() as u32) <= T::MaxLocLinks::get(), Error::<T>::LocLinksTooMuchData);
+            Ok(())
+        }
+
+        fn insert_items(
+            loc_id: &T::LocId,
+            loc: &mut LegalOfficerCaseOf<T>,
+            origin: &T::AccountId,
+            items: &ItemsParamsOf<T>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            for item in items.metadata.iter() {
+                Self::insert_metadata(loc_id, loc, origin, item, block_number)?;
+            }
+            for item in items.files.iter() {
+                Self::insert_file(loc_id, loc, origin, item, block_number)?;
+            }
+            for item in items.links.iter() {
+                Self::insert_link(loc_id, loc, origin, item, block_number)?;
+            }
+            Ok(())
+        }
+
+        fn insert_metadata(
+            loc_id: &T::LocId,
+            loc: &mut LegalOfficerCaseOf<T>,
+            origin: &T::AccountId,
+            item: &MetadataItemParamsOf<T>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure!(loc.metadata_count < T::MaxLocMetadata::get(), Error::<T>::LocMetadataTooMuchData);
+            let acknowledged_by_owner = loc.is_owner(origin)
+                && Self::acknowledge_as_owner(loc_id, loc, LocItemKey::Metadata(item.name), origin)?;
+            <LocMetadataMap<T>>::insert(loc_id, item.name, MetadataItem {
+                name: item.name,
+                value: item.value,
+                submitter: item.submitter.clone(),
+                acknowledged_by_owner,
+                acknowledged_by_verified_issuer: false,
+                submitted_on: Some(block_number),
+                acknowledged_by_owner_on: if acknowledged_by_owner { Some(block_number) } else { None },
+                acknowledged_by_verified_issuer_on: None,
+            });
+            Self::record_item_position(loc_id, LocItemKey::Metadata(item.name));
+            loc.metadata_count += 1;
+            Self::deposit_event(Event::MetadataAdded(*loc_id, item.name, item.submitter.clone()));
+            Ok(())
         }
 
-        /// Imports a tokens record
-        #[pallet::call_index(28)]
-        #[pallet::weight(T::WeightInfo::import_tokens_record())]
-        pub fn import_tokens_record(
-            origin: OriginFor<T>,
-            #[pallet::compact] collection_loc_id: T::LocId,
-            record_id: T::TokensRecordId,
-            description: <T as Config>::Hash,
-            files: Vec<crate::TokensRecordFileOf<T>>,
-            submitter: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+        fn insert_file(
+            loc_id: &T::LocId,
+            loc: &mut LegalOfficerCaseOf<T>,
+            origin: &T::AccountId,
+            file: &FileParamsOf<T>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure!(loc.files_count < T::MaxLocFiles::get(), Error::<T>::LocFilesTooMuchData);
+            let acknowledged_by_owner = loc.is_owner(origin)
+                && Self::acknowledge_as_owner(loc_id, loc, LocItemKey::File(file.hash), origin)?;
+            <LocFilesMap<T>>::insert(loc_id, file.hash, File {
+                hash: file.hash,
+                nature: file.nature,
+                submitter: file.submitter.clone(),
+                size: file.size,
+                acknowledged_by_owner,
+                acknowledged_by_verified_issuer: false,
+                submitted_on: Some(block_number),
+                acknowledged_by_owner_on: if acknowledged_by_owner { Some(block_number) } else { None },
+                acknowledged_by_verified_issuer_on: None,
+            });
+            Self::record_item_position(loc_id, LocItemKey::File(file.hash));
+            loc.files_count += 1;
+            Self::deposit_event(Event::FileAdded(*loc_id, file.hash, file.submitter.clone()));
+            Ok(())
+        }
 
-            if <crate::pallet::TokensRecordsMap<T>>::contains_key(&collection_loc_id, &record_id) {
-                Err(crate::pallet::Error::<T>::TokensRecordAlreadyExists)?
+        fn insert_link(
+            loc_id: &T::LocId,
+            loc: &mut LegalOfficerCaseOf<T>,
+            origin: &T::AccountId,
+            link: &LocLinkParamsOf<T>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure!(loc.links_count < T::MaxLocLinks::get(), Error::<T>::LocLinksTooMuchData);
+            let acknowledged_by_owner = loc.is_owner(origin)
+                && Self::acknowledge_as_owner(loc_id, loc, LocItemKey::Link(link.id), origin)?;
+            <LocLinksMap<T>>::insert(loc_id, link.id, LocLink {
+                id: link.id,
+                nature: link.nature,
+                submitter: link.submitter.clone(),
+                acknowledged_by_owner,
+                acknowledged_by_verified_issuer: false,
+                submitted_on: Some(block_number),
+                acknowledged_by_owner_on: if acknowledged_by_owner { Some(block_number) } else { None },
+                acknowledged_by_verified_issuer_on: None,
+            });
+            Self::record_item_position(loc_id, LocItemKey::Link(link.id));
+            loc.links_count += 1;
+            Self::deposit_event(Event::LinkAdded(*loc_id, link.id, link.submitter.clone()));
+            Ok(())
+        }
+
+        pub(crate) fn record_item_position(loc_id: &T::LocId, key: LocItemKeyOf<T>) {
+            let position = <NextLocItemPositionMap<T>>::get(loc_id);
+            <LocItemPositionsMap<T>>::insert(loc_id, key, position);
+            <NextLocItemPositionMap<T>>::insert(loc_id, position.saturating_add(1));
+        }
+
+        /// Removes the position and the owner acknowledgements of a deleted item.
+        fn forget_item(loc_id: &T::LocId, key: LocItemKeyOf<T>) {
+            <LocItemPositionsMap<T>>::remove(loc_id, &key);
+            let _ = <OwnerAcknowledgementsMap<T>>::clear_prefix((loc_id, key), T::MaxLocCoOwners::get().saturating_add(1), None);
+        }
+
+        /// The keys of the items of a LOC, in order of addition.
+        pub fn loc_item_keys(loc_id: &T::LocId) -> Vec<LocItemKeyOf<T>> {
+            let mut positions: Vec<(LocItemKeyOf<T>, u32)> = <LocItemPositionsMap<T>>::iter_prefix(loc_id).collect();
+            positions.sort_by_key(|(_, position)| *position);
+            positions.into_iter().map(|(key, _)| key).collect()
+        }
+
+        fn ensure_can_import(loc_id: &T::LocId, items: &ItemsOf<T>) -> DispatchResult {
+            let metadata_names: BTreeSet<&<T as Config>::Hash> = items.metadata.iter().map(|item| &item.name).collect();
+            if metadata_names.len() < items.metadata.len()
+                || items.metadata.iter().any(|item| <LocMetadataMap<T>>::contains_key(loc_id, item.name)) {
+                Err(Error::<T>::DuplicateLocMetadata)?
             }
-            if files.len() == 0 {
-                Err(crate::pallet::Error::<T>::MustUpload)?
-            } else {
-                let files_hashes: Vec<<T as Config>::Hash> = files.iter()
-                    .map(|file| file.hash)
-                    .collect();
-                if !Self::has_unique_elements(&files_hashes) {
-                    Err(crate::pallet::Error::<T>::DuplicateFile)?
-                }
+            let file_hashes: BTreeSet<&<T as Config>::Hash> = items.files.iter().map(|item| &item.hash).collect();
+            if file_hashes.len() < items.files.len()
+                || items.files.iter().any(|item| <LocFilesMap<T>>::contains_key(loc_id, item.hash)) {
+                Err(Error::<T>::DuplicateLocFile)?
+            }
+            let link_ids: BTreeSet<&T::LocId> = items.links.iter().map(|item| &item.id).collect();
+            if link_ids.len() < items.links.len()
+                || items.links.iter().any(|item| <LocLinksMap<T>>::contains_key(loc_id, item.id)) {
+                Err(Error::<T>::DuplicateLocLink)?
             }
+            Ok(())
+        }
 
-            let mut bounded_files: BoundedVec<crate::TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(files.len());
-            for file in files.iter() {
-                bounded_files.try_push(file.clone()).map_err(|_| crate::pallet::Error::<T>::TokensRecordTooMuchData)?;
+        fn import_items(loc_id: &T::LocId, loc: &mut LegalOfficerCaseOf<T>, items: &ItemsOf<T>) -> DispatchResult {
+            ensure!(items.metadata.len() <= T::MaxLocMetadata::get() as usize, Error::<T>::LocMetadataTooMuchData);
+            ensure!(items.files.len() <= T::MaxLocFiles::get() as usize, Error::<T>::LocFilesTooMuchData);
+            ensure!(items.links.len() <= T::MaxLocLinks::get() as usize, Error::<T>::LocLinksTooMuchData);
+            for item in items.metadata.iter() {
+                <LocMetadataMap<T>>::insert(loc_id, item.name, item.clone());
+                Self::record_item_position(loc_id, LocItemKey::Metadata(item.name));
+            }
+            for item in items.files.iter() {
+                <LocFilesMap<T>>::insert(loc_id, item.hash, item.clone());
+                Self::record_item_position(loc_id, LocItemKey::File(item.hash));
             }
+            for item in items.links.iter() {
+                <LocLinksMap<T>>::insert(loc_id, item.id, item.clone());
+                Self::record_item_position(loc_id, LocItemKey::Link(item.id));
+            }
+            loc.metadata_count = items.metadata.len() as u32;
+            loc.files_count = items.files.len() as u32;
+            loc.links_count = items.links.len() as u32;
+            Ok(())
+        }
 
-            let record = crate::TokensRecord {
-                description,
-                files: bounded_files,
-                submitter,
-                imported: true,
-            };
-            <TokensRecordsMap<T>>::insert(collection_loc_id, record_id, record);
+        /// The number of items of a LOC, which bounds the walks over its items.
+        fn items_count(loc: &LegalOfficerCaseOf<T>) -> u32 {
+            loc.metadata_count.saturating_add(loc.files_count).saturating_add(loc.links_count)
+        }
 
-            Self::deposit_event(Event::TokensRecordImported(collection_loc_id, record_id));
-            Ok(().into())
+        fn has_items_unacknowledged_by_owner(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> bool {
+            <LocFilesMap<T>>::iter_prefix_values(loc_id).take(loc.files_count as usize).any(|file| !file.acknowledged_by_owner)
+                || <LocMetadataMap<T>>::iter_prefix_values(loc_id).take(loc.metadata_count as usize).any(|item| !item.acknowledged_by_owner)
+                || <LocLinksMap<T>>::iter_prefix_values(loc_id).take(loc.links_count as usize).any(|link| !link.acknowledged_by_owner)
         }
 
-        /// Imports an invited contributor selection
-        #[pallet::call_index(29)]
-        #[pallet::weight(T::WeightInfo::import_invited_contributor_selection())]
-        pub fn import_invited_contributor_selection(
-            origin: OriginFor<T>,
-            #[pallet::compact] loc_id: T::LocId,
-            invited_contributor: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+        fn has_items_unacknowledged_by_verified_issuer(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> bool {
+            <LocFilesMap<T>>::iter_prefix_values(loc_id)
+                .take(loc.files_count as usize)
+                .any(|file| loc.is_submitted_by_verified_issuer(&file.submitter) && !file.acknowledged_by_verified_issuer)
+                || <LocMetadataMap<T>>::iter_prefix_values(loc_id)
+                    .take(loc.metadata_count as usize)
+                    .any(|item| loc.is_submitted_by_verified_issuer(&item.submitter) && !item.acknowledged_by_verified_issuer)
+                || <LocLinksMap<T>>::iter_prefix_values(loc_id)
+                    .take(loc.links_count as usize)
+                    .any(|link| loc.is_submitted_by_verified_issuer(&link.submitter) && !link.acknowledged_by_verified_issuer)
+        }
 
-            let already_invited_contributor = Self::selected_invited_contributors(loc_id, &invited_contributor);
-            if already_invited_contributor.is_some() {
-                Err(Error::<T>::AlreadyExists)?
-            } else {
-                <InvitedContributorsByLocMap<T>>::insert(loc_id, &invited_contributor, ());
+        /// Tells if an item required by the template is missing or not acknowledged by owner, walking
+        /// the files and links of the LOC only once.
+        fn has_missing_template_items(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, template: &LocTemplateOf<T>) -> bool {
+            if template.metadata_names.iter()
+                .any(|name| !Self::loc_metadata(loc_id, name).map_or(false, |item| item.acknowledged_by_owner)) {
+                return true;
             }
-            Ok(().into())
+            let file_natures: BTreeSet<<T as Config>::Hash> = if template.file_natures.is_empty() {
+                BTreeSet::new()
+            } else {
+                <LocFilesMap<T>>::iter_prefix_values(loc_id)
+                    .take(loc.files_count as usize)
+                    .filter(|file| file.acknowledged_by_owner)
+                    .map(|file| file.nature)
+                    .collect()
+            };
+            let link_natures: BTreeSet<<T as Config>::Hash> = if template.link_natures.is_empty() {
+                BTreeSet::new()
+            } else {
+                <LocLinksMap<T>>::iter_prefix_values(loc_id)
+                    .take(loc.links_count as usize)
+                    .filter(|link| link.acknowledged_by_owner)
+                    .map(|link| link.nature)
+                    .collect()
+            };
+            template.file_natures.iter().any(|nature| !file_natures.contains(nature))
+                || template.link_natures.iter().any(|nature| !link_natures.contains(nature))
         }
 
-        /// Import a verified issuer
-        #[pallet::call_index(30)]
-        #[pallet::weight(T::WeightInfo::import_verified_issuer())]
-        pub fn import_verified_issuer(
-            origin: OriginFor<T>,
-            legal_officer: T::AccountId,
-            issuer: T::AccountId,
-            #[pallet::compact] identity_loc_id: T::LocId,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+        fn can_add_item(who: &T::AccountId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
+            collection_loc.loc_type == LocType::Collection
+                && match &collection_loc.requester { Requester::Account(requester) => requester == who, _ => false }
+                && collection_loc.closed
+                && collection_loc.void_info.is_none()
+        }
 
-            let existing_issuer = Self::verified_issuers(&legal_officer, &issuer);
-            if existing_issuer.is_some() {
-                Err(Error::<T>::AlreadyExists)?
-            } else {
-                <VerifiedIssuersMap<T>>::insert(&legal_officer, &issuer, VerifiedIssuer {
-                    identity_loc: identity_loc_id,
-                    imported: true,
-                });
-            }
-            Ok(().into())
+        fn can_revoke_item(collection_loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>, who: &T::AccountId) -> bool {
+            Self::is_owner_or_co_owner(collection_loc_id, collection_loc, who)
+                || (T::RequesterCanRevokeCollectionItems::get() && collection_loc.requester == Requester::Account(who.clone()))
         }
 
-        /// Import a verified issuer selection
-        #[pallet::call_index(31)]
-        #[pallet::weight(T::WeightInfo::import_verified_issuer_selection())]
-        pub fn import_verified_issuer_selection(
-            origin: OriginFor<T>,
-            #[pallet::compact] loc_id: T::LocId,
-            issuer: T::AccountId,
-            loc_owner: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+        /// Tells if a collection item was revoked.
+        pub fn is_collection_item_revoked(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId) -> bool {
+            <CollectionItemRevocationsMap<T>>::contains_key(collection_loc_id, item_id)
+        }
 
-            let already_issuer = Self::selected_verified_issuers(loc_id, &issuer);
-            if already_issuer.is_some() {
-                Err(Error::<T>::AlreadyExists)?
-            } else {
-                <VerifiedIssuersByLocMap<T>>::insert(loc_id, &issuer, ());
-                <LocsByVerifiedIssuerMap<T>>::insert((&issuer, loc_owner, loc_id), ());
+        /// Tells if given account may claim the delivery of given file of a restricted delivery item i.e. if
+        /// the item is still valid, the file belongs to one of its versions and the account holds its token
+        /// or is its registered owner.
+        pub fn can_claim_restricted_delivery(
+            collection_loc_id: &T::LocId,
+            item_id: &T::CollectionItemId,
+            file_hash: &<T as Config>::Hash,
+            account: &T::AccountId,
+        ) -> Result<bool, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            Ok(match Self::collection_items(collection_loc_id, item_id) {
+                Some(item) => item.restricted_delivery
+                    && !Self::is_collection_item_revoked(collection_loc_id, item_id)
+                    && Self::item_has_file(collection_loc_id, item_id, &item, file_hash)
+                    && (Self::owns_item_token(collection_loc_id, item_id, &item, account) || Self::is_collection_item_owner(collection_loc_id, item_id, account)),
+                None => false,
+            })
+        }
+
+        /// Tells if given account may access the files of given tokens record, as the holder of the token
+        /// of given restricted delivery item of the same collection or its registered owner. The record must
+        /// be neither revoked nor superseded and, if it targets some items only, given item must be one of them.
+        pub fn can_access_tokens_record(
+            collection_loc_id: &T::LocId,
+            record_id: &T::TokensRecordId,
+            item_id: &T::CollectionItemId,
+            account: &T::AccountId,
+        ) -> Result<bool, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            if !<TokensRecordsMap<T>>::contains_key(collection_loc_id, record_id)
+                || <TokensRecordRevocationsMap<T>>::contains_key(collection_loc_id, record_id)
+                || <TokensRecordSupersededByMap<T>>::contains_key(collection_loc_id, record_id)
+                || (<TokensRecordTargetsMap<T>>::contains_key(collection_loc_id, record_id)
+                    && !<CollectionItemTokensRecordsMap<T>>::contains_key((*collection_loc_id, *item_id), record_id)) {
+                return Ok(false);
             }
-            Ok(().into())
+            Ok(match Self::collection_items(collection_loc_id, item_id) {
+                Some(item) => item.restricted_delivery
+                    && !Self::is_collection_item_revoked(collection_loc_id, item_id)
+                    && (Self::owns_item_token(collection_loc_id, item_id, &item, account) || Self::is_collection_item_owner(collection_loc_id, item_id, account)),
+                None => false,
+            })
         }
 
-        /// Import a sponsorship.
-        #[pallet::call_index(32)]
-        #[pallet::weight(T::WeightInfo::import_sponsorship())]
-        pub fn import_sponsorship(
-            origin: OriginFor<T>,
-            #[pallet::compact] sponsorship_id: T::SponsorshipId,
-            sponsor: T::AccountId,
-            sponsored_account: SupportedAccountId<T::AccountId, T::EthereumAddress>,
-            legal_officer: T::AccountId,
-            loc_id: Option<T::LocId>,
-        ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+        /// Ensures that the registered owner of given item may change i.e. the collection is not void, the item
+        /// is not revoked and its ownership is not given by a native token.
+        fn ensure_can_change_item_owner(
+            collection_loc_id: &T::LocId,
+            collection_loc: &LegalOfficerCaseOf<T>,
+            item_id: &T::CollectionItemId,
+        ) -> DispatchResult {
+            if collection_loc.void_info.is_some() {
+                Err(Error::<T>::CannotMutateVoid)?
+            }
+            if Self::is_collection_item_revoked(collection_loc_id, item_id) {
+                Err(Error::<T>::CannotTransferCollectionItem)?
+            }
+            if <CollectionItemNativeTokensMap<T>>::contains_key(collection_loc_id, item_id) {
+                Err(Error::<T>::CollectionItemHasNativeToken)?
+            }
+            Ok(())
+        }
 
-            if <SponsorshipMap<T>>::contains_key(&sponsorship_id) {
-                Err(Error::<T>::AlreadyExists)?
-            } else {
-                let sponsorship = Sponsorship {
-                    sponsor: sponsor.clone(),
-                    sponsored_account: sponsored_account.clone(),
-                    legal_officer,
-                    loc_id,
-                    imported: true,
-                };
-                <SponsorshipMap<T>>::insert(sponsorship_id, sponsorship);
+        /// Tells if an item with given ID exists or existed in given collection.
+        fn collection_item_id_used(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId) -> bool {
+            <CollectionItemsMap<T>>::contains_key(collection_loc_id, item_id)
+                || <RemovedCollectionItemsMap<T>>::contains_key(collection_loc_id, item_id)
+        }
 
-                Self::deposit_event(Event::SponsorshipImported(sponsorship_id));
-                Ok(().into())
+        /// Tells if given account is the registered owner of a collection item.
+        pub fn is_collection_item_owner(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId, account: &T::AccountId) -> bool {
+            Self::collection_item_owner(collection_loc_id, item_id).as_ref() == Some(account)
+        }
+
+        /// The tokens records targeting given collection item. Records applying to the whole collection are not included.
+        pub fn tokens_records_of_item(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId) -> Result<Vec<T::TokensRecordId>, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            Ok(<CollectionItemTokensRecordsMap<T>>::iter_key_prefix((*collection_loc_id, *item_id)).collect())
+        }
+
+        /// Tells if given account holds the token of given item. The native token, when minted, is the only
+        /// one taken into account, the oracle being queried otherwise.
+        fn owns_item_token(
+            collection_loc_id: &T::LocId,
+            item_id: &T::CollectionItemId,
+            item: &CollectionItemOf<T>,
+            account: &T::AccountId,
+        ) -> bool {
+            if let Some(native_token) = Self::collection_item_native_token(collection_loc_id, item_id) {
+                return T::NativeTokenMinter::holds(native_token.kind, &native_token.id, account);
+            }
+            match &item.token {
+                Some(token) => T::TokenOwnershipOracle::owns_token(account, &token.token_type, &token.token_id, &token.token_issuance),
+                None => false,
             }
         }
-    }
 
-    impl<T: Config> LocQuery<T::LocId, <T as frame_system::Config>::AccountId> for Pallet<T> {
-        fn has_closed_identity_locs(
-            account: &<T as frame_system::Config>::AccountId,
-            legal_officers: &Vec<<T as frame_system::Config>::AccountId>
+        fn item_has_file(
+            collection_loc_id: &T::LocId,
+            item_id: &T::CollectionItemId,
+            item: &CollectionItemOf<T>,
+            file_hash: &<T as Config>::Hash,
         ) -> bool {
-            Self::has_closed_identity_loc(account, &legal_officers[0]) && Self::has_closed_identity_loc(account, &legal_officers[1])
+            let latest_version = Self::collection_item_latest_version(collection_loc_id, item_id)
+                .unwrap_or(0)
+                .min(T::MaxCollectionItemVersions::get());
+            item.files.iter().any(|file| file.hash == *file_hash)
+                || (1..=latest_version)
+                    .filter_map(|version| Self::collection_item_version((*collection_loc_id, *item_id), version))
+                    .any(|version| version.files.iter().any(|file| file.hash == *file_hash))
         }
 
-        fn get_loc(loc_id: &T::LocId) -> Option<LegalOfficerCaseSummary<T::AccountId>> {
-            let option_loc = <LocMap<T>>::get(&loc_id);
+        fn collection_limits_reached(collection_loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>, new_items: u32) -> bool {
+            let collection_size = <CollectionSizeMap<T>>::get(collection_loc_id).unwrap_or(0);
+            return match collection_loc.collection_max_size { None => false, Some(limit) => collection_size.saturating_add(new_items) > limit }
+                || Self::collection_submission_closed(collection_loc);
+        }
 
-            match option_loc {
-                Some(loc) => Some(LegalOfficerCaseSummary {
-                    owner: loc.owner,
-                    requester: match loc.requester {
-                        Account(account) => Some(account),
-                        _ => None
-                    }
-                }),
-                _ => None
-            }
+        fn collection_submission_closed(collection_loc: &LegalOfficerCaseOf<T>) -> bool {
+            let current_block_number = <frame_system::Pallet<T>>::block_number();
+            match collection_loc.collection_last_block_submission { None => false, Some(last_block) => current_block_number >= last_block }
         }
-    }
 
-    impl<T: Config> LocValidity<T::LocId, <T as frame_system::Config>::AccountId> for Pallet<T> {
-        fn loc_valid_with_owner(
-            loc_id: &<T as pallet::Config>::LocId,
-            legal_officer: &<T as frame_system::Config>::AccountId,
-        ) -> bool {
-            Self::loc_valid_with_owner(&loc_id, &legal_officer)
+        fn has_unique_elements<I>(iter: I) -> bool
+            where
+                I: IntoIterator,
+                I::Item: Ord,
+        {
+            let mut uniq = BTreeSet::new();
+            iter.into_iter().all(move |x| uniq.insert(x))
         }
-    }
 
-    impl<T: Config> Pallet<T> {
+        fn do_add_collection_item(
+            origin: OriginFor<T>,
+            collection_loc_id: T::LocId,
+            item_id: T::CollectionItemId,
+            item_description: <T as Config>::Hash,
+            item_files: Vec<CollectionItemFileOf<T>>,
+            item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
+            restricted_delivery: bool,
+            terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_add_collection_items(origin, collection_loc_id, Vec::from([ CollectionItemParams {
+                item_id,
+                item_description,
+                item_files,
+                item_token,
+                restricted_delivery,
+                terms_and_conditions,
+                native_token: None,
+            } ]))
+        }
 
-        fn do_make_void(
+        fn do_add_collection_items(
             origin: OriginFor<T>,
-            loc_id: T::LocId,
-            replacer_loc_id: Option<T::LocId>
+            collection_loc_id: T::LocId,
+            items: Vec<CollectionItemParamsOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            for item in items.iter() {
+                Self::ensure_valid_collection_item_params(item)?;
+            }
+
+            let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
+            match collection_loc_option {
+                None => Err(Error::<T>::WrongCollectionLoc)?,
+                Some(collection_loc) => {
+                    let item_ids: Vec<T::CollectionItemId> = items.iter()
+                        .map(|item| item.item_id)
+                        .collect();
+                    if !Self::has_unique_elements(item_ids.iter().map(|item_id| item_id.encode()))
+                        || item_ids.iter().any(|item_id| Self::collection_item_id_used(&collection_loc_id, item_id)) {
+                        Err(Error::<T>::CollectionItemAlreadyExists)?
+                    }
+                    if ! Self::can_add_item(&who, &collection_loc) {
+                        Err(Error::<T>::WrongCollectionLoc)?
+                    }
+                    if Self::collection_limits_reached(&collection_loc_id, &collection_loc, items.len() as u32) {
+                        Err(Error::<T>::CollectionLimitsReached)?
+                    }
+                    for item in items.iter() {
+                        Self::ensure_can_add_collection_item(&collection_loc, item)?;
+                    }
+
+                    let num_of_files = items.iter()
+                        .map(|item| item.item_files.len())
+                        .fold(0, |tot, current| tot + current);
+                    let tot_size = items.iter()
+                        .flat_map(|item| item.item_files.iter())
+                        .map(|file| file.size)
+                        .fold(0, |tot, current| tot + current);
+                    Self::apply_file_storage_fee(&who, &collection_loc_id, collection_loc.loc_type, num_of_files, tot_size)?;
+
+                    let certificate_fee = items.iter()
+                        .filter_map(|item| item.item_token.as_ref())
+                        .map(|token| Self::calculate_certificate_fee(token.token_issuance))
+                        .fold(BalanceOf::<T>::zero(), |tot, current| tot.saturating_add(current));
+                    if items.iter().any(|item| item.item_token.is_some()) {
+                        Self::slash_and_distribute(&who, certificate_fee, &|credit| {
+                            T::RewardDistributor::distribute_with_loc_owner(credit, Self::fee_distribution_key(FeeKind::Certificate, collection_loc.loc_type), &collection_loc.owner)
+                        })?;
+                        Self::deposit_event(Event::CertificateFeeWithdrawn(who.clone(), certificate_fee));
+                    }
+
+                    let fee = collection_loc.collection_item_fee.saturating_mul((items.len() as u32).into());
+                    if fee > 0_u32.into() {
+                        let (beneficiary, amount) = Self::slash_and_distribute(&who, fee, &|credit| {
+                            T::RewardDistributor::distribute_with_loc_owner(credit, Self::fee_distribution_key(FeeKind::CollectionItem, collection_loc.loc_type), &collection_loc.owner)
+                        })?;
+                        Self::deposit_event(Event::CollectionItemFeeWithdrawn(who.clone(), fee, beneficiary, amount));
+                    }
+
+                    for item in items {
+                        let item_id = item.item_id;
+                        let native_token = item.native_token;
+                        let token_issuance = item.item_token.as_ref().map(|token| token.token_issuance);
+                        let item_deposit = Self::storage_deposit(item.item_files.len(), item.item_files.iter().map(|file| file.size).sum());
+                        Self::insert_collection_item(&collection_loc_id, item)?;
+                        if let Some(deposit) = item_deposit {
+                            <CollectionItemDepositsMap<T>>::insert((collection_loc_id, item_id, 0), (who.clone(), deposit));
+                        }
+                        Self::deposit_event(Event::ItemAdded(collection_loc_id, item_id));
+                        if let (Some(kind), Some(issuance)) = (native_token, token_issuance) {
+                            Self::mint_native_token(&collection_loc_id, &item_id, kind, &who, issuance)?;
+                        }
+                    }
+                },
+            }
+            Ok(().into())
+        }
+
+        fn do_add_tokens_record(
+            origin: OriginFor<T>,
+            collection_loc_id: T::LocId,
+            record_id: T::TokensRecordId,
+            description: <T as Config>::Hash,
+            files: Vec<TokensRecordFileOf<T>>,
+            charge_submitter: bool,
+            target_items: Vec<T::CollectionItemId>,
+            supersedes: Option<T::TokensRecordId>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            if !<LocMap<T>>::contains_key(&loc_id) {
-                Err(Error::<T>::NotFound)?
-            } else {
-                let loc = <LocMap<T>>::get(&loc_id).unwrap();
-                if loc.owner != who {
-                    Err(Error::<T>::Unauthorized)?
-                }
-                if loc.void_info.is_some() {
-                    Err(Error::<T>::AlreadyVoid)?
-                }
-
-                if replacer_loc_id.is_some() {
-                    let replacer = replacer_loc_id.unwrap();
-                    if !<LocMap<T>>::contains_key(&replacer) {
-                        Err(Error::<T>::ReplacerLocNotFound)?
+            let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
+            match collection_loc_option {
+                None => Err(Error::<T>::WrongCollectionLoc)?,
+                Some(collection_loc) => {
+                    if <TokensRecordsMap<T>>::contains_key(&collection_loc_id, &record_id) {
+                        Err(Error::<T>::TokensRecordAlreadyExists)?
+                    }
+                    if !Self::can_add_record(&who, &collection_loc_id, &collection_loc) {
+                        Err(Error::<T>::CannotAddRecord)?
+                    }
+                    if files.len() == 0 {
+                        Err(Error::<T>::MustUpload)?
                     } else {
-                        let replacer_loc = <LocMap<T>>::get(&replacer).unwrap();
-                        if replacer_loc.void_info.is_some() {
-                            Err(Error::<T>::ReplacerLocAlreadyVoid)?
+                        let files_hashes: Vec<<T as Config>::Hash> = files.iter()
+                            .map(|file| file.hash)
+                            .collect();
+                        if !Self::has_unique_elements(&files_hashes) {
+                            Err(Error::<T>::DuplicateFile)?
                         }
-                        if replacer_loc.replacer_of.is_some() {
-                            Err(Error::<T>::ReplacerLocAlreadyReplacing)?
+                    }
+                    let bounded_targets: BoundedVec<T::CollectionItemId, T::MaxTokensRecordTargets> = BoundedVec::try_from(target_items)
+                        .map_err(|_| Error::<T>::TokensRecordTooManyTargets)?;
+                    if !Self::has_unique_elements(bounded_targets.iter().map(|item_id| item_id.encode())) {
+                        Err(Error::<T>::DuplicateTokensRecordTarget)?
+                    }
+                    if bounded_targets.iter().any(|item_id| !<CollectionItemsMap<T>>::contains_key(&collection_loc_id, item_id)) {
+                        Err(Error::<T>::CollectionItemNotFound)?
+                    }
+                    if let Some(superseded_record_id) = supersedes {
+                        let superseded_record = <TokensRecordsMap<T>>::get(&collection_loc_id, &superseded_record_id)
+                            .ok_or(Error::<T>::TokensRecordNotFound)?;
+                        if superseded_record.submitter != who && !Self::is_owner_or_co_owner(&collection_loc_id, &collection_loc, &who) {
+                            Err(Error::<T>::Unauthorized)?
                         }
-                        if !replacer_loc.loc_type.eq(&loc.loc_type) {
-                            Err(Error::<T>::ReplacerLocWrongType)?
+                        if <TokensRecordRevocationsMap<T>>::contains_key(&collection_loc_id, &superseded_record_id) {
+                            Err(Error::<T>::CannotSupersedeRevokedTokensRecord)?
+                        }
+                        if <TokensRecordSupersededByMap<T>>::contains_key(&collection_loc_id, &superseded_record_id) {
+                            Err(Error::<T>::TokensRecordAlreadySuperseded)?
                         }
                     }
-                }
 
-                let loc_void_info = LocVoidInfo {
-                    replacer:replacer_loc_id
-                };
-                <LocMap<T>>::mutate(loc_id, |loc| {
-                    let mutable_loc = loc.as_mut().unwrap();
-                    mutable_loc.void_info = Some(loc_void_info);
-                });
-                if replacer_loc_id.is_some() {
-                    <LocMap<T>>::mutate(replacer_loc_id.unwrap(), |replacer_loc| {
-                        let mutable_replacer_loc = replacer_loc.as_mut().unwrap();
-                        mutable_replacer_loc.replacer_of = Some(loc_id);
-                    });
-                }
+                    let mut bounded_files: BoundedVec<TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(files.len());
+                    for file in files.iter() {
+                        bounded_files.try_push(file.clone()).map_err(|_| Error::<T>::TokensRecordTooMuchData)?;
+                    }
+                    let fee_payer = if charge_submitter { who.clone() } else {
+                        match collection_loc.requester {
+                            Account(requester_account) => requester_account,
+                            _ => Err(Error::<T>::InvalidFeePayer)?,
+                        }
+                    };
 
-                if loc.loc_type == LocType::Collection && !loc.closed && loc.value_fee > 0_u32.into() {
-                    match loc.requester {
-                        Account(requester_account) => {
-                            T::Currency::unreserve(&requester_account, loc.value_fee);
-                        },
-                        _ => {},
+                    let tot_size = files.iter()
+                        .map(|file| file.size)
+                        .fold(0, |tot, current| tot + current);
+                    if fee_payer != who {
+                        let total_fees = Self::calculate_fee(files.len() as u32, tot_size)
+                            .saturating_add(collection_loc.tokens_record_fee);
+                        Self::consume_fee_allowance(&collection_loc_id, &fee_payer, &who, total_fees, false)?;
                     }
-                }
+                    Self::apply_file_storage_fee(&fee_payer, &collection_loc_id, collection_loc.loc_type, files.len(), tot_size)?;
 
-                Self::deposit_event(Event::LocVoid(loc_id));
-                Ok(().into())
+                    let fee = collection_loc.tokens_record_fee;
+                    if fee > 0_u32.into() {
+                        let (beneficiary, amount) = Self::slash_and_distribute(&fee_payer, fee, &|credit| {
+                            T::RewardDistributor::distribute_with_loc_owner(credit, Self::fee_distribution_key(FeeKind::TokensRecord, collection_loc.loc_type), &collection_loc.owner)
+                        })?;
+                        Self::deposit_event(Event::TokensRecordFeeWithdrawn(fee_payer, fee, beneficiary, amount));
+                    }
+
+                    let record = TokensRecord {
+                        description,
+                        files: bounded_files,
+                        submitter: who.clone(),
+                        imported: false,
+                    };
+                    <TokensRecordsMap<T>>::insert(collection_loc_id, record_id, record);
+                    if let Some(deposit) = Self::storage_deposit(files.len(), tot_size) {
+                        <TokensRecordDepositsMap<T>>::insert(collection_loc_id, record_id, (fee_payer.clone(), deposit));
+                    }
+
+                    if !bounded_targets.is_empty() {
+                        for item_id in bounded_targets.iter() {
+                            <CollectionItemTokensRecordsMap<T>>::insert((collection_loc_id, *item_id), record_id, ());
+                        }
+                        <TokensRecordTargetsMap<T>>::insert(collection_loc_id, record_id, bounded_targets);
+                    }
+                    if let Some(superseded_record_id) = supersedes {
+                        <TokensRecordSupersedesMap<T>>::insert(collection_loc_id, record_id, superseded_record_id);
+                        <TokensRecordSupersededByMap<T>>::insert(collection_loc_id, superseded_record_id, record_id);
+                        Self::deposit_event(Event::TokensRecordSuperseded(collection_loc_id, record_id, superseded_record_id));
+                    }
+                },
             }
+
+            Ok(().into())
         }
 
-        fn has_closed_identity_loc(
-            account: &<T as frame_system::Config>::AccountId,
-            legal_officer: &<T as frame_system::Config>::AccountId
-        ) -> bool {
-            let value = <AccountLocsMap<T>>::get(account);
-            match value {
-                Some(loc_ids) => {
-                    return loc_ids.iter().map(|id| <LocMap<T>>::get(id))
-                        .filter(|option| option.is_some())
-                        .map(|some| some.unwrap())
-                        .find(|loc| loc.owner == *legal_officer && loc.loc_type == LocType::Identity && loc.closed)
-                        .is_some();
+        fn do_import_tokens_record(
+            collection_loc_id: T::LocId,
+            record_id: T::TokensRecordId,
+            description: <T as Config>::Hash,
+            files: Vec<TokensRecordFileOf<T>>,
+            submitter: T::AccountId,
+            target_items: Vec<T::CollectionItemId>,
+            supersedes: Option<T::TokensRecordId>,
+        ) -> DispatchResultWithPostInfo {
+            if <crate::pallet::TokensRecordsMap<T>>::contains_key(&collection_loc_id, &record_id) {
+                Err(crate::pallet::Error::<T>::TokensRecordAlreadyExists)?
+            }
+            if files.len() == 0 {
+                Err(crate::pallet::Error::<T>::MustUpload)?
+            } else {
+                let files_hashes: Vec<<T as Config>::Hash> = files.iter()
+                    .map(|file| file.hash)
+                    .collect();
+                if !Self::has_unique_elements(&files_hashes) {
+                    Err(crate::pallet::Error::<T>::DuplicateFile)?
                 }
-                None => false
             }
-        }
 
-        fn loc_valid_with_owner(
-            loc_id: &<T as Config>::LocId,
-            legal_officer: &<T as frame_system::Config>::AccountId
-        ) -> bool {
-            let loc = <LocMap<T>>::get(loc_id);
-            match loc {
-                Some(loc) => {
-                    return loc.closed && loc.void_info.is_none() && loc.owner == *legal_officer;
+            let bounded_targets: BoundedVec<T::CollectionItemId, T::MaxTokensRecordTargets> = BoundedVec::try_from(target_items)
+                .map_err(|_| Error::<T>::TokensRecordTooManyTargets)?;
+            if !Self::has_unique_elements(bounded_targets.iter().map(|item_id| item_id.encode())) {
+                Err(Error::<T>::DuplicateTokensRecordTarget)?
+            }
+            if bounded_targets.iter().any(|item_id| !<CollectionItemsMap<T>>::contains_key(&collection_loc_id, item_id)) {
+                Err(Error::<T>::CollectionItemNotFound)?
+            }
+            if let Some(superseded_record_id) = supersedes {
+                if !<TokensRecordsMap<T>>::contains_key(&collection_loc_id, &superseded_record_id) {
+                    Err(Error::<T>::TokensRecordNotFound)?
+                }
+                if <TokensRecordRevocationsMap<T>>::contains_key(&collection_loc_id, &superseded_record_id) {
+                    Err(Error::<T>::CannotSupersedeRevokedTokensRecord)?
+                }
+                if <TokensRecordSupersededByMap<T>>::contains_key(&collection_loc_id, &superseded_record_id) {
+                    Err(Error::<T>::TokensRecordAlreadySuperseded)?
                 }
-                None => false
             }
-        }
 
-        fn link_with_account(
-            account_id: &<T as frame_system::Config>::AccountId,
-            loc_id: &<T as Config>::LocId,
-        ) -> Result<(), sp_runtime::DispatchError> {
-            if <AccountLocsMap<T>>::contains_key(account_id) {
-                <AccountLocsMap<T>>::mutate(account_id, |locs| {
-                    let list = locs.as_mut().unwrap();
-                    list.try_push(loc_id.clone())
-                }).map_err(|_| Error::<T>::AccountLocsTooMuchData)?;
-				Ok(())
-            } else {
-				let mut list: BoundedVec<<T as Config>::LocId, <T as Config>::MaxAccountLocs> = BoundedVec::new();
-				list.try_push(loc_id.clone())
-					.map_err(|_| Error::<T>::AccountLocsTooMuchData)?;
-				<AccountLocsMap<T>>::insert(account_id, list);
-				Ok(())
+            let mut bounded_files: BoundedVec<crate::TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(files.len());
+            for file in files.iter() {
+                bounded_files.try_push(file.clone()).map_err(|_| crate::pallet::Error::<T>::TokensRecordTooMuchData)?;
             }
-        }
-
-        fn is_valid_logion_id(loc: &LegalOfficerCaseOf<T>) -> bool {
-            loc.loc_type != LocType::Identity
-                || match loc.requester { RequesterOf::<T>::None => false, _ => true }
-                || !loc.closed
-                || loc.void_info.is_some()
-        }
 
-        fn build_open_loc(
-            legal_officer: &T::AccountId,
-            requester: &RequesterOf<T>,
-            loc_type: LocType,
-            sponsorship_id: Option<T::SponsorshipId>,
-            legal_fee: BalanceOf<T>,
-        ) -> LegalOfficerCaseOf<T> {
-            LegalOfficerCaseOf::<T> {
-                owner: legal_officer.clone(),
-                requester: requester.clone(),
-                metadata: BoundedVec::new(),
-                files: BoundedVec::new(),
-                closed: false,
-                loc_type: loc_type.clone(),
-                links: BoundedVec::new(),
-                void_info: None,
-                replacer_of: None,
-                collection_last_block_submission: None,
-                collection_max_size: None,
-                collection_can_upload: false,
-                seal: None,
-                sponsorship_id: sponsorship_id.clone(),
-                value_fee: 0u32.into(),
-                legal_fee: legal_fee.clone(),
-                collection_item_fee: 0u32.into(),
-                tokens_record_fee: 0u32.into(),
-                imported: false,
+            let record = crate::TokensRecord {
+                description,
+                files: bounded_files,
+                submitter,
+                imported: true,
+            };
+            <TokensRecordsMap<T>>::insert(collection_loc_id, record_id, record);
+            if !bounded_targets.is_empty() {
+                for item_id in bounded_targets.iter() {
+                    <CollectionItemTokensRecordsMap<T>>::insert((collection_loc_id, *item_id), record_id, ());
+                }
+                <TokensRecordTargetsMap<T>>::insert(collection_loc_id, record_id, bounded_targets);
             }
-        }
-
-        fn build_open_collection_loc(
-            who: &T::AccountId,
-            requester: &RequesterOf<T>,
-            collection_last_block_submission: Option<BlockNumberFor<T>>,
-            collection_max_size: Option<CollectionSize>,
-            collection_can_upload: bool,
-            value_fee: BalanceOf<T>,
-            legal_fee: BalanceOf<T>,
-            collection_item_fee: BalanceOf<T>,
-            tokens_record_fee: BalanceOf<T>,
-        ) -> LegalOfficerCaseOf<T> {
-            LegalOfficerCaseOf::<T> {
-                owner: who.clone(),
-                requester: requester.clone(),
-                metadata: BoundedVec::new(),
-                files: BoundedVec::new(),
-                closed: false,
-                loc_type: LocType::Collection,
-                links: BoundedVec::new(),
-                void_info: None,
-                replacer_of: None,
-                collection_last_block_submission: collection_last_block_submission.clone(),
-                collection_max_size: collection_max_size.clone(),
-                collection_can_upload,
-                seal: None,
-                sponsorship_id: None,
-                value_fee,
-                legal_fee,
-                collection_item_fee,
-                tokens_record_fee,
-                imported: false,
+            if let Some(superseded_record_id) = supersedes {
+                <TokensRecordSupersedesMap<T>>::insert(collection_loc_id, record_id, superseded_record_id);
+                <TokensRecordSupersededByMap<T>>::insert(collection_loc_id, superseded_record_id, record_id);
             }
-        }
-
-        fn can_add_item(who: &T::AccountId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
-            collection_loc.loc_type == LocType::Collection
-                && match &collection_loc.requester { Requester::Account(requester) => requester == who, _ => false }
-                && collection_loc.closed
-                && collection_loc.void_info.is_none()
-        }
-
-        fn collection_limits_reached(collection_loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
-            let collection_size = <CollectionSizeMap<T>>::get(collection_loc_id).unwrap_or(0);
-            let current_block_number = <frame_system::Pallet<T>>::block_number();
-            return match collection_loc.collection_max_size { None => false, Some(limit) => collection_size >= limit }
-                || match collection_loc.collection_last_block_submission { None => false, Some(last_block) => current_block_number >= last_block };
-        }
 
-        fn has_unique_elements<I>(iter: I) -> bool
-            where
-                I: IntoIterator,
-                I::Item: Ord,
-        {
-            let mut uniq = BTreeSet::new();
-            iter.into_iter().all(move |x| uniq.insert(x))
+            Self::deposit_event(Event::TokensRecordImported(collection_loc_id, record_id));
+            Ok(().into())
         }
 
-        fn do_add_collection_item(
-            origin: OriginFor<T>,
-            collection_loc_id: T::LocId,
-            item_id: T::CollectionItemId,
-            item_description: <T as Config>::Hash,
-            item_files: Vec<CollectionItemFileOf<T>>,
-            item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
-            restricted_delivery: bool,
-            terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
-        ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-
-            if item_token.is_some() && item_token.as_ref().unwrap().token_issuance < 1_u32.into() {
+        fn ensure_valid_collection_item_params(item: &CollectionItemParamsOf<T>) -> DispatchResult {
+            if item.item_token.is_some() && item.item_token.as_ref().unwrap().token_issuance < 1_u32.into() {
                 Err(Error::<T>::BadTokenIssuance)?
             }
 
-            if restricted_delivery && item_token.is_none() {
+            if (item.restricted_delivery || item.native_token.is_some()) && item.item_token.is_none() {
                 Err(Error::<T>::MissingToken)?
             }
 
-            if restricted_delivery && item_files.len() == 0 {
-                Err(Error::<T>::MissingFiles)?
+            if item.native_token == Some(NativeTokenKind::NonFungible) && item.item_token.as_ref().unwrap().token_issuance != 1_u32.into() {
+                Err(Error::<T>::BadTokenIssuance)?
             }
 
-            let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
-            match collection_loc_option {
-                None => Err(Error::<T>::WrongCollectionLoc)?,
-                Some(collection_loc) => {
-                    if <CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
-                        Err(Error::<T>::CollectionItemAlreadyExists)?
-                    }
-                    if ! Self::can_add_item(&who, &collection_loc) {
-                        Err(Error::<T>::WrongCollectionLoc)?
-                    }
-                    if Self::collection_limits_reached(&collection_loc_id, &collection_loc) {
-                        Err(Error::<T>::CollectionLimitsReached)?
-                    }
-                    if !collection_loc.collection_can_upload && item_files.len() > 0 {
-                        Err(Error::<T>::CannotUpload)?
-                    }
-                    if collection_loc.collection_can_upload {
-                        let files_hashes: Vec<<T as Config>::Hash> = item_files.iter()
-                            .map(|file| file.hash)
-                            .collect();
-                        if !Self::has_unique_elements(&files_hashes) {
-                            Err(Error::<T>::DuplicateFile)?
-                        }
-                    }
+            if item.restricted_delivery && item.item_files.len() == 0 {
+                Err(Error::<T>::MissingFiles)?
+            }
+            Ok(())
+        }
 
-                    for terms_and_conditions_element in &terms_and_conditions {
-                        if !<LocMap<T>>::contains_key(&terms_and_conditions_element.tc_loc) {
-                            Err(Error::<T>::TermsAndConditionsLocNotFound)?
-                        } else {
-                            let tc_loc = <LocMap<T>>::get(terms_and_conditions_element.tc_loc).unwrap();
-                            if tc_loc.void_info.is_some() {
-                                Err(Error::<T>::TermsAndConditionsLocVoid)?
-                            } else if !tc_loc.closed {
-                                Err(Error::<T>::TermsAndConditionsLocNotClosed)?
-                            }
-                        }
-                    }
-                    let tot_size = item_files.iter()
-                        .map(|file| file.size)
-                        .fold(0, |tot, current| tot + current);
-                    Self::apply_file_storage_fee(&who, item_files.len(), tot_size)?;
-					let bounded_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = BoundedVec::try_from(item_files)
-						.map_err(|_| Error::<T>::CollectionItemFilesTooMuchData)?;
-					let bounded_tcs: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTCs> = BoundedVec::try_from(terms_and_conditions)
-						.map_err(|_| Error::<T>::CollectionItemTCsTooMuchData)?;
-					let item = CollectionItem {
-                        description: item_description,
-                        files: bounded_files,
-                        token: item_token.clone(),
-                        restricted_delivery,
-                        terms_and_conditions: bounded_tcs,
-                        imported: false,
-                    };
-                    <CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
-                    let collection_size = <CollectionSizeMap<T>>::get(&collection_loc_id).unwrap_or(0);
-                    <CollectionSizeMap<T>>::insert(&collection_loc_id, collection_size + 1);
-
-                    match item_token {
-                        Some(token) => {
-                            let fee = Self::calculate_certificate_fee(token.token_issuance);
-                            Self::slash_and_distribute(&who, fee, &|credit| {
-                                T::RewardDistributor::distribute_with_loc_owner(credit, T::CertificateFeeDistributionKey::get(), &collection_loc.owner)
-                            })?;
-                            Self::deposit_event(Event::CertificateFeeWithdrawn(who.clone(), fee));
-                        }
-                        _ => {}
-                    };
+        fn ensure_can_add_collection_item(
+            collection_loc: &LegalOfficerCaseOf<T>,
+            item: &CollectionItemParamsOf<T>,
+        ) -> DispatchResult {
+            if !collection_loc.collection_can_upload && item.item_files.len() > 0 {
+                Err(Error::<T>::CannotUpload)?
+            }
+            if collection_loc.collection_can_upload {
+                let files_hashes: Vec<<T as Config>::Hash> = item.item_files.iter()
+                    .map(|file| file.hash)
+                    .collect();
+                if !Self::has_unique_elements(&files_hashes) {
+                    Err(Error::<T>::DuplicateFile)?
+                }
+            }
+            if item.item_files.len() > T::MaxCollectionItemFiles::get() as usize {
+                Err(Error::<T>::CollectionItemFilesTooMuchData)?
+            }
+            if item.terms_and_conditions.len() > T::MaxCollectionItemTCs::get() as usize {
+                Err(Error::<T>::CollectionItemTCsTooMuchData)?
+            }
 
-                    let fee = collection_loc.collection_item_fee;
-                    if fee > 0_u32.into() {
-                        let (beneficiary, amount) = Self::slash_and_distribute(&who, fee, &|credit| {
-                            T::RewardDistributor::distribute_with_loc_owner(credit, T::CollectionItemFeeDistributionKey::get(), &collection_loc.owner)
-                        })?;
-                        Self::deposit_event(Event::CollectionItemFeeWithdrawn(who.clone(), fee, beneficiary, amount));
+            for terms_and_conditions_element in &item.terms_and_conditions {
+                if !<LocMap<T>>::contains_key(&terms_and_conditions_element.tc_loc) {
+                    Err(Error::<T>::TermsAndConditionsLocNotFound)?
+                } else {
+                    let tc_loc = <LocMap<T>>::get(terms_and_conditions_element.tc_loc).unwrap();
+                    if tc_loc.void_info.is_some() {
+                        Err(Error::<T>::TermsAndConditionsLocVoid)?
+                    } else if !tc_loc.closed {
+                        Err(Error::<T>::TermsAndConditionsLocNotClosed)?
                     }
-                },
+                }
             }
+            Ok(())
+        }
 
-            Self::deposit_event(Event::ItemAdded(collection_loc_id, item_id));
-            Ok(().into())
+        fn insert_collection_item(collection_loc_id: &T::LocId, item: CollectionItemParamsOf<T>) -> DispatchResult {
+            let bounded_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = BoundedVec::try_from(item.item_files)
+                .map_err(|_| Error::<T>::CollectionItemFilesTooMuchData)?;
+            let bounded_tcs: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTCs> = BoundedVec::try_from(item.terms_and_conditions)
+                .map_err(|_| Error::<T>::CollectionItemTCsTooMuchData)?;
+            <CollectionItemsMap<T>>::insert(collection_loc_id, item.item_id, CollectionItem {
+                description: item.item_description,
+                files: bounded_files,
+                token: item.item_token,
+                restricted_delivery: item.restricted_delivery,
+                terms_and_conditions: bounded_tcs,
+                imported: false,
+            });
+            <CollectionItemsAddedOnMap<T>>::insert(collection_loc_id, item.item_id, frame_system::Pallet::<T>::block_number());
+            let collection_size = <CollectionSizeMap<T>>::get(collection_loc_id).unwrap_or(0);
+            <CollectionSizeMap<T>>::insert(collection_loc_id, collection_size + 1);
+            Ok(())
+        }
+
+        pub(crate) fn mint_native_token(
+            collection_loc_id: &T::LocId,
+            item_id: &T::CollectionItemId,
+            kind: NativeTokenKind,
+            owner: &T::AccountId,
+            issuance: T::TokenIssuance,
+        ) -> DispatchResult {
+            let token_id = T::Hasher::hash(&(collection_loc_id, item_id).encode());
+            T::NativeTokenMinter::mint(kind, &token_id, owner, issuance)?;
+            <CollectionItemNativeTokensMap<T>>::insert(collection_loc_id, item_id, NativeToken {
+                kind,
+                id: token_id,
+            });
+            <NativeTokenItemsMap<T>>::insert(token_id, (*collection_loc_id, *item_id));
+            Self::deposit_event(Event::NativeTokenMinted(*collection_loc_id, *item_id, token_id));
+            Ok(())
+        }
+
+        /// The weight of reading the T&C LOCs of an item, each T&C element may reference a different LOC.
+        pub fn terms_and_conditions_weight() -> Weight {
+            T::DbWeight::get().reads(T::MaxCollectionItemTCs::get() as u64)
+        }
+
+        /// The weight of minting a native token, on top of the addition of a collection item.
+        pub fn native_token_weight() -> Weight {
+            T::WeightInfo::mint_non_fungible_native_token().max(T::WeightInfo::mint_fungible_native_token())
+        }
+
+        /// Routing table giving the key used to distribute each kind of fee, given the type of the LOC it is charged for.
+        pub fn fee_distribution_key(fee_kind: FeeKind, loc_type: LocType) -> DistributionKey {
+            match (fee_kind, loc_type) {
+                (FeeKind::Storage, _) => T::FileStorageFeeDistributionKey::get(),
+                (FeeKind::Legal, LocType::Identity) => T::IdentityLocLegalFeeDistributionKey::get(),
+                (FeeKind::Legal, LocType::Transaction) => T::TransactionLocLegalFeeDistributionKey::get(),
+                (FeeKind::Legal, LocType::Collection) => T::CollectionLocLegalFeeDistributionKey::get(),
+                (FeeKind::Certificate, _) => T::CertificateFeeDistributionKey::get(),
+                (FeeKind::Value, _) => T::ValueFeeDistributionKey::get(),
+                (FeeKind::CollectionItem, _) => T::CollectionItemFeeDistributionKey::get(),
+                (FeeKind::TokensRecord, _) => T::TokensRecordFeeDistributionKey::get(),
+            }
         }
 
         pub fn calculate_certificate_fee(token_issuance: T::TokenIssuance) -> BalanceOf<T> {
@@ -2536,15 +4697,148 @@ pub mod pallet {
             }
         }
 
-        fn apply_file_storage_fee(fee_payer: &T::AccountId, num_of_entries: usize, tot_size: u32) -> DispatchResult {
+        fn apply_loc_files_storage_fee(fee_payer: &T::AccountId, loc_id: &T::LocId, loc_type: LocType, files: &Vec<FileParamsOf<T>>) -> DispatchResult {
+            let tot_size = files.iter()
+                .map(|file| file.size)
+                .fold(0, |tot, current| tot + current);
+            Self::apply_file_storage_fee(fee_payer, loc_id, loc_type, files.len(), tot_size)?;
+            for file in files.iter() {
+                let fee = Self::calculate_fee(1, file.size);
+                if fee.is_zero() {
+                    continue;
+                }
+                match T::FileStoragePaymentMode::get() {
+                    StoragePaymentMode::Fee => <LocFileStorageFeesMap<T>>::insert(loc_id, file.hash, (fee_payer.clone(), fee)),
+                    StoragePaymentMode::Deposit => <LocFileDepositsMap<T>>::insert(loc_id, file.hash, (fee_payer.clone(), fee)),
+                }
+            }
+            Ok(())
+        }
+
+        fn apply_file_storage_fee(fee_payer: &T::AccountId, loc_id: &T::LocId, loc_type: LocType, num_of_entries: usize, tot_size: u32) -> DispatchResult {
             let fee = Self::calculate_fee(num_of_entries as u32, tot_size);
-            Self::slash_and_distribute(&fee_payer, fee, &|credit| {
-                T::RewardDistributor::distribute(credit, T::FileStorageFeeDistributionKey::get())
-            })?;
-            Self::deposit_event(Event::StorageFeeWithdrawn(fee_payer.clone(), fee));
+            match T::FileStoragePaymentMode::get() {
+                StoragePaymentMode::Fee => {
+                    Self::slash_and_distribute(&fee_payer, fee, &|credit| {
+                        T::RewardDistributor::distribute(credit, Self::fee_distribution_key(FeeKind::Storage, loc_type))
+                    })?;
+                    Self::deposit_event(Event::StorageFeeWithdrawn(fee_payer.clone(), fee));
+                },
+                StoragePaymentMode::Deposit => {
+                    if !fee.is_zero() {
+                        T::Currency::reserve(fee_payer, fee).map_err(|_| Error::<T>::InsufficientFunds)?;
+                        <StorageDepositsMap<T>>::mutate(loc_id, fee_payer, |deposit| *deposit = deposit.saturating_add(fee));
+                        Self::deposit_event(Event::StorageDepositReserved(fee_payer.clone(), *loc_id, fee));
+                    }
+                },
+            }
+            Ok(())
+        }
+
+        /// The deposit held for given entries when storage is paid with deposits.
+        fn storage_deposit(num_of_entries: usize, tot_size: u32) -> Option<BalanceOf<T>> {
+            let deposit = Self::calculate_fee(num_of_entries as u32, tot_size);
+            if T::FileStoragePaymentMode::get() == StoragePaymentMode::Deposit && !deposit.is_zero() {
+                Some(deposit)
+            } else {
+                None
+            }
+        }
+
+        /// Gives back to its depositor a storage deposit held for given LOC.
+        fn release_deposit(loc_id: &T::LocId, depositor: &T::AccountId, deposit: BalanceOf<T>) {
+            T::Currency::unreserve(depositor, deposit);
+            let remaining_deposit = <StorageDepositsMap<T>>::get(loc_id, depositor).saturating_sub(deposit);
+            if remaining_deposit.is_zero() {
+                <StorageDepositsMap<T>>::remove(loc_id, depositor);
+            } else {
+                <StorageDepositsMap<T>>::insert(loc_id, depositor, remaining_deposit);
+            }
+            Self::deposit_event(Event::StorageDepositReleased(depositor.clone(), *loc_id, deposit));
+        }
+
+        /// Gives back the given share of what was paid to store a deleted LOC file. The part of a deposit not
+        /// given back is charged as a storage fee, a refunded storage fee is paid by the refund account.
+        fn refund_file_storage(loc_id: &T::LocId, loc_type: LocType, hash: &<T as Config>::Hash, refund: Percent) -> DispatchResult {
+            if let Some((depositor, deposit)) = <LocFileDepositsMap<T>>::take(loc_id, hash) {
+                Self::release_deposit(loc_id, &depositor, deposit);
+
+                let fee = deposit.saturating_sub(refund * deposit);
+                if !fee.is_zero() {
+                    Self::slash_and_distribute(&depositor, fee, &|credit| {
+                        T::RewardDistributor::distribute(credit, Self::fee_distribution_key(FeeKind::Storage, loc_type))
+                    })?;
+                    Self::deposit_event(Event::StorageFeeWithdrawn(depositor, fee));
+                }
+            }
+            if let Some((payer, fee)) = <LocFileStorageFeesMap<T>>::take(loc_id, hash) {
+                let refunded_fee = refund * fee;
+                if !refunded_fee.is_zero() {
+                    T::Currency::transfer(&T::StorageFeeRefundAccount::get(), &payer, refunded_fee, ExistenceRequirement::KeepAlive)
+                        .map_err(|_| Error::<T>::CannotRefundStorageFee)?;
+                    Self::deposit_event(Event::StorageFeeRefunded(payer, *loc_id, refunded_fee));
+                }
+            }
             Ok(())
         }
 
+        /// Removes a collection item and its versions, releasing their storage deposits.
+        fn remove_collection_item_data(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId) {
+            <CollectionItemsMap<T>>::remove(collection_loc_id, item_id);
+            <CollectionItemsAddedOnMap<T>>::remove(collection_loc_id, item_id);
+            <CollectionItemRevocationsMap<T>>::remove(collection_loc_id, item_id);
+            <CollectionItemOwnersMap<T>>::remove(collection_loc_id, item_id);
+            if let Some(native_token) = <CollectionItemNativeTokensMap<T>>::take(collection_loc_id, item_id) {
+                <NativeTokenItemsMap<T>>::remove(native_token.id);
+            }
+            if let Some(latest_version) = <CollectionItemLatestVersionMap<T>>::take(collection_loc_id, item_id) {
+                let _ = <CollectionItemVersionsMap<T>>::clear_prefix((collection_loc_id, item_id), latest_version, None);
+            }
+            for (_, (depositor, deposit)) in <CollectionItemDepositsMap<T>>::drain_prefix((collection_loc_id, item_id)) {
+                Self::release_deposit(collection_loc_id, &depositor, deposit);
+            }
+            // Records still targeting the item keep it in their targets, its ID being never reused
+            let _ = <CollectionItemTokensRecordsMap<T>>::clear_prefix((collection_loc_id, item_id), u32::MAX, None);
+        }
+
+        /// Removes a tokens record and its relations, releasing its storage deposit.
+        fn remove_tokens_record_data(collection_loc_id: &T::LocId, record_id: &T::TokensRecordId) {
+            <TokensRecordsMap<T>>::remove(collection_loc_id, record_id);
+            if let Some(targets) = <TokensRecordTargetsMap<T>>::take(collection_loc_id, record_id) {
+                for item_id in targets.iter() {
+                    <CollectionItemTokensRecordsMap<T>>::remove((*collection_loc_id, *item_id), record_id);
+                }
+            }
+            <TokensRecordRevocationsMap<T>>::remove(collection_loc_id, record_id);
+            if let Some(superseded_record_id) = <TokensRecordSupersedesMap<T>>::take(collection_loc_id, record_id) {
+                <TokensRecordSupersededByMap<T>>::remove(collection_loc_id, superseded_record_id);
+            }
+            if let Some(superseding_record_id) = <TokensRecordSupersededByMap<T>>::take(collection_loc_id, record_id) {
+                <TokensRecordSupersedesMap<T>>::remove(collection_loc_id, superseding_record_id);
+            }
+            if let Some((depositor, deposit)) = <TokensRecordDepositsMap<T>>::take(collection_loc_id, record_id) {
+                Self::release_deposit(collection_loc_id, &depositor, deposit);
+            }
+        }
+
+        /// The LOCs having a link to given LOC.
+        pub fn locs_linking_to(loc_id: &T::LocId) -> Result<Vec<T::LocId>, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            Ok(<LinksToLocMap<T>>::iter_key_prefix(loc_id).collect())
+        }
+
+        fn index_links<I>(source: &T::LocId, targets: I) where I: Iterator<Item = T::LocId> {
+            for target in targets {
+                <LinksToLocMap<T>>::insert(target, source, ());
+            }
+        }
+
+        /// The storage deposits currently held for given LOC, by depositor.
+        pub fn storage_deposits_of(loc_id: &T::LocId) -> Result<Vec<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+            Self::ensure_no_migration_in_progress()?;
+            Ok(<StorageDepositsMap<T>>::iter_prefix(loc_id).collect())
+        }
+
         pub fn calculate_fee(num_of_entries: u32, tot_size: u32) -> BalanceOf<T> {
             let byte_fee: BalanceOf<T> = T::FileStorageByteFee::get();
             let entry_fee: BalanceOf<T> = T::FileStorageEntryFee::get();
@@ -2552,34 +4846,375 @@ pub mod pallet {
                 .saturating_add(entry_fee.saturating_mul(num_of_entries.into()))
         }
 
-        fn apply_legal_fee(loc: &LegalOfficerCaseOf<T>) -> DispatchResult {
-            let fee_payer: Option<T::AccountId> = match loc.sponsorship_id {
+        /// Resolves the account paying for items added to given LOC. The requester or the sponsor may only be
+        /// charged by another signer within the fee allowance they granted to this signer.
+        fn fee_payer_account(
+            loc_id: &T::LocId,
+            loc: &LegalOfficerCaseOf<T>,
+            signer: &T::AccountId,
+            fee_payer: FeePayer,
+            fees: BalanceOf<T>,
+        ) -> Result<T::AccountId, sp_runtime::DispatchError> {
+            let fee_payer_account = match fee_payer {
+                FeePayer::Signer => signer.clone(),
+                FeePayer::Requester => match &loc.requester {
+                    Account(requester_account) => requester_account.clone(),
+                    _ => Err(Error::<T>::InvalidFeePayer)?,
+                },
+                FeePayer::Sponsor => Self::sponsor_of(loc)?.ok_or(Error::<T>::InvalidFeePayer)?,
+            };
+            if fee_payer_account != *signer {
+                Self::consume_fee_allowance(loc_id, &fee_payer_account, signer, fees, true)?;
+            }
+            Ok(fee_payer_account)
+        }
+
+        /// Resolves the account paying for a file added to given LOC when no payer was chosen: the sponsor, the requester
+        /// or, if the requester is not a Polkadot account, the owner.
+        fn default_fee_payer_account(loc: &LegalOfficerCaseOf<T>) -> Result<T::AccountId, sp_runtime::DispatchError> {
+            match Self::sponsor_of(loc)? {
+                Some(sponsor) => Ok(sponsor),
+                None => match &loc.requester {
+                    Account(requester_account) => Ok(requester_account.clone()),
+                    _ => Ok(loc.owner.clone()),
+                },
+            }
+        }
+
+        fn sponsor_of(loc: &LegalOfficerCaseOf<T>) -> Result<Option<T::AccountId>, sp_runtime::DispatchError> {
+            match loc.sponsorship_id {
                 Some(sponsorship_id) => {
-                    let sponsorship = <SponsorshipMap<T>>::get(sponsorship_id).unwrap();
-                    Some(sponsorship.sponsor)
+                    let sponsorship = <SponsorshipMap<T>>::get(sponsorship_id).ok_or(Error::<T>::InvalidFeePayer)?;
+                    Ok(Some(sponsorship.sponsor))
+                },
+                None => Ok(None),
+            }
+        }
+
+        fn do_acknowledge_items(
+            who: &T::AccountId,
+            loc_id: T::LocId,
+            metadata: &[<T as pallet::Config>::Hash],
+            files: &[<T as pallet::Config>::Hash],
+            links: &[T::LocId],
+        ) -> DispatchResultWithPostInfo {
+            let loc = Self::loc(&loc_id).ok_or(Error::<T>::NotFound)?;
+            let ack_by_owner = Self::is_owner_or_co_owner(&loc_id, &loc, who);
+            let ack_by_verified_issuer = Self::selected_verified_issuers(loc_id, who).is_some();
+            if !ack_by_owner && !ack_by_verified_issuer {
+                Err(Error::<T>::Unauthorized)?
+            } else if loc.closed {
+                Err(Error::<T>::CannotMutate)?
+            } else if loc.void_info.is_some() {
+                Err(Error::<T>::CannotMutateVoid)?
+            }
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            Self::acknowledge_in::<LocMetadataMap<T>, _, _>(&loc_id, &loc, metadata, LocItemKey::Metadata, who, ack_by_owner, current_block)?;
+            Self::acknowledge_in::<LocFilesMap<T>, _, _>(&loc_id, &loc, files, LocItemKey::File, who, ack_by_owner, current_block)?;
+            Self::acknowledge_in::<LocLinksMap<T>, _, _>(&loc_id, &loc, links, LocItemKey::Link, who, ack_by_owner, current_block)?;
+
+            let acknowledged_items = metadata.iter().map(|name| LocItemKey::Metadata(*name))
+                .chain(files.iter().map(|hash| LocItemKey::File(*hash)))
+                .chain(links.iter().map(|id| LocItemKey::Link(*id)));
+            for item in acknowledged_items {
+                Self::deposit_event(Event::ItemAcknowledged(loc_id, item, who.clone()));
+            }
+            Ok(().into())
+        }
+
+        /// Acknowledges the items of given LOC matching given keys, a verified issuer being only allowed to
+        /// acknowledge the items it submitted. An item of a co-owned LOC is acknowledged by owners once
+        /// the approval threshold is reached.
+        fn acknowledge_in<M, K, I>(
+            loc_id: &T::LocId,
+            loc: &LegalOfficerCaseOf<T>,
+            keys: &[K],
+            item_key: fn(K) -> LocItemKeyOf<T>,
+            who: &T::AccountId,
+            ack_by_owner: bool,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResult
+        where
+            M: frame_support::storage::StorageDoubleMap<T::LocId, K, I, Query = Option<I>>,
+            K: FullCodec + Copy,
+            I: FullCodec + AcknowledgeableItem<T::AccountId, T::EthereumAddress, BlockNumberFor<T>>,
+        {
+            for key in keys.iter() {
+                M::try_mutate(loc_id, key, |maybe_item| -> DispatchResult {
+                    let item = maybe_item.as_mut().ok_or(Error::<T>::ItemNotFound)?;
+                    if ack_by_owner {
+                        if item.acknowledged_by_owner() {
+                            Err(Error::<T>::ItemAlreadyAcknowledged)?
+                        }
+                        if Self::acknowledge_as_owner(loc_id, loc, item_key(*key), who)? {
+                            item.acknowledge(true, block_number);
+                        }
+                    } else {
+                        if item.acknowledged_by_verified_issuer() {
+                            Err(Error::<T>::ItemAlreadyAcknowledged)?
+                        }
+                        match item.submitter() {
+                            Polkadot(polkadot_submitter) if polkadot_submitter == who => (),
+                            _ => Err(Error::<T>::Unauthorized)?
+                        }
+                        item.acknowledge(false, block_number);
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        }
+
+        /// Acknowledges by owner the items of given map, at most `count` items being walked.
+        fn acknowledge_all_by_owner<M, K, I>(loc_id: &T::LocId, count: u32, block_number: BlockNumberFor<T>)
+        where
+            M: frame_support::storage::IterableStorageDoubleMap<T::LocId, K, I>,
+            K: FullCodec,
+            I: FullCodec + AcknowledgeableItem<T::AccountId, T::EthereumAddress, BlockNumberFor<T>>,
+        {
+            let unacknowledged: Vec<(K, I)> = M::iter_prefix(loc_id)
+                .take(count as usize)
+                .filter(|(_, item)| !item.acknowledged_by_owner())
+                .collect();
+            for (key, mut item) in unacknowledged {
+                item.acknowledge(true, block_number);
+                M::insert(loc_id, key, item);
+            }
+        }
+
+        /// The owner may delete any item, other submitters only their own items not yet acknowledged.
+        fn ensure_can_delete(
+            loc: &LegalOfficerCaseOf<T>,
+            who: &T::AccountId,
+            submitter: &SupportedAccountId<T::AccountId, T::EthereumAddress>,
+            acknowledged: bool,
+        ) -> DispatchResult {
+            if loc.closed {
+                Err(Error::<T>::CannotMutate)?
+            } else if loc.void_info.is_some() {
+                Err(Error::<T>::CannotMutateVoid)?
+            }
+            let submitted_by_caller = match submitter {
+                Polkadot(polkadot_submitter) => polkadot_submitter == who,
+                _ => false,
+            };
+            if !loc.is_owner(who) && !(submitted_by_caller && !acknowledged) {
+                Err(Error::<T>::Unauthorized)?
+            }
+            Ok(())
+        }
+
+        /// Only the requester or the sponsor of a LOC may grant fee allowances on it.
+        fn ensure_can_grant_fee_allowance(loc_id: &T::LocId, who: &T::AccountId) -> Result<LegalOfficerCaseOf<T>, sp_runtime::DispatchError> {
+            let loc = Self::loc(loc_id).ok_or(Error::<T>::NotFound)?;
+            let is_requester = match &loc.requester { Account(requester_account) => requester_account == who, _ => false };
+            let is_sponsor = Self::sponsor_of(&loc)?.map_or(false, |sponsor| sponsor == *who);
+            if !is_requester && !is_sponsor {
+                Err(Error::<T>::Unauthorized)?
+            }
+            Ok(loc)
+        }
+
+        /// Decrements the allowance granted by a payer to a contributor, failing if it does not cover given fees.
+        /// Without allowance, fees are charged only if no allowance is `required`, as an allowance is opt-in for
+        /// the payers charged by default.
+        fn consume_fee_allowance(
+            loc_id: &T::LocId,
+            payer: &T::AccountId,
+            contributor: &T::AccountId,
+            fees: BalanceOf<T>,
+            required: bool,
+        ) -> DispatchResult {
+            <FeeAllowancesMap<T>>::try_mutate((loc_id, payer, contributor), |allowance| {
+                match allowance {
+                    Some(remaining) if *remaining >= fees => {
+                        *remaining = remaining.saturating_sub(fees);
+                        Ok(())
+                    },
+                    None if !required => Ok(()),
+                    _ => Err(Error::<T>::InsufficientFeeAllowance.into()),
                 }
-                _ => {
+            })
+        }
+
+        fn legal_fee_payer(loc: &LegalOfficerCaseOf<T>) -> Result<Option<T::AccountId>, sp_runtime::DispatchError> {
+            match Self::sponsor_of(loc)? {
+                Some(sponsor) => Ok(Some(sponsor)),
+                None => {
                     match loc.requester.clone() {
-                        Account(requester_account) => Some(requester_account),
-                        _ => None
+                        Account(requester_account) => Ok(Some(requester_account)),
+                        _ => Ok(None)
                     }
                 }
-            };
+            }
+        }
+
+        fn apply_legal_fee(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> DispatchResult {
+            let fee_payer = Self::legal_fee_payer(loc)?;
             if fee_payer.is_some() {
-                let fee = loc.legal_fee;
-                let (beneficiary, _) = Self::slash_and_distribute(&fee_payer.as_ref().unwrap(), fee, &|credit| {
-                    let distribution_key = match loc.loc_type {
-                        LocType::Identity => T::IdentityLocLegalFeeDistributionKey::get(),
-                        LocType::Transaction => T::TransactionLocLegalFeeDistributionKey::get(),
-                        LocType::Collection => T::CollectionItemFeeDistributionKey::get(),
-                    };
-                    T::RewardDistributor::distribute_with_loc_owner(credit, distribution_key, &loc.owner.clone())
-                })?;
-                Self::deposit_event(Event::LegalFeeWithdrawn(fee_payer.unwrap(), beneficiary, fee));
+                let mut main_beneficiary = None;
+                for (owner, fee) in Self::legal_fee_shares(loc_id, loc) {
+                    let (beneficiary, _) = Self::slash_and_distribute(&fee_payer.as_ref().unwrap(), fee, &|credit| {
+                        T::RewardDistributor::distribute_with_loc_owner(credit, Self::fee_distribution_key(FeeKind::Legal, loc.loc_type), &owner)
+                    })?;
+                    main_beneficiary.get_or_insert(beneficiary);
+                }
+                if let Some(beneficiary) = main_beneficiary {
+                    Self::deposit_event(Event::LegalFeeWithdrawn(fee_payer.unwrap(), beneficiary, loc.legal_fee));
+                }
+            }
+            Ok(())
+        }
+
+        /// Gives back to the legal fee payer the share the LOC owners received when the LOC was created.
+        fn refund_legal_fee(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> DispatchResult {
+            let fee_payer = Self::legal_fee_payer(loc)?;
+            if fee_payer.is_some() {
+                let payer = fee_payer.unwrap();
+                for (owner, fee) in Self::legal_fee_shares(loc_id, loc) {
+                    let owner_share = Self::fee_distribution_key(FeeKind::Legal, loc.loc_type).loc_owner_percent * fee;
+                    if !owner_share.is_zero() {
+                        T::Currency::transfer(&owner, &payer, owner_share, ExistenceRequirement::KeepAlive)
+                            .map_err(|_| Error::<T>::InsufficientFunds)?;
+                        Self::deposit_event(Event::LegalFeeRefunded(payer.clone(), owner_share));
+                    }
+                }
             }
             Ok(())
         }
 
+        /// Splits the legal fee evenly between LOC owners, the main owner receiving the remainder.
+        fn legal_fee_shares(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let owners = Self::owners_of(loc_id, loc);
+            let owners_count: BalanceOf<T> = (owners.len() as u32).into();
+            let share = loc.legal_fee / owners_count;
+            let remainder = loc.legal_fee.saturating_sub(share.saturating_mul(owners_count));
+            owners.into_iter()
+                .enumerate()
+                .map(|(index, owner)| (owner, if index == 0 { share.saturating_add(remainder) } else { share }))
+                .collect()
+        }
+
+        /// The LOC owner followed by its co-owners, if any.
+        fn owners_of(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> Vec<T::AccountId> {
+            let mut owners = Vec::from([ loc.owner.clone() ]);
+            if let Some(co_ownership) = Self::co_ownership(loc_id) {
+                owners.extend(co_ownership.co_owners.into_iter());
+            }
+            owners
+        }
+
+        /// Transfers a LOC from a legal officer to another, the verified issuers selected on the LOC
+        /// being taken from `remaining_issuers`. Returns false, leaving the LOC untouched, if there are
+        /// not enough remaining issuers. If the target already owns or co-owns the LOC, the source
+        /// is merged into the target.
+        fn transfer_loc(loc_id: &T::LocId, from: &T::AccountId, to: &T::AccountId, remaining_issuers: &mut u32) -> Result<bool, sp_runtime::DispatchError> {
+            let mut loc = Self::loc(loc_id).ok_or(Error::<T>::NotFound)?;
+            let merge = Self::is_owner_or_co_owner(loc_id, &loc, to);
+            if loc.owner == *from {
+                let issuers: Vec<T::AccountId> = <VerifiedIssuersByLocMap<T>>::iter_key_prefix(loc_id)
+                    .take((*remaining_issuers as usize).saturating_add(1))
+                    .collect();
+                if issuers.len() > *remaining_issuers as usize {
+                    return Ok(false);
+                }
+                *remaining_issuers = remaining_issuers.saturating_sub(issuers.len() as u32);
+                loc.owner = to.clone();
+                <LocMap<T>>::insert(loc_id, loc);
+                for issuer in issuers {
+                    <LocsByVerifiedIssuerMap<T>>::remove((&issuer, from, loc_id));
+                    <LocsByVerifiedIssuerMap<T>>::insert((&issuer, to, loc_id), ());
+                }
+                if merge {
+                    Self::remove_co_owner(loc_id, to);
+                }
+            } else if merge {
+                Self::remove_co_owner(loc_id, from);
+            } else {
+                <CoOwnershipMap<T>>::mutate(loc_id, |maybe_co_ownership| {
+                    if let Some(co_ownership) = maybe_co_ownership {
+                        co_ownership.co_owners.iter_mut()
+                            .filter(|co_owner| **co_owner == *from)
+                            .for_each(|co_owner| *co_owner = to.clone());
+                    }
+                });
+            }
+            if let Some(approval) = <OwnerApprovalsMap<T>>::take(loc_id, from) {
+                if !merge {
+                    <OwnerApprovalsMap<T>>::insert(loc_id, to, approval);
+                }
+            }
+            <LocsByOwnerMap<T>>::remove(from, loc_id);
+            <LocsByOwnerMap<T>>::insert(to, loc_id, ());
+            Self::deposit_event(Event::LocOwnershipTransferred(*loc_id, from.clone(), to.clone()));
+            Ok(true)
+        }
+
+        /// Drops a co-owner, capping the approval threshold by the remaining number of owners.
+        /// The LOC is no longer co-owned when its last co-owner is dropped.
+        fn remove_co_owner(loc_id: &T::LocId, co_owner: &T::AccountId) {
+            if let Some(mut co_ownership) = Self::co_ownership(loc_id) {
+                co_ownership.co_owners.retain(|account| account != co_owner);
+                if co_ownership.co_owners.is_empty() {
+                    <CoOwnershipMap<T>>::remove(loc_id);
+                    Self::clear_owner_approvals(loc_id);
+                } else {
+                    co_ownership.threshold = co_ownership.threshold.min(co_ownership.co_owners.len() as u32 + 1);
+                    <CoOwnershipMap<T>>::insert(loc_id, co_ownership);
+                }
+            }
+        }
+
+        fn is_owner_or_co_owner(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, who: &T::AccountId) -> bool {
+            loc.owner == *who
+                || Self::co_ownership(loc_id).map_or(false, |co_ownership| co_ownership.co_owners.contains(who))
+        }
+
+        /// Records the approval of an action by an owner of a co-owned LOC and tells if the action
+        /// can be executed i.e. the approval threshold is reached. Approvals are keyed by the hash of
+        /// the action including its parameters, an owner approving another action withdraws its previous
+        /// approval. Actions on LOCs without co-owners are always executed.
+        fn approve_owner_action(loc_id: &T::LocId, action: OwnerActionOf<T>, who: &T::AccountId) -> Result<bool, sp_runtime::DispatchError> {
+            match Self::co_ownership(loc_id) {
+                None => Ok(true),
+                Some(co_ownership) => {
+                    let action_hash = T::Hasher::hash(&action.encode());
+                    ensure!(Self::owner_approval(loc_id, who) != Some(action_hash), Error::<T>::AlreadyApproved);
+                    <OwnerApprovalsMap<T>>::insert(loc_id, who, action_hash);
+                    Self::deposit_event(Event::OwnerActionApproved(*loc_id, action, who.clone()));
+
+                    let approvals = <OwnerApprovalsMap<T>>::iter_prefix_values(loc_id)
+                        .filter(|approved| *approved == action_hash)
+                        .count() as u32;
+                    Ok(approvals >= co_ownership.threshold)
+                }
+            }
+        }
+
+        /// Records the acknowledgement of an item by an owner of a co-owned LOC and tells if the item is
+        /// acknowledged by owners i.e. the approval threshold is reached. The items of LOCs without co-owners
+        /// are acknowledged by their single owner.
+        fn acknowledge_as_owner(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, item_key: LocItemKeyOf<T>, who: &T::AccountId) -> Result<bool, sp_runtime::DispatchError> {
+            match Self::co_ownership(loc_id) {
+                None => Ok(true),
+                Some(co_ownership) => {
+                    ensure!(!<OwnerAcknowledgementsMap<T>>::contains_key((loc_id, &item_key, who)), Error::<T>::ItemAlreadyAcknowledged);
+                    <OwnerAcknowledgementsMap<T>>::insert((loc_id, &item_key, who), ());
+
+                    let acknowledgements = sp_std::iter::once(&loc.owner)
+                        .chain(co_ownership.co_owners.iter())
+                        .filter(|owner| <OwnerAcknowledgementsMap<T>>::contains_key((loc_id, &item_key, *owner)))
+                        .count() as u32;
+                    Ok(acknowledgements >= co_ownership.threshold)
+                }
+            }
+        }
+
+        /// Removes all pending approvals of a LOC, there is at most one per owner.
+        fn clear_owner_approvals(loc_id: &T::LocId) {
+            let _ = <OwnerApprovalsMap<T>>::clear_prefix(loc_id, T::MaxLocCoOwners::get().saturating_add(1), None);
+        }
+
         fn can_link_to_sponsorship(
             sponsorship_id: &T::SponsorshipId,
             expected_owner: &T::AccountId,
@@ -2603,10 +5238,36 @@ pub mod pallet {
             });
         }
 
-        fn ensure_valid_links(links: &Vec<LocLinkParams<T::LocId, <T as pallet::Config>::Hash, T::AccountId, T::EthereumAddress>>) -> Result<(), sp_runtime::DispatchError> {
+        fn ensure_valid_links(owner: &T::AccountId, links: &Vec<LocLinkParams<T::LocId, <T as pallet::Config>::Hash, T::AccountId, T::EthereumAddress>>) -> Result<(), sp_runtime::DispatchError> {
+            for link in links.iter() {
+                Self::ensure_valid_link(owner, &link.id, &link.nature)?;
+            }
+            Ok(())
+        }
+
+        fn ensure_valid_link(owner: &T::AccountId, target_id: &T::LocId, nature: &<T as pallet::Config>::Hash) -> Result<(), sp_runtime::DispatchError> {
+            let target = Self::loc(target_id).ok_or(Error::<T>::LinkedLocNotFound)?;
+            Self::ensure_link_rules(owner, &target, &T::LinkRules::rules(nature))
+        }
+
+        fn ensure_valid_imported_links(owner: &T::AccountId, links: &Vec<LocLinkOf<T>>) -> Result<(), sp_runtime::DispatchError> {
             for link in links.iter() {
-                if Self::loc(link.id).is_none() {
-                    Err(Error::<T>::LinkedLocNotFound)?;
+                let rules = T::LinkRules::rules(&link.nature);
+                if !rules.is_empty() {
+                    let target = Self::loc(link.id).ok_or(Error::<T>::LinkedLocNotFound)?;
+                    Self::ensure_link_rules(owner, &target, &rules)?;
+                }
+            }
+            Ok(())
+        }
+
+        fn ensure_link_rules(owner: &T::AccountId, target: &LegalOfficerCaseOf<T>, rules: &Vec<LinkRule>) -> Result<(), sp_runtime::DispatchError> {
+            for rule in rules.iter() {
+                match rule {
+                    LinkRule::TargetClosed => ensure!(target.closed, Error::<T>::LinkedLocNotClosed),
+                    LinkRule::TargetNotVoid => ensure!(target.void_info.is_none(), Error::<T>::LinkedLocVoid),
+                    LinkRule::SameOwner => ensure!(target.owner == *owner, Error::<T>::LinkedLocOwnerMismatch),
+                    LinkRule::TargetType(loc_type) => ensure!(target.loc_type == *loc_type, Error::<T>::LinkedLocTypeMismatch),
                 }
             }
             Ok(())
@@ -1,51 +1,619 @@
-use frame_support::traits::Get;
-use frame_support::weights::Weight;
-use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::traits::{ConstU32, Get, OnRuntimeUpgrade, StorageVersion};
+use frame_support::weights::{Weight, WeightMeter};
 use sp_io::{hashing::twox_128, storage::clear_prefix, KillStorageResult};
 
-use crate::{Config, PalletStorageVersion, pallet::StorageVersion};
+use crate::Config;
 use super::*;
 
+/// The raw storage key of the last entry processed by a stepped migration.
+pub type MigrationCursor = BoundedVec<u8, ConstU32<128>>;
+
+/// The progress of a stepped migration.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MigrationStatus {
+    /// The migration resumes after this entry, it starts from the first entry if `None`.
+    pub cursor: Option<MigrationCursor>,
+    /// The number of entries processed so far.
+    pub processed: u32,
+}
+
+/// A storage migration executed over several blocks.
+///
+/// The migration is started by `on_runtime_upgrade` if the on-chain storage version is `FROM_VERSION`.
+/// Each block, `step` is then called with the remaining migration weight until it returns `true`, at
+/// which point the on-chain storage version is set to `TO_VERSION`. Dispatchables are rejected in the
+/// meantime.
+pub trait SteppedMigration {
+
+    /// The storage version the migration applies to.
+    const FROM_VERSION: u16;
+
+    /// The storage version once the migration completed.
+    const TO_VERSION: u16;
+
+    /// Processes entries after `status.cursor` as long as `meter` allows it, updates `status`
+    /// accordingly and returns `true` if there are no more entries to process.
+    fn step(status: &mut MigrationStatus, meter: &mut WeightMeter) -> bool;
+
+    /// The maximum weight of processing a single entry, a step must be given at least this weight
+    /// for the migration to make progress.
+    fn max_entry_weight() -> Weight;
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        Ok(Vec::new())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        Ok(())
+    }
+}
+
+/// No migration.
+impl SteppedMigration for () {
+
+    const FROM_VERSION: u16 = 0;
+
+    const TO_VERSION: u16 = 0;
+
+    fn step(_status: &mut MigrationStatus, _meter: &mut WeightMeter) -> bool {
+        true
+    }
+
+    fn max_entry_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 pub mod v23 {
+    use frame_support::migrations::VersionedMigration;
     use super::*;
     use crate::*;
+    use frame_support::Blake2_128Concat;
+
+    #[frame_support::storage_alias]
+    pub type LocMap<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::LocId,
+        LegalOfficerCaseV23Of<T>,
+    >;
+
+    /// Removes useless maps and adds the `imported` flag, executed only if the on-chain storage version is 22.
+    pub type RemoveUselessMapsAddImported<P, T> = VersionedMigration<
+        22,
+        23,
+        RemoveUselessMapsAddImportedUnversioned<P, T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
 
-    pub struct RemoveUselessMapsAddImported<P: Get<&'static str>, T>(sp_std::marker::PhantomData<(P, T)>);
+    pub struct RemoveUselessMapsAddImportedUnversioned<P: Get<&'static str>, T>(sp_std::marker::PhantomData<(P, T)>);
 
-    impl<P: Get<&'static str>, T: Config> OnRuntimeUpgrade for RemoveUselessMapsAddImported<P, T>
-        where <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance: From<u128> {
+    impl<P: Get<&'static str>, T: Config> OnRuntimeUpgrade for RemoveUselessMapsAddImportedUnversioned<P, T> {
 
         fn on_runtime_upgrade() -> Weight {
-            super::do_storage_upgrade::<T, _>(
-                StorageVersion::V22AddRecurrentFees,
-                StorageVersion::V23RemoveUselessMapsAddImported,
-                "RemoveUselessMapsAddImported",
-                || {
-					super::clear_storage::<T>(P::get(), "IdentityLocLocsMap")
-						.saturating_add(super::clear_storage::<T>(P::get(), "OtherAccountLocsMap"))
-                        .saturating_add(add_imported_flag::<T>())
+            super::clear_storage::<T>(P::get(), "IdentityLocLocsMap")
+                .saturating_add(super::clear_storage::<T>(P::get(), "OtherAccountLocsMap"))
+                .saturating_add(add_imported_flag::<T>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let locs = LocMap::<T>::iter_keys().count() as u64;
+            frame_support::ensure!(LocMap::<T>::iter_values().count() as u64 == locs, "Some LOCs cannot be decoded");
+            Ok(locs.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let locs: u64 = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            frame_support::ensure!(LocMap::<T>::iter_keys().count() as u64 == locs, "Some LOCs were lost");
+            frame_support::ensure!(LocMap::<T>::iter_values().count() as u64 == locs, "Some LOCs cannot be decoded");
+            Ok(())
+        }
+    }
+
+    fn add_imported_flag<T: Config>() -> Weight {
+        let mut number_translated = 0;
+
+        LocMap::<T>::translate_values(|loc: LegalOfficerCaseV22Of<T>| {
+            let translated = LegalOfficerCaseV23 {
+                owner: loc.owner,
+                requester: loc.requester,
+                metadata: loc.metadata,
+                files: loc.files,
+                closed: loc.closed,
+                loc_type: loc.loc_type,
+                links: loc.links,
+                void_info: loc.void_info,
+                replacer_of: loc.replacer_of,
+                collection_last_block_submission: loc.collection_last_block_submission,
+                collection_max_size: loc.collection_max_size,
+                collection_can_upload: loc.collection_can_upload,
+                seal: loc.seal,
+                sponsorship_id: loc.sponsorship_id,
+                value_fee: loc.value_fee,
+                legal_fee: loc.value_fee,
+                collection_item_fee: loc.collection_item_fee,
+                tokens_record_fee: loc.tokens_record_fee,
+                imported: false,
+            };
+            number_translated += 1;
+            Some(translated)
+        });
+
+        CollectionItemsMap::<T>::translate_values(|loc: CollectionItemV22Of<T>| {
+            let translated = CollectionItem {
+                description: loc.description,
+                files: loc.files,
+                token: loc.token,
+                restricted_delivery: loc.restricted_delivery,
+                terms_and_conditions: loc.terms_and_conditions,
+                imported: false,
+            };
+            number_translated += 1;
+            Some(translated)
+        });
+
+        TokensRecordsMap::<T>::translate_values(|record: TokensRecordV22Of<T>| {
+            let translated = TokensRecord {
+                description: record.description,
+                files: record.files,
+                submitter: record.submitter,
+                imported: false,
+            };
+            number_translated += 1;
+            Some(translated)
+        });
+
+        VerifiedIssuersMap::<T>::translate_values(|issuer: VerifiedIssuerV22Of<T>| {
+            let translated = VerifiedIssuer {
+                identity_loc: issuer.identity_loc,
+                imported: false,
+            };
+            number_translated += 1;
+            Some(translated)
+        });
+
+        SponsorshipMap::<T>::translate_values(|sponsorship: SponsorshipV22Of<T>| {
+            let translated = Sponsorship {
+                sponsor: sponsorship.sponsor,
+                sponsored_account: sponsorship.sponsored_account,
+                legal_officer: sponsorship.legal_officer,
+                loc_id: sponsorship.loc_id,
+                imported: false,
+            };
+            number_translated += 1;
+            Some(translated)
+        });
+
+        T::DbWeight::get().reads_writes(number_translated, number_translated)
+    }
+}
+
+pub mod v24 {
+    use frame_support::migrations::VersionedMigration;
+    use super::*;
+    use crate::*;
+    use frame_support::Blake2_128Concat;
+
+    #[frame_support::storage_alias]
+    pub type LocMap<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::LocId,
+        LegalOfficerCaseV26Of<T>,
+    >;
+
+    /// Adds block numbers to LOCs and their items, executed only if the on-chain storage version is 23.
+    pub type AddBlockNumbers<T> = VersionedMigration<
+        23,
+        24,
+        AddBlockNumbersUnversioned<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    pub struct AddBlockNumbersUnversioned<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for AddBlockNumbersUnversioned<T> {
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut number_translated = 0;
+            LocMap::<T>::translate_values(|loc: LegalOfficerCaseV23Of<T>| {
+                let translated = LegalOfficerCaseV26 {
+                    owner: loc.owner,
+                    requester: loc.requester,
+                    metadata: BoundedVec::truncate_from(loc.metadata.into_iter().map(|item| MetadataItem {
+                        name: item.name,
+                        value: item.value,
+                        submitter: item.submitter,
+                        acknowledged_by_owner: item.acknowledged_by_owner,
+                        acknowledged_by_verified_issuer: item.acknowledged_by_verified_issuer,
+                        submitted_on: None,
+                        acknowledged_by_owner_on: None,
+                        acknowledged_by_verified_issuer_on: None,
+                    }).collect()),
+                    files: BoundedVec::truncate_from(loc.files.into_iter().map(|file| File {
+                        hash: file.hash,
+                        nature: file.nature,
+                        submitter: file.submitter,
+                        size: file.size,
+                        acknowledged_by_owner: file.acknowledged_by_owner,
+                        acknowledged_by_verified_issuer: file.acknowledged_by_verified_issuer,
+                        submitted_on: None,
+                        acknowledged_by_owner_on: None,
+                        acknowledged_by_verified_issuer_on: None,
+                    }).collect()),
+                    closed: loc.closed,
+                    loc_type: loc.loc_type,
+                    links: BoundedVec::truncate_from(loc.links.into_iter().map(|link| LocLink {
+                        id: link.id,
+                        nature: link.nature,
+                        submitter: link.submitter,
+                        acknowledged_by_owner: link.acknowledged_by_owner,
+                        acknowledged_by_verified_issuer: link.acknowledged_by_verified_issuer,
+                        submitted_on: None,
+                        acknowledged_by_owner_on: None,
+                        acknowledged_by_verified_issuer_on: None,
+                    }).collect()),
+                    void_info: loc.void_info,
+                    replacer_of: loc.replacer_of,
+                    collection_last_block_submission: loc.collection_last_block_submission,
+                    collection_max_size: loc.collection_max_size,
+                    collection_can_upload: loc.collection_can_upload,
+                    seal: loc.seal,
+                    sponsorship_id: loc.sponsorship_id,
+                    value_fee: loc.value_fee,
+                    legal_fee: loc.legal_fee,
+                    collection_item_fee: loc.collection_item_fee,
+                    tokens_record_fee: loc.tokens_record_fee,
+                    imported: loc.imported,
+                    created_on: None,
+                    closed_on: None,
+                    voided_on: None,
+                };
+                number_translated += 1;
+                Some(translated)
+            });
+            T::DbWeight::get().reads_writes(number_translated, number_translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let locs = v23::LocMap::<T>::iter_keys().count() as u64;
+            frame_support::ensure!(v23::LocMap::<T>::iter_values().count() as u64 == locs, "Some LOCs cannot be decoded");
+            Ok(locs.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let locs: u64 = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            frame_support::ensure!(LocMap::<T>::iter_keys().count() as u64 == locs, "Some LOCs were lost");
+            frame_support::ensure!(LocMap::<T>::iter_values().count() as u64 == locs, "Some LOCs cannot be decoded");
+            Ok(())
+        }
+    }
+}
+
+pub mod v25 {
+    use frame_support::migrations::VersionedMigration;
+    use super::*;
+    use crate::*;
+
+    /// Indexes the LOCs linking to a LOC, executed only if the on-chain storage version is 24.
+    pub type IndexLinksToLoc<T> = VersionedMigration<
+        24,
+        25,
+        IndexLinksToLocUnversioned<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    pub struct IndexLinksToLocUnversioned<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for IndexLinksToLocUnversioned<T> {
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut number_read = 0;
+            let mut number_indexed = 0;
+            for (loc_id, loc) in v24::LocMap::<T>::iter() {
+                number_read += 1;
+                for link in loc.links.iter() {
+                    LinksToLocMap::<T>::insert(link.id, loc_id, ());
+                    number_indexed += 1;
+                }
+            }
+            T::DbWeight::get().reads_writes(number_read, number_indexed)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let links: u64 = v24::LocMap::<T>::iter_values()
+                .map(|loc| loc.links.len() as u64)
+                .sum();
+            Ok(links.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let links: u64 = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            frame_support::ensure!(LinksToLocMap::<T>::iter_keys().count() as u64 == links, "Some links were not indexed");
+            Ok(())
+        }
+    }
+}
+
+pub mod v26 {
+    use frame_support::migrations::VersionedMigration;
+    use super::*;
+    use crate::*;
+
+    /// Indexes LOCs by owner, executed only if the on-chain storage version is 25.
+    pub type IndexLocsByOwner<T> = VersionedMigration<
+        25,
+        26,
+        IndexLocsByOwnerUnversioned<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    pub struct IndexLocsByOwnerUnversioned<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for IndexLocsByOwnerUnversioned<T> {
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut number_read = 0;
+            let mut number_indexed = 0;
+            for (loc_id, loc) in v24::LocMap::<T>::iter() {
+                number_read += 1;
+                LocsByOwnerMap::<T>::insert(&loc.owner, loc_id, ());
+                number_indexed += 1;
+            }
+            for (loc_id, co_ownership) in CoOwnershipMap::<T>::iter() {
+                number_read += 1;
+                for co_owner in co_ownership.co_owners.iter() {
+                    LocsByOwnerMap::<T>::insert(co_owner, loc_id, ());
+                    number_indexed += 1;
+                }
+            }
+            T::DbWeight::get().reads_writes(number_read, number_indexed)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let locs = v24::LocMap::<T>::iter_keys().count() as u64;
+            Ok(locs.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let locs: u64 = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            frame_support::ensure!(LocsByOwnerMap::<T>::iter_keys().count() as u64 >= locs, "Some LOCs were not indexed");
+            Ok(())
+        }
+    }
+}
+
+pub mod v27 {
+    use super::*;
+    use crate::*;
+    use frame_support::Blake2_128Concat;
+
+    #[frame_support::storage_alias]
+    pub type LocMap<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::LocId,
+        LegalOfficerCaseV26Of<T>,
+    >;
+
+    pub struct MoveLocItems<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> MoveLocItems<T> {
+
+        /// The weight of reading a LOC, its encoded size being bounded by the item limits in force when it was written.
+        pub fn read_weight() -> Weight {
+            T::DbWeight::get().reads(1)
+                .saturating_add(Weight::from_parts(0, LegalOfficerCaseV26Of::<T>::max_encoded_len() as u64))
+        }
+
+        /// The weight of moving given number of items of a LOC. Each item is written with its position,
+        /// the LOC and the position counter being written once.
+        pub fn move_weight(items: u32) -> Weight {
+            T::DbWeight::get().writes(2u64.saturating_add(2u64.saturating_mul(items as u64)))
+        }
+
+        fn items_of(loc: &LegalOfficerCaseV26Of<T>) -> u32 {
+            (loc.metadata.len() + loc.files.len() + loc.links.len()) as u32
+        }
+
+        /// Moves the items of a LOC to their own maps, their positions keeping the order they had in the LOC.
+        fn move_items(loc_id: T::LocId, loc: LegalOfficerCaseV26Of<T>) {
+            for item in loc.metadata.iter() {
+                LocMetadataMap::<T>::insert(loc_id, item.name, item.clone());
+                Pallet::<T>::record_item_position(&loc_id, LocItemKey::Metadata(item.name));
+            }
+            for file in loc.files.iter() {
+                LocFilesMap::<T>::insert(loc_id, file.hash, file.clone());
+                Pallet::<T>::record_item_position(&loc_id, LocItemKey::File(file.hash));
+            }
+            for link in loc.links.iter() {
+                LocLinksMap::<T>::insert(loc_id, link.id, link.clone());
+                Pallet::<T>::record_item_position(&loc_id, LocItemKey::Link(link.id));
+            }
+            crate::LocMap::<T>::insert(loc_id, LegalOfficerCase {
+                owner: loc.owner,
+                requester: loc.requester,
+                metadata_count: loc.metadata.len() as u32,
+                files_count: loc.files.len() as u32,
+                closed: loc.closed,
+                loc_type: loc.loc_type,
+                links_count: loc.links.len() as u32,
+                void_info: loc.void_info,
+                replacer_of: loc.replacer_of,
+                collection_last_block_submission: loc.collection_last_block_submission,
+                collection_max_size: loc.collection_max_size,
+                collection_can_upload: loc.collection_can_upload,
+                seal: loc.seal,
+                sponsorship_id: loc.sponsorship_id,
+                value_fee: loc.value_fee,
+                legal_fee: loc.legal_fee,
+                collection_item_fee: loc.collection_item_fee,
+                tokens_record_fee: loc.tokens_record_fee,
+                imported: loc.imported,
+                created_on: loc.created_on,
+                closed_on: loc.closed_on,
+                voided_on: loc.voided_on,
+            });
+        }
+    }
+
+    impl<T: Config> SteppedMigration for MoveLocItems<T> {
+
+        const FROM_VERSION: u16 = 26;
+
+        const TO_VERSION: u16 = 27;
+
+        fn step(status: &mut MigrationStatus, meter: &mut WeightMeter) -> bool {
+            let read_weight = Self::read_weight();
+            let mut iter = match &status.cursor {
+                Some(cursor) => LocMap::<T>::iter_from(cursor.to_vec()),
+                None => LocMap::<T>::iter(),
+            };
+            loop {
+                if !meter.can_consume(read_weight) {
+                    return false;
+                }
+                meter.consume(read_weight);
+                match iter.next() {
+                    Some((loc_id, loc)) => {
+                        let move_weight = Self::move_weight(Self::items_of(&loc));
+                        if !meter.can_consume(move_weight) {
+                            return false;
+                        }
+                        meter.consume(move_weight);
+                        Self::move_items(loc_id, loc);
+                        status.cursor = Some(LocMap::<T>::hashed_key_for(loc_id).try_into()
+                            .expect("LOC storage keys fit into cursor"));
+                        status.processed += 1;
+                    },
+                    None => return true,
                 }
-            )
+            }
+        }
+
+        fn max_entry_weight() -> Weight {
+            let max_items = T::MaxLocMetadata::get()
+                .saturating_add(T::MaxLocFiles::get())
+                .saturating_add(T::MaxLocLinks::get());
+            Self::read_weight().saturating_add(Self::move_weight(max_items))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            let mut locs: u64 = 0;
+            let mut items: u64 = 0;
+            for (_, loc) in LocMap::<T>::iter() {
+                locs += 1;
+                items += (loc.metadata.len() + loc.files.len() + loc.links.len()) as u64;
+            }
+            frame_support::ensure!(locs == LocMap::<T>::iter_keys().count() as u64, "Some LOCs cannot be decoded");
+            Ok((locs, items).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            let (locs, items): (u64, u64) = Decode::decode(&mut &state[..])
+                .map_err(|_| "Invalid pre-upgrade state")?;
+            let mut migrated_locs: u64 = 0;
+            let mut counted_items: u64 = 0;
+            for (_, loc) in crate::LocMap::<T>::iter() {
+                migrated_locs += 1;
+                counted_items += (loc.metadata_count + loc.files_count + loc.links_count) as u64;
+            }
+            frame_support::ensure!(migrated_locs == crate::LocMap::<T>::iter_keys().count() as u64, "Some LOCs cannot be decoded");
+            frame_support::ensure!(migrated_locs == locs, "Some LOCs were not migrated");
+            frame_support::ensure!(counted_items == items, "LOC item counts do not match");
+            let moved_items = (LocMetadataMap::<T>::iter_values().count()
+                + LocFilesMap::<T>::iter_values().count()
+                + LocLinksMap::<T>::iter_values().count()) as u64;
+            frame_support::ensure!(moved_items == items, "Some LOC items were not moved");
+            Ok(())
         }
     }
 }
 
-fn do_storage_upgrade<T: Config, F>(expected_version: StorageVersion, target_version: StorageVersion, migration_name: &str, migration: F) -> Weight
-    where F: FnOnce() -> Weight {
-    let storage_version = PalletStorageVersion::<T>::get();
-    if storage_version == expected_version {
-        let weight = migration();
+/// The migrations of the pallet, in execution order. They must be executed before the pallet's `on_runtime_upgrade`
+/// (i.e. listed in the runtime's migrations), the latter then starting the stepped migration moving LOC items
+/// (see [`v27::MoveLocItems`]).
+pub type Migrations<P, T> = (
+    MigrateToFrameStorageVersion<T>,
+    v23::RemoveUselessMapsAddImported<P, T>,
+    v24::AddBlockNumbers<T>,
+    v25::IndexLinksToLoc<T>,
+    v26::IndexLocsByOwner<T>,
+);
+
+/// Replaces the legacy `PalletStorageVersion` value with FRAME's on-chain storage version.
+///
+/// Must be executed before any other migration of the pallet.
+pub struct MigrateToFrameStorageVersion<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToFrameStorageVersion<T> {
 
-        PalletStorageVersion::<T>::set(target_version);
-        log::info!("✅ {:?} migration successfully executed", migration_name);
-        weight
-    } else {
-        if storage_version != target_version {
-            log::warn!("❗ {:?} cannot run migration with storage version {:?} (expected {:?})", migration_name, storage_version, expected_version);
-        } else {
-            log::info!("❎ {:?} execution skipped, already at target version {:?}", migration_name, target_version);
+    fn on_runtime_upgrade() -> Weight {
+        match legacy::PalletStorageVersion::<T>::take() {
+            Some(legacy_index) => {
+                let version = legacy::to_storage_version(legacy_index);
+                version.put::<Pallet<T>>();
+                log::info!("✅ Legacy storage version index {} replaced by version {:?}", legacy_index, version);
+                T::DbWeight::get().reads_writes(1, 2)
+            },
+            None => {
+                log::info!("❎ No legacy storage version, migration skipped");
+                T::DbWeight::get().reads(1)
+            },
         }
-        T::DbWeight::get().reads(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        if legacy::PalletStorageVersion::<T>::exists() {
+            frame_support::ensure!(legacy::PalletStorageVersion::<T>::get().is_some(), "Legacy storage version cannot be decoded");
+        }
+        Ok(legacy::PalletStorageVersion::<T>::get().encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        let legacy_index: Option<u8> = Decode::decode(&mut &state[..])
+            .map_err(|_| "Invalid pre-upgrade state")?;
+        frame_support::ensure!(!legacy::PalletStorageVersion::<T>::exists(), "Legacy storage version was not removed");
+        if let Some(legacy_index) = legacy_index {
+            frame_support::ensure!(StorageVersion::get::<Pallet<T>>() == legacy::to_storage_version(legacy_index), "Unexpected storage version");
+        }
+        Ok(())
+    }
+}
+
+pub mod legacy {
+    use super::*;
+
+    /// The legacy storage version. It was stored as the SCALE encoding of a fieldless enum whose variants were
+    /// named `V1`, `V2...`, etc., i.e. the index of the variant.
+    #[frame_support::storage_alias]
+    pub type PalletStorageVersion<T: Config> = StorageValue<Pallet<T>, u8>;
+
+    /// The FRAME storage version matching a legacy version index i.e. the index of `V<n>...` becomes `n`.
+    pub fn to_storage_version(legacy_index: u8) -> StorageVersion {
+        StorageVersion::new(legacy_index as u16 + 1)
     }
 }
 
@@ -70,104 +638,95 @@ fn clear_storage<T: Config>(pallet_name: &str, storage_name: &str) -> Weight {
 	T::DbWeight::get().reads_writes(keys_removed + 1, keys_removed)
 }
 
-fn add_imported_flag<T: Config>() -> Weight {
-    let mut number_translated = 0;
-
-    LocMap::<T>::translate_values(|loc: LegalOfficerCaseV22Of<T>| {
-        let translated = LegalOfficerCase {
-            owner: loc.owner,
-            requester: loc.requester,
-            metadata: loc.metadata,
-            files: loc.files,
-            closed: loc.closed,
-            loc_type: loc.loc_type,
-            links: loc.links,
-            void_info: loc.void_info,
-            replacer_of: loc.replacer_of,
-            collection_last_block_submission: loc.collection_last_block_submission,
-            collection_max_size: loc.collection_max_size,
-            collection_can_upload: loc.collection_can_upload,
-            seal: loc.seal,
-            sponsorship_id: loc.sponsorship_id,
-            value_fee: loc.value_fee,
-            legal_fee: loc.value_fee,
-            collection_item_fee: loc.collection_item_fee,
-            tokens_record_fee: loc.tokens_record_fee,
-            imported: false,
-        };
-        number_translated += 1;
-        Some(translated)
-    });
-
-    CollectionItemsMap::<T>::translate_values(|loc: CollectionItemV22Of<T>| {
-        let translated = CollectionItem {
-            description: loc.description,
-            files: loc.files,
-            token: loc.token,
-            restricted_delivery: loc.restricted_delivery,
-            terms_and_conditions: loc.terms_and_conditions,
-            imported: false,
-        };
-        number_translated += 1;
-        Some(translated)
-    });
-
-    TokensRecordsMap::<T>::translate_values(|record: TokensRecordV22Of<T>| {
-        let translated = TokensRecord {
-            description: record.description,
-            files: record.files,
-            submitter: record.submitter,
-            imported: false,
-        };
-        number_translated += 1;
-        Some(translated)
-    });
-
-    VerifiedIssuersMap::<T>::translate_values(|issuer: VerifiedIssuerV22Of<T>| {
-        let translated = VerifiedIssuer {
-            identity_loc: issuer.identity_loc,
-            imported: false,
-        };
-        number_translated += 1;
-        Some(translated)
-    });
-
-    SponsorshipMap::<T>::translate_values(|sponsorship: SponsorshipV22Of<T>| {
-        let translated = Sponsorship {
-            sponsor: sponsorship.sponsor,
-            sponsored_account: sponsorship.sponsored_account,
-            legal_officer: sponsorship.legal_officer,
-            loc_id: sponsorship.loc_id,
-            imported: false,
-        };
-        number_translated += 1;
-        Some(translated)
-    });
-
-    T::DbWeight::get().reads_writes(number_translated, number_translated)
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LegalOfficerCaseV26<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance,
+    MaxLocMetadata: Get<u32>, MaxLocFiles: Get<u32>, MaxLocLinks: Get<u32>> {
+    pub owner: AccountId,
+    pub requester: Requester<AccountId, LocId, EthereumAddress>,
+    pub metadata: BoundedVec<MetadataItem<AccountId, EthereumAddress, Hash, BlockNumber>, MaxLocMetadata>,
+    pub files: BoundedVec<File<Hash, AccountId, EthereumAddress, BlockNumber>, MaxLocFiles>,
+    pub closed: bool,
+    pub loc_type: LocType,
+    pub links: BoundedVec<LocLink<LocId, Hash, AccountId, EthereumAddress, BlockNumber>, MaxLocLinks>,
+    pub void_info: Option<LocVoidInfo<LocId>>,
+    pub replacer_of: Option<LocId>,
+    pub collection_last_block_submission: Option<BlockNumber>,
+    pub collection_max_size: Option<CollectionSize>,
+    pub collection_can_upload: bool,
+    pub seal: Option<Hash>,
+    pub sponsorship_id: Option<SponsorshipId>,
+    pub value_fee: Balance,
+    pub legal_fee: Balance,
+    pub collection_item_fee: Balance,
+    pub tokens_record_fee: Balance,
+    pub imported: bool,
+    pub created_on: Option<BlockNumber>,
+    pub closed_on: Option<BlockNumber>,
+    pub voided_on: Option<BlockNumber>,
+}
+
+pub type LegalOfficerCaseV26Of<T> = LegalOfficerCaseV26<
+    <T as frame_system::Config>::AccountId,
+    <T as pallet::Config>::Hash,
+    <T as pallet::Config>::LocId,
+    BlockNumberFor<T>,
+    <T as pallet::Config>::EthereumAddress,
+    <T as pallet::Config>::SponsorshipId,
+    BalanceOf<T>,
+    <T as pallet::Config>::MaxLocMetadata,
+    <T as pallet::Config>::MaxLocFiles,
+    <T as pallet::Config>::MaxLocLinks,
+>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MetadataItemV23<AccountId, EthereumAddress, Hash> {
+    pub name: Hash,
+    pub value: Hash,
+    pub submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    pub acknowledged_by_owner: bool,
+    pub acknowledged_by_verified_issuer: bool,
+}
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LocLinkV23<LocId, Hash, AccountId, EthereumAddress> {
+    pub id: LocId,
+    pub nature: Hash,
+    pub submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    pub acknowledged_by_owner: bool,
+    pub acknowledged_by_verified_issuer: bool,
+}
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct FileV23<Hash, AccountId, EthereumAddress> {
+    pub hash: Hash,
+    pub nature: Hash,
+    pub submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    pub size: u32,
+    pub acknowledged_by_owner: bool,
+    pub acknowledged_by_verified_issuer: bool,
 }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct LegalOfficerCaseV22<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance,
     MaxLocMetadata: Get<u32>, MaxLocFiles: Get<u32>, MaxLocLinks: Get<u32>> {
-    owner: AccountId,
-    requester: Requester<AccountId, LocId, EthereumAddress>,
-    metadata: BoundedVec<MetadataItem<AccountId, EthereumAddress, Hash>, MaxLocMetadata>,
-    files: BoundedVec<File<Hash, AccountId, EthereumAddress>, MaxLocFiles>,
-    closed: bool,
-    loc_type: LocType,
-    links: BoundedVec<LocLink<LocId, Hash, AccountId, EthereumAddress>, MaxLocLinks>,
-    void_info: Option<LocVoidInfo<LocId>>,
-    replacer_of: Option<LocId>,
-    collection_last_block_submission: Option<BlockNumber>,
-    collection_max_size: Option<CollectionSize>,
-    collection_can_upload: bool,
-    seal: Option<Hash>,
-    sponsorship_id: Option<SponsorshipId>,
-    value_fee: Balance,
-    legal_fee: Balance,
-    collection_item_fee: Balance,
-    tokens_record_fee: Balance,
+    pub owner: AccountId,
+    pub requester: Requester<AccountId, LocId, EthereumAddress>,
+    pub metadata: BoundedVec<MetadataItemV23<AccountId, EthereumAddress, Hash>, MaxLocMetadata>,
+    pub files: BoundedVec<FileV23<Hash, AccountId, EthereumAddress>, MaxLocFiles>,
+    pub closed: bool,
+    pub loc_type: LocType,
+    pub links: BoundedVec<LocLinkV23<LocId, Hash, AccountId, EthereumAddress>, MaxLocLinks>,
+    pub void_info: Option<LocVoidInfo<LocId>>,
+    pub replacer_of: Option<LocId>,
+    pub collection_last_block_submission: Option<BlockNumber>,
+    pub collection_max_size: Option<CollectionSize>,
+    pub collection_can_upload: bool,
+    pub seal: Option<Hash>,
+    pub sponsorship_id: Option<SponsorshipId>,
+    pub value_fee: Balance,
+    pub legal_fee: Balance,
+    pub collection_item_fee: Balance,
+    pub tokens_record_fee: Balance,
 }
 
 pub type LegalOfficerCaseV22Of<T> = LegalOfficerCaseV22<
@@ -183,6 +742,43 @@ pub type LegalOfficerCaseV22Of<T> = LegalOfficerCaseV22<
     <T as pallet::Config>::MaxLocLinks,
 >;
 
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LegalOfficerCaseV23<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId, Balance,
+    MaxLocMetadata: Get<u32>, MaxLocFiles: Get<u32>, MaxLocLinks: Get<u32>> {
+    pub owner: AccountId,
+    pub requester: Requester<AccountId, LocId, EthereumAddress>,
+    pub metadata: BoundedVec<MetadataItemV23<AccountId, EthereumAddress, Hash>, MaxLocMetadata>,
+    pub files: BoundedVec<FileV23<Hash, AccountId, EthereumAddress>, MaxLocFiles>,
+    pub closed: bool,
+    pub loc_type: LocType,
+    pub links: BoundedVec<LocLinkV23<LocId, Hash, AccountId, EthereumAddress>, MaxLocLinks>,
+    pub void_info: Option<LocVoidInfo<LocId>>,
+    pub replacer_of: Option<LocId>,
+    pub collection_last_block_submission: Option<BlockNumber>,
+    pub collection_max_size: Option<CollectionSize>,
+    pub collection_can_upload: bool,
+    pub seal: Option<Hash>,
+    pub sponsorship_id: Option<SponsorshipId>,
+    pub value_fee: Balance,
+    pub legal_fee: Balance,
+    pub collection_item_fee: Balance,
+    pub tokens_record_fee: Balance,
+    pub imported: bool,
+}
+
+pub type LegalOfficerCaseV23Of<T> = LegalOfficerCaseV23<
+    <T as frame_system::Config>::AccountId,
+    <T as pallet::Config>::Hash,
+    <T as pallet::Config>::LocId,
+    BlockNumberFor<T>,
+    <T as pallet::Config>::EthereumAddress,
+    <T as pallet::Config>::SponsorshipId,
+    BalanceOf<T>,
+    <T as pallet::Config>::MaxLocMetadata,
+    <T as pallet::Config>::MaxLocFiles,
+    <T as pallet::Config>::MaxLocLinks,
+>;
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionItemV22<Hash, TokenIssuance, BoundedCollectionItemFilesList, BoundedCollectionItemTCList> {
     description: Hash,
@@ -1,12 +1,15 @@
-use crate::{self as pallet_loc, NegativeImbalanceOf, RequesterOf, Hasher};
+use crate::{self as pallet_loc, NegativeImbalanceOf, RequesterOf, Hasher, StoragePaymentMode, LinkRule, LinkRules, LocType, NativeTokenKind, NativeTokenMinter, TokenOwnershipOracle};
 use logion_shared::{DistributionKey, IsLegalOfficer};
 use sp_core::hash::H256;
 use frame_benchmarking::account;
 use frame_support::{derive_impl, construct_runtime, parameter_types, traits::{EnsureOrigin, Currency}};
 use sp_io::hashing::sha2_256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, Percent, BuildStorage};
+use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, DispatchError, DispatchResult, Percent, BuildStorage};
 use frame_system as system;
 use sp_core::H160;
+use frame_support::weights::Weight;
+use std::cell::RefCell;
+use crate::migrations::{SteppedMigration, v27::MoveLocItems};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -126,15 +129,20 @@ impl IsLegalOfficer<<Test as system::Config>::AccountId, RuntimeOrigin> for LoAu
     }
 }
 
-pub const MAX_LOC_ITEMS: u8 = 3;
+pub const MAX_LOC_ITEMS: u8 = 50;
 
 parameter_types! {
 	pub const MaxAccountLocs: u32 = 100u32;
 	#[derive(Debug, Eq, Clone, PartialEq, TypeInfo)]
 	pub const MaxLocItems: u32 = MAX_LOC_ITEMS as u32;
+	#[derive(Debug, Eq, Clone, PartialEq, TypeInfo)]
+	pub const MaxLocCoOwners: u32 = 2;
     pub const MaxCollectionItemFiles: u32 = 2;
     pub const MaxCollectionItemTCs: u32 = 2;
+    pub const MaxCollectionItemsPerBatch: u32 = 10;
+    pub const MaxCollectionItemVersions: u32 = 2;
     pub const MaxTokensRecordFiles: u32 = 10;
+    pub const MaxTokensRecordTargets: u32 = 3;
 }
 
 // Fake accounts used to simulate reward beneficiaries balances
@@ -181,24 +189,54 @@ parameter_types! {
         logion_treasury_percent: Percent::from_percent(100),
         loc_owner_percent: Percent::from_percent(0),
     };
-    pub const RecurentFeeDistributionKey: DistributionKey = DistributionKey {
+    pub const CollectionItemFeeDistributionKey: DistributionKey = DistributionKey {
         legal_officers_percent: Percent::from_percent(0),
         community_treasury_percent: Percent::from_percent(0),
         logion_treasury_percent: Percent::from_percent(95),
         loc_owner_percent: Percent::from_percent(5),
     };
-    pub const IdentityLocLegalFeeDistributionKey: DistributionKey = DistributionKey {
+    pub const TokensRecordFeeDistributionKey: DistributionKey = DistributionKey {
         legal_officers_percent: Percent::from_percent(0),
         community_treasury_percent: Percent::from_percent(0),
-        logion_treasury_percent: Percent::from_percent(100),
+        logion_treasury_percent: Percent::from_percent(90),
+        loc_owner_percent: Percent::from_percent(10),
+    };
+    pub const IdentityLocLegalFeeDistributionKey: DistributionKey = DistributionKey {
+        legal_officers_percent: Percent::from_percent(0),
+        community_treasury_percent: Percent::from_percent(100),
+        logion_treasury_percent: Percent::from_percent(0),
         loc_owner_percent: Percent::from_percent(0),
     };
-    pub const OtherLocLegalFeeDistributionKey: DistributionKey = DistributionKey {
+    pub const TransactionLocLegalFeeDistributionKey: DistributionKey = DistributionKey {
         legal_officers_percent: Percent::from_percent(0),
         community_treasury_percent: Percent::from_percent(0),
         logion_treasury_percent: Percent::from_percent(0),
         loc_owner_percent: Percent::from_percent(100),
     };
+    pub const CollectionLocLegalFeeDistributionKey: DistributionKey = DistributionKey {
+        legal_officers_percent: Percent::from_percent(0),
+        community_treasury_percent: Percent::from_percent(0),
+        logion_treasury_percent: Percent::from_percent(10),
+        loc_owner_percent: Percent::from_percent(90),
+    };
+}
+
+parameter_types! {
+    pub storage MaxReportedLinkingLocs: u32 = 10;
+}
+
+parameter_types! {
+    pub storage FileStoragePaymentMode: StoragePaymentMode = StoragePaymentMode::Fee;
+    pub const DeletedFileStorageRefund: Percent = Percent::from_percent(50);
+}
+
+parameter_types! {
+    pub storage RequesterCanRevokeCollectionItems: bool = true;
+    pub storage CollectionItemRemovalGracePeriod: u64 = 10;
+}
+
+parameter_types! {
+    pub MaxMigrationWeightPerBlock: Weight = MoveLocItems::<Test>::max_entry_weight().saturating_mul(2);
 }
 
 pub struct SHA256;
@@ -209,6 +247,60 @@ impl Hasher<H256> for SHA256 {
     }
 }
 
+pub struct LinkRulesMock;
+impl LinkRules<H256> for LinkRulesMock {
+    fn rules(nature: &H256) -> Vec<LinkRule> {
+        if *nature == SHA256::hash(&"closed-target".as_bytes().to_vec()) {
+            Vec::from([ LinkRule::TargetClosed ])
+        } else if *nature == SHA256::hash(&"non-void-target".as_bytes().to_vec()) {
+            Vec::from([ LinkRule::TargetNotVoid ])
+        } else if *nature == SHA256::hash(&"same-owner-target".as_bytes().to_vec()) {
+            Vec::from([ LinkRule::SameOwner ])
+        } else if *nature == SHA256::hash(&"identity-target".as_bytes().to_vec()) {
+            Vec::from([ LinkRule::TargetType(LocType::Identity) ])
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Account `n` holds the token whose ID is `n` in big-endian.
+pub struct TokenOwnershipOracleMock;
+impl TokenOwnershipOracle<AccountId, H256, TokenIssuance> for TokenOwnershipOracleMock {
+    fn owns_token(account: &AccountId, _token_type: &H256, token_id: &H256, _token_issuance: &TokenIssuance) -> bool {
+        *token_id == owned_token_id(*account)
+    }
+}
+
+pub fn owned_token_id(account: AccountId) -> H256 {
+    H256::from_low_u64_be(account)
+}
+
+thread_local! {
+    static MINTED_NATIVE_TOKENS: RefCell<Vec<(NativeTokenKind, H256, AccountId, TokenIssuance)>> = RefCell::new(Vec::new());
+}
+
+/// Records minted tokens, minting fails for tokens with a supply greater than 1000.
+pub struct NativeTokenMinterMock;
+impl NativeTokenMinter<AccountId, H256, TokenIssuance> for NativeTokenMinterMock {
+    fn mint(kind: NativeTokenKind, token_id: &H256, owner: &AccountId, issuance: TokenIssuance) -> DispatchResult {
+        if issuance > 1000 {
+            return Err(DispatchError::Other("Supply too high"));
+        }
+        MINTED_NATIVE_TOKENS.with(|tokens| tokens.borrow_mut().push((kind, *token_id, *owner, issuance)));
+        Ok(())
+    }
+
+    fn holds(kind: NativeTokenKind, token_id: &H256, account: &AccountId) -> bool {
+        MINTED_NATIVE_TOKENS.with(|tokens| tokens.borrow().iter()
+            .any(|(minted_kind, minted_id, owner, _)| *minted_kind == kind && minted_id == token_id && owner == account))
+    }
+}
+
+pub fn minted_native_tokens() -> Vec<(NativeTokenKind, H256, AccountId, TokenIssuance)> {
+    MINTED_NATIVE_TOKENS.with(|tokens| tokens.borrow().clone())
+}
+
 impl pallet_loc::Config for Test {
     type LocId = LocId;
     type RuntimeEvent = RuntimeEvent;
@@ -223,24 +315,40 @@ impl pallet_loc::Config for Test {
 	type MaxLocLinks = MaxLocItems;
 	type MaxCollectionItemFiles = MaxCollectionItemFiles;
 	type MaxCollectionItemTCs = MaxCollectionItemTCs;
+	type MaxCollectionItemsPerBatch = MaxCollectionItemsPerBatch;
+	type MaxCollectionItemVersions = MaxCollectionItemVersions;
     type MaxTokensRecordFiles = MaxTokensRecordFiles;
+    type MaxTokensRecordTargets = MaxTokensRecordTargets;
+    type MaxReportedLinkingLocs = MaxReportedLinkingLocs;
     type WeightInfo = SubstrateWeight<Test>;
     type Currency = Balances;
     type FileStorageByteFee = FileStorageByteFee;
     type FileStorageEntryFee = FileStorageEntryFee;
     type RewardDistributor = RewardDistributor;
     type FileStorageFeeDistributionKey = FileStorageFeeDistributionKey;
+    type FileStoragePaymentMode = FileStoragePaymentMode;
+    type DeletedFileStorageRefund = DeletedFileStorageRefund;
+    type StorageFeeRefundAccount = LogionTreasuryAccountId;
     type EthereumAddress = EthereumAddress;
     type SponsorshipId = SponsorshipId;
     type CertificateFee = CertificateFee;
     type CertificateFeeDistributionKey = CertificateFeeDistributionKey;
     type TokenIssuance = TokenIssuance;
     type ValueFeeDistributionKey = ValueFeeDistributionKey;
-    type CollectionItemFeeDistributionKey = RecurentFeeDistributionKey;
-    type TokensRecordFeeDistributionKey = RecurentFeeDistributionKey;
+    type CollectionItemFeeDistributionKey = CollectionItemFeeDistributionKey;
+    type TokensRecordFeeDistributionKey = TokensRecordFeeDistributionKey;
     type IdentityLocLegalFeeDistributionKey = IdentityLocLegalFeeDistributionKey;
-    type TransactionLocLegalFeeDistributionKey = OtherLocLegalFeeDistributionKey;
-    type CollectionLocLegalFeeDistributionKey = OtherLocLegalFeeDistributionKey;
+    type TransactionLocLegalFeeDistributionKey = TransactionLocLegalFeeDistributionKey;
+    type CollectionLocLegalFeeDistributionKey = CollectionLocLegalFeeDistributionKey;
+    type LinkRules = LinkRulesMock;
+    type MaxLocCoOwners = MaxLocCoOwners;
+    type TransferOrigin = system::EnsureRoot<AccountId>;
+    type RequesterCanRevokeCollectionItems = RequesterCanRevokeCollectionItems;
+    type CollectionItemRemovalGracePeriod = CollectionItemRemovalGracePeriod;
+    type SteppedMigration = MoveLocItems<Test>;
+    type MaxMigrationWeightPerBlock = MaxMigrationWeightPerBlock;
+    type TokenOwnershipOracle = TokenOwnershipOracleMock;
+    type NativeTokenMinter = NativeTokenMinterMock;
 	#[cfg(feature = "runtime-benchmarks")]
 	type LocIdFactory = ();
 	#[cfg(feature = "runtime-benchmarks")]
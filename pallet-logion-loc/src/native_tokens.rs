@@ -0,0 +1,91 @@
+//! Implementations of [`NativeTokenMinter`] relying on FRAME's token traits, typically implemented
+//! by `pallet-nfts` and `pallet-assets`.
+
+use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Get, tokens::{fungibles, nonfungibles_v2}};
+use sp_runtime::{DispatchError, traits::{One, Zero}};
+use sp_std::marker::PhantomData;
+
+use crate::{NativeTokenKind, NativeTokenMinter};
+
+/// Mints non-fungible tokens as items of the NFT collection given by `Collection`, the token ID
+/// being the item ID. Fungible tokens are not supported.
+pub struct NonFungibleTokenMinter<Nfts, Collection, ItemConfig>(PhantomData<(Nfts, Collection, ItemConfig)>);
+
+impl<AccountId, Hash, TokenIssuance, Nfts, Collection, ItemConfig> NativeTokenMinter<AccountId, Hash, TokenIssuance>
+for NonFungibleTokenMinter<Nfts, Collection, ItemConfig>
+where
+    AccountId: PartialEq,
+    Nfts: nonfungibles_v2::Mutate<AccountId, ItemConfig, ItemId = Hash>,
+    Collection: Get<Nfts::CollectionId>,
+    ItemConfig: Default,
+{
+    fn mint(kind: NativeTokenKind, token_id: &Hash, owner: &AccountId, _issuance: TokenIssuance) -> DispatchResult {
+        match kind {
+            NativeTokenKind::NonFungible => Nfts::mint_into(&Collection::get(), token_id, owner, &ItemConfig::default(), true),
+            NativeTokenKind::Fungible => Err(DispatchError::Other("Fungible native tokens not supported")),
+        }
+    }
+
+    fn holds(kind: NativeTokenKind, token_id: &Hash, account: &AccountId) -> bool {
+        match kind {
+            NativeTokenKind::NonFungible => Nfts::owner(&Collection::get(), token_id).as_ref() == Some(account),
+            NativeTokenKind::Fungible => false,
+        }
+    }
+}
+
+/// Mints fungible tokens as new assets, the asset ID being the token ID. The owner of the token
+/// becomes the admin of the asset and receives the whole issuance. Non-fungible tokens are not supported.
+pub struct FungibleTokenMinter<Assets>(PhantomData<Assets>);
+
+impl<AccountId, Hash, TokenIssuance, Assets> NativeTokenMinter<AccountId, Hash, TokenIssuance>
+for FungibleTokenMinter<Assets>
+where
+    AccountId: Clone,
+    Hash: Clone,
+    Assets: fungibles::Create<AccountId, AssetId = Hash> + fungibles::Mutate<AccountId>,
+    TokenIssuance: Into<<Assets as fungibles::Inspect<AccountId>>::Balance>,
+{
+    fn mint(kind: NativeTokenKind, token_id: &Hash, owner: &AccountId, issuance: TokenIssuance) -> DispatchResult {
+        match kind {
+            NativeTokenKind::Fungible => {
+                Assets::create(token_id.clone(), owner.clone(), false, One::one())?;
+                Assets::mint_into(token_id.clone(), owner, issuance.into())?;
+                Ok(())
+            },
+            NativeTokenKind::NonFungible => Err(DispatchError::Other("Non-fungible native tokens not supported")),
+        }
+    }
+
+    fn holds(kind: NativeTokenKind, token_id: &Hash, account: &AccountId) -> bool {
+        match kind {
+            NativeTokenKind::Fungible => !Assets::balance(token_id.clone(), account).is_zero(),
+            NativeTokenKind::NonFungible => false,
+        }
+    }
+}
+
+/// Delegates minting to `NonFungible` or `Fungible` given the kind of token.
+pub struct NativeTokenMinters<NonFungible, Fungible>(PhantomData<(NonFungible, Fungible)>);
+
+impl<AccountId, Hash, TokenIssuance, NonFungible, Fungible> NativeTokenMinter<AccountId, Hash, TokenIssuance>
+for NativeTokenMinters<NonFungible, Fungible>
+where
+    NonFungible: NativeTokenMinter<AccountId, Hash, TokenIssuance>,
+    Fungible: NativeTokenMinter<AccountId, Hash, TokenIssuance>,
+{
+    fn mint(kind: NativeTokenKind, token_id: &Hash, owner: &AccountId, issuance: TokenIssuance) -> DispatchResult {
+        match kind {
+            NativeTokenKind::NonFungible => NonFungible::mint(kind, token_id, owner, issuance),
+            NativeTokenKind::Fungible => Fungible::mint(kind, token_id, owner, issuance),
+        }
+    }
+
+    fn holds(kind: NativeTokenKind, token_id: &Hash, account: &AccountId) -> bool {
+        match kind {
+            NativeTokenKind::NonFungible => NonFungible::holds(kind, token_id, account),
+            NativeTokenKind::Fungible => Fungible::holds(kind, token_id, account),
+        }
+    }
+}
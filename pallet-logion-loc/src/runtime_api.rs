@@ -4,7 +4,8 @@
 
 use sp_api;
 use codec::Codec;
-use sp_runtime::traits::MaybeDisplay;
+use sp_runtime::{traits::MaybeDisplay, DispatchError};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 
@@ -17,4 +18,36 @@ sp_api::decl_runtime_apis! {
         /// Query expected item legal fees for adding an item with given type
         fn query_certificate_fee(token_issuance: TokenIssuance) -> Balance;
     }
-}
\ No newline at end of file
+
+    pub trait StorageDepositsApi<LocId, AccountId, Balance>
+    where LocId: Codec, AccountId: Codec, Balance: Codec + MaybeDisplay
+    {
+        /// Query the storage deposits held for given LOC, by depositor, fails during a storage migration
+        fn query_storage_deposits(loc_id: LocId) -> Result<Vec<(AccountId, Balance)>, DispatchError>;
+    }
+
+    pub trait LinksApi<LocId>
+    where LocId: Codec
+    {
+        /// Query the LOCs having a link to given LOC, fails during a storage migration
+        fn query_locs_linking_to(loc_id: LocId) -> Result<Vec<LocId>, DispatchError>;
+    }
+
+    pub trait TokenOwnershipApi<LocId, CollectionItemId, TokensRecordId, Hash, AccountId>
+    where LocId: Codec, CollectionItemId: Codec, TokensRecordId: Codec, Hash: Codec, AccountId: Codec
+    {
+        /// Query if given account may claim the delivery of given file of a restricted delivery collection item, fails during a storage migration
+        fn query_restricted_delivery_allowed(collection_loc_id: LocId, item_id: CollectionItemId, file_hash: Hash, account: AccountId) -> Result<bool, DispatchError>;
+
+        /// Query if given account, as the holder of given item's token, may access the files of given tokens record, fails during a storage migration
+        fn query_tokens_record_access_allowed(collection_loc_id: LocId, record_id: TokensRecordId, item_id: CollectionItemId, account: AccountId) -> Result<bool, DispatchError>;
+    }
+
+    pub trait TokensRecordsApi<LocId, CollectionItemId, TokensRecordId>
+    where LocId: Codec, CollectionItemId: Codec, TokensRecordId: Codec
+    {
+        /// Query the tokens records targeting given collection item, records applying to the whole collection
+        /// being excluded. Fails during a storage migration
+        fn query_tokens_records_of_item(collection_loc_id: LocId, item_id: CollectionItemId) -> Result<Vec<TokensRecordId>, DispatchError>;
+    }
+}
@@ -1,7 +1,8 @@
+use codec::Encode;
 use core::str::FromStr;
 use frame_support::{assert_err, assert_ok};
 use frame_support::dispatch::DispatchResultWithPostInfo;
-use frame_support::traits::Len;
+use frame_support::traits::{Hooks, Len};
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use sp_core::{H160, H256};
 use sp_core::bounded::BoundedVec;
@@ -9,9 +10,11 @@ use sp_runtime::DispatchError::BadOrigin;
 use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::traits::Hash;
 
-use logion_shared::{Beneficiary, LocQuery, LocValidity};
+use logion_shared::{Beneficiary, LocOwnership, LocQuery, LocValidity};
 
-use crate::{CollectionItem, CollectionItemFile, CollectionItemToken, Config, Error, fees::*, File, FileParams, Hasher, Items, ItemsOf, ItemsParams, ItemsParamsOf, LegalOfficerCase, LocLink, LocLinkParams, LocType, LocVoidInfo, MetadataItem, MetadataItemParams, mock::*, OtherAccountId, Requester::{Account, OtherAccount}, Requester, RequesterOf, Sponsorship, SupportedAccountId, TermsAndConditionsElement, TermsAndConditionsElementOf, TokensRecord, TokensRecordFile, TokensRecordFileOf, VerifiedIssuer};
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+use crate::migrations::{legacy, LegalOfficerCaseV23, LegalOfficerCaseV23Of, LegalOfficerCaseV26, LegalOfficerCaseV26Of, LocLinkV23, MigrateToFrameStorageVersion, v23, v24, v25, v26, v27};
+use crate::{CollectionItem, CollectionItemFile, CollectionItemParams, CollectionItemParamsOf, CollectionItemRevocation, CollectionItemVersion, CollectionItemToken, Config, Error, FeeKind, FeePayer, fees::*, File, FileParams, Hasher, Items, ItemsOf, ItemsParams, ItemsParamsOf, LegalOfficerCase, LocItemKey, LocLink, LocLinkParams, LocType, LocVoidInfo, MetadataItem, MetadataItemParams, mock::*, NativeToken, NativeTokenKind, OtherAccountId, OwnerAction, Requester::{Account, OtherAccount}, Requester, RequesterOf, Sponsorship, StoragePaymentMode, SupportedAccountId, TermsAndConditionsElement, TermsAndConditionsElementOf, TokensRecord, TokensRecordFile, TokensRecordFileOf, TokensRecordRevocation, VerifiedIssuer};
 
 const LOC_ID: u32 = 0;
 const OTHER_LOC_ID: u32 = 1;
@@ -42,11 +45,11 @@ fn it_creates_loc_with_default_legal_fee() {
         assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
             owner: legal_officer_id(1),
             requester: LOC_REQUESTER,
-            metadata: BoundedVec::new(),
-            files: BoundedVec::new(),
+            metadata_count: 0,
+            files_count: 0,
             closed: false,
             loc_type: LocType::Transaction,
-            links: BoundedVec::new(),
+            links_count: 0,
             void_info: None,
             replacer_of: None,
             collection_last_block_submission: None,
@@ -59,6 +62,9 @@ fn it_creates_loc_with_default_legal_fee() {
             collection_item_fee: 0,
             tokens_record_fee: 0,
             imported: false,
+            created_on: Some(1),
+            closed_on: None,
+            voided_on: None,
         }));
 
         let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(legal_officer_id(1)));
@@ -91,11 +97,11 @@ fn it_creates_loc_with_custom_legal_fee() {
         assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
             owner: legal_officer_id(1),
             requester: LOC_REQUESTER,
-            metadata: BoundedVec::new(),
-            files: BoundedVec::new(),
+            metadata_count: 0,
+            files_count: 0,
             closed: false,
             loc_type: LocType::Transaction,
-            links: BoundedVec::new(),
+            links_count: 0,
             void_info: None,
             replacer_of: None,
             collection_last_block_submission: None,
@@ -108,6 +114,9 @@ fn it_creates_loc_with_custom_legal_fee() {
             collection_item_fee: 0,
             tokens_record_fee: 0,
             imported: false,
+            created_on: Some(1),
+            closed_on: None,
+            voided_on: None,
         }));
 
         let fees = Fees::only_legal(custom_legal_fee, Beneficiary::LegalOfficer(legal_officer_id(1)));
@@ -228,8 +237,7 @@ fn it_adds_metadata_when_caller_and_submitter_is_owner() {
             submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
         };
         assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -237,16 +245,23 @@ fn sha256(data: &Vec<u8>) -> H256 {
     <SHA256 as Hasher<H256>>::hash(data)
 }
 
-fn expected_metadata(metadata: MetadataItemParams<AccountId, EthereumAddress, crate::mock::Hash>, acknowledged_by_owner: bool, acknowledged_by_verified_issuer: bool) -> MetadataItem<AccountId, EthereumAddress, crate::mock::Hash> {
+fn expected_metadata(metadata: MetadataItemParams<AccountId, EthereumAddress, crate::mock::Hash>, acknowledged_by_owner: bool, acknowledged_by_verified_issuer: bool) -> MetadataItem<AccountId, EthereumAddress, crate::mock::Hash, BlockNumberFor<Test>> {
     return MetadataItem {
         name: metadata.name,
         value: metadata.value,
         submitter: metadata.submitter,
         acknowledged_by_owner,
         acknowledged_by_verified_issuer,
+        submitted_on: Some(System::block_number()),
+        acknowledged_by_owner_on: acknowledged_on(acknowledged_by_owner),
+        acknowledged_by_verified_issuer_on: acknowledged_on(acknowledged_by_verified_issuer),
     };
 }
 
+fn acknowledged_on(acknowledged: bool) -> Option<BlockNumberFor<Test>> {
+    if acknowledged { Some(System::block_number()) } else { None }
+}
+
 #[test]
 fn it_adds_metadata_when_caller_is_requester_and_submitter_is_requester() {
     new_test_ext().execute_with(|| {
@@ -258,8 +273,7 @@ fn it_adds_metadata_when_caller_is_requester_and_submitter_is_requester() {
             submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
         };
         assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -274,8 +288,8 @@ fn it_adds_metadata_when_caller_is_requester() {
             submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
         };
         assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataAdded(LOC_ID, metadata.name, metadata.submitter)));
     });
 }
 
@@ -284,8 +298,40 @@ fn it_acknowledges_metadata_as_owner() {
     new_test_ext().execute_with(|| {
         let metadata = create_loc_with_metadata_from_requester();
         assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::Metadata(metadata.name), legal_officer_id(1))));
+    });
+}
+
+#[test]
+fn it_records_item_submission_and_acknowledgement_blocks() {
+    new_test_ext().execute_with(|| {
+        let metadata = create_loc_with_metadata_from_requester();
+        System::set_block_number(2);
+
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name.clone()));
+
+        let item = LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap();
+        assert_eq!(item.submitted_on, Some(1));
+        assert_eq!(item.acknowledged_by_owner_on, Some(2));
+        assert_eq!(item.acknowledged_by_verified_issuer_on, None);
+    });
+}
+
+#[test]
+fn it_records_loc_lifecycle_blocks() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        System::set_block_number(3);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        System::set_block_number(5);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        let summary = LogionLoc::get_loc(&LOC_ID).unwrap().unwrap();
+        assert_eq!(summary.created_on, Some(1));
+        assert_eq!(summary.closed_on, Some(3));
+        assert_eq!(summary.voided_on, Some(5));
     });
 }
 
@@ -304,8 +350,7 @@ fn it_acknowledges_metadata_as_verified_issuer() {
 
         assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, metadata.name.clone()));
 
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, ACKNOWLEDGED));
     });
 }
 
@@ -326,7 +371,7 @@ fn it_fails_to_close_with_metadata_unacknowledged_by_verified_issuer() {
 
         let loc = LogionLoc::loc(LOC_ID).unwrap();
         assert!(!loc.closed);
-        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -335,8 +380,7 @@ fn it_fails_to_acknowledge_requester_metadata_as_issuer() {
     new_test_ext().execute_with(|| {
         let metadata = create_loc_with_metadata_from_requester();
         assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, metadata.name.clone()), Error::<Test>::Unauthorized);
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -379,8 +423,7 @@ fn it_closes_loc_and_acknowledges_metadata() {
     new_test_ext().execute_with(|| {
         let metadata = create_loc_with_metadata_from_requester();
         assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -402,8 +445,7 @@ fn create_loc_with_metadata_from_requester() -> MetadataItemParams<AccountId, Et
         submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
     };
     assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
-    let loc = LogionLoc::loc(LOC_ID).unwrap();
-    assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     metadata
 }
 
@@ -467,15 +509,14 @@ fn it_adds_file_when_caller_owner_and_submitter_is_owner() {
         };
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
         assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
 
         let fees = Fees::only_storage(1, file.size);
         fees.assert_balances_events(snapshot);
     });
 }
 
-fn expected_file(file: &FileParams<H256, AccountId, EthereumAddress>, acknowledged_by_owner: bool, acknowledged_by_verified_issuer: bool) -> File<H256, AccountId, EthereumAddress> {
+fn expected_file(file: &FileParams<H256, AccountId, EthereumAddress>, acknowledged_by_owner: bool, acknowledged_by_verified_issuer: bool) -> File<H256, AccountId, EthereumAddress, BlockNumberFor<Test>> {
     return File {
         hash: file.hash,
         nature: file.nature.clone(),
@@ -483,6 +524,9 @@ fn expected_file(file: &FileParams<H256, AccountId, EthereumAddress>, acknowledg
         size: file.size,
         acknowledged_by_owner,
         acknowledged_by_verified_issuer,
+        submitted_on: Some(System::block_number()),
+        acknowledged_by_owner_on: acknowledged_on(acknowledged_by_owner),
+        acknowledged_by_verified_issuer_on: acknowledged_on(acknowledged_by_verified_issuer),
     }
 }
 
@@ -499,8 +543,7 @@ fn it_adds_file_when_caller_is_requester_and_submitter_is_requester() {
         };
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
         assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
         let fees = Fees::only_storage(1, file.size);
         fees.assert_balances_events(snapshot);
     });
@@ -519,10 +562,10 @@ fn it_adds_file_when_caller_is_requester() {
         };
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
         assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
         let fees = Fees::only_storage(1, file.size);
         fees.assert_balances_events(snapshot);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileAdded(LOC_ID, file.hash, file.submitter)));
     });
 }
 
@@ -531,8 +574,7 @@ fn it_acknowledges_file() {
     new_test_ext().execute_with(|| {
         let file = create_loc_with_file_from_requester();
         assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.hash.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -552,8 +594,8 @@ fn it_acknowledges_file_as_verified_issuer() {
 
         assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, file.hash.clone()));
 
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::File(file.hash), ISSUER_ID1)));
     });
 }
 
@@ -575,7 +617,7 @@ fn it_fails_to_close_with_file_unacknowledged_by_verified_issuer() {
 
         let loc = LogionLoc::loc(LOC_ID).unwrap();
         assert!(!loc.closed);
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -584,8 +626,7 @@ fn it_fails_to_acknowledge_requester_file_as_issuer() {
     new_test_ext().execute_with(|| {
         let file = create_loc_with_file_from_requester();
         assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, file.hash.clone()), Error::<Test>::Unauthorized);
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 #[test]
@@ -627,8 +668,7 @@ fn it_closes_loc_and_acknowledges_file() {
     new_test_ext().execute_with(|| {
         let file = create_loc_with_file_from_requester();
         assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
@@ -651,8 +691,7 @@ fn create_loc_with_file_from_requester() -> FileParams<H256, AccountId, Ethereum
         size: FILE_SIZE,
     };
     assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
-    let loc = LogionLoc::loc(LOC_ID).unwrap();
-    assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     file
 }
 
@@ -689,2584 +728,5690 @@ fn it_fails_adding_file_when_insufficient_funds() {
     });
 }
 
-fn check_no_fees(previous_balances: BalancesSnapshot) {
-    let current_balances = BalancesSnapshot::take(
-        previous_balances.payer_account,
-        previous_balances.legal_officers.iter().map(|legal_officer|{ legal_officer.0 }).collect()
-    );
-    let balances_delta = current_balances.delta_since(&previous_balances);
-
-    assert_eq!(balances_delta.total_credited(), 0);
-    assert_eq!(balances_delta.total_debited(), 0);
-}
-
 #[test]
-fn it_fails_adding_file_when_closed() {
+fn it_adds_file_with_fees_paid_by_signer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
         let file = FileParams {
             hash: sha256(&"test".as_bytes().to_vec()),
             nature: sha256(&"test-file-nature".as_bytes().to_vec()),
             submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
             size: FILE_SIZE,
         };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::CannotMutate);
+        let requester_snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_files(vec![file.clone()]), FeePayer::Signer));
+
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), requester_snapshot.payer);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeWithdrawn(legal_officer_id(1), Fees::storage_fees(1, FILE_SIZE))));
     });
 }
 
 #[test]
-fn it_adds_link_with_owner() {
+fn it_fails_adding_file_with_fees_paid_by_sponsor_if_not_sponsored() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
             submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
         };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0].id, link.id);
-        assert_eq!(loc.links[0].nature, link.nature);
-        assert_eq!(loc.links[0].submitter, link.submitter);
-        assert_eq!(loc.links[0].acknowledged_by_owner, true);
-        assert_eq!(loc.links[0].acknowledged_by_verified_issuer, false);
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_files(vec![file.clone()]), FeePayer::Sponsor), Error::<Test>::InvalidFeePayer);
     });
 }
 
 #[test]
-fn it_adds_link_with_requester() {
+fn it_fails_adding_file_with_fees_paid_by_ethereum_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        let requester = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(requester));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OtherAccountId::Ethereum(requester), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Other(OtherAccountId::Ethereum(requester)),
+            size: FILE_SIZE,
         };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_files(vec![file.clone()]), FeePayer::Requester), Error::<Test>::InvalidFeePayer);
     });
 }
 
 #[test]
-fn it_fails_adding_link_for_unauthorized_caller() {
+fn it_adds_file_with_fees_paid_by_requester_within_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(UNAUTHORIZED_CALLER),
+        let fees = Fees::storage_fees(1, FILE_SIZE);
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), fees + 1));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
         };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, link.clone()), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_files(vec![file.clone()]), FeePayer::Requester));
+
+        assert_eq!(LogionLoc::fee_allowance((LOC_ID, LOC_REQUESTER_ID, legal_officer_id(1))), Some(1));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeWithdrawn(LOC_REQUESTER_ID, fees)));
     });
 }
 
 #[test]
-fn it_fails_adding_link_when_closed() {
+fn it_fails_adding_file_with_fees_paid_by_requester_without_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
             submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
         };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()), Error::<Test>::CannotMutate);
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_files(vec![file.clone()]), FeePayer::Requester), Error::<Test>::InsufficientFeeAllowance);
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_wrong_link() {
+fn it_adds_file_with_fees_paid_by_sponsor_within_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        let requester = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(requester));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OtherAccountId::Ethereum(requester), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        let fees = Fees::storage_fees(1, FILE_SIZE);
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(SPONSOR_ID), LOC_ID, legal_officer_id(1), fees));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Other(OtherAccountId::Ethereum(requester)),
+            size: FILE_SIZE,
         };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()), Error::<Test>::LinkedLocNotFound);
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_files(vec![file.clone()]), FeePayer::Sponsor));
+
+        assert_eq!(LogionLoc::fee_allowance((LOC_ID, SPONSOR_ID, legal_officer_id(1))), Some(0));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeWithdrawn(SPONSOR_ID, fees)));
     });
 }
 
 #[test]
-fn it_acknowledges_link() {
+fn it_adds_file_with_fees_paid_by_owner_if_no_polkadot_requester() {
     new_test_ext().execute_with(|| {
-        let link = create_loc_with_link_from_requester();
-        assert_ok!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
+        };
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID, file.clone()));
+
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeWithdrawn(legal_officer_id(1), Fees::storage_fees(1, FILE_SIZE))));
     });
 }
 
-fn expected_link(link: &LocLinkParams<LocId, crate::mock::Hash, AccountId, EthereumAddress>, acknowledged_by_owner: bool, acknowledged_by_verified_issuer: bool) -> LocLink<LocId, crate::mock::Hash, AccountId, EthereumAddress> {
-    return LocLink {
-        id: link.id,
-        nature: link.nature.clone(),
-        submitter: link.submitter,
-        acknowledged_by_owner,
-        acknowledged_by_verified_issuer,
-    }
-}
+#[test]
+fn it_reserves_storage_deposit_when_adding_file() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        let file = add_file_to_transaction_loc();
 
-fn create_loc_with_link_from_requester() -> LocLinkParams<LocId, crate::mock::Hash, AccountId, EthereumAddress> {
-    setup_default_balances();
-    assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-    let link = LocLinkParams {
-        id: OTHER_LOC_ID,
-        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-    };
-    assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
-    let loc = LogionLoc::loc(LOC_ID).unwrap();
-    assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-    link
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), deposit);
+        assert_eq!(LogionLoc::storage_deposits_of(&LOC_ID), Ok(vec![(LOC_REQUESTER_ID, deposit)]));
+        assert_eq!(LogionLoc::loc_file_deposit(LOC_ID, file.hash), Some((LOC_REQUESTER_ID, deposit)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageDepositReserved(LOC_REQUESTER_ID, LOC_ID, deposit)));
+    });
 }
 
 #[test]
-fn it_acknowledges_link_as_verified_issuer() {
+fn it_keeps_storage_deposit_on_void() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-        };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        let file = add_file_to_transaction_loc();
 
-        assert_ok!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, link.id.clone()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
 
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, ACKNOWLEDGED));
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), deposit);
+        assert_eq!(LogionLoc::loc_file_deposit(LOC_ID, file.hash), Some((LOC_REQUESTER_ID, deposit)));
     });
 }
 
 #[test]
-fn it_fails_to_close_with_link_unacknowledged_by_verified_issuer() {
+fn it_releases_storage_deposit_of_void_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-        };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        let file = add_file_to_transaction_loc();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
 
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true), Error::<Test>::CannotCloseUnacknowledgedByVerifiedIssuer);
+        assert_ok!(LogionLoc::release_storage_deposits(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, 10));
 
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert!(!loc.closed);
-        assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), 0);
+        assert!(LogionLoc::storage_deposits_of(&LOC_ID).unwrap().is_empty());
+        assert_eq!(LogionLoc::loc_file_deposit(LOC_ID, file.hash), None);
+        assert!(LogionLoc::loc_file(LOC_ID, file.hash).is_none());
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().files_count, 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageDepositReleased(LOC_REQUESTER_ID, LOC_ID, deposit)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileDeleted(LOC_ID, file.hash)));
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_requester_link_as_issuer() {
+fn it_fails_releasing_storage_deposit_if_not_owner() {
     new_test_ext().execute_with(|| {
-        let link = create_loc_with_link_from_requester();
-        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, link.id.clone()), Error::<Test>::Unauthorized);
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        setup_default_balances();
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        let file = add_file_to_transaction_loc();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        assert_err!(LogionLoc::release_storage_deposits(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, 10), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::release_storage_deposits(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, 10), Error::<Test>::Unauthorized);
+
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), deposit);
+        assert!(LogionLoc::loc_file(LOC_ID, file.hash).is_some());
     });
 }
+
 #[test]
-fn it_fails_to_acknowledge_unknown_link() {
+fn it_fails_releasing_storage_deposit_of_loc_not_void() {
     new_test_ext().execute_with(|| {
-        create_loc_with_link_from_requester();
-        let id = 42;
-        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, id), Error::<Test>::ItemNotFound);
+        setup_default_balances();
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        add_file_to_transaction_loc();
+
+        assert_err!(LogionLoc::release_storage_deposits(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, 10), Error::<Test>::LocNotVoid);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_already_acknowledged_link() {
+fn it_fails_reserving_storage_deposit_when_insufficient_funds() {
     new_test_ext().execute_with(|| {
-        let link = create_loc_with_link_from_requester();
-        assert_ok!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()));
-        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()), Error::<Test>::ItemAlreadyAcknowledged);
+        setup_default_balances();
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()), Error::<Test>::InsufficientFunds);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_link_when_unauthorized_caller() {
+fn it_deletes_own_unacknowledged_metadata() {
     new_test_ext().execute_with(|| {
-        let link = create_loc_with_link_from_requester();
-        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, link.id.clone()), Error::<Test>::Unauthorized);
+        let metadata = create_loc_with_metadata_from_requester();
+
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataDeleted(LOC_ID, metadata.name)));
     });
 }
 
 #[test]
-fn it_fails_to_close_loc_with_unacknowledged_link() {
+fn it_fails_deleting_own_acknowledged_metadata() {
     new_test_ext().execute_with(|| {
-        create_loc_with_link_from_requester();
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false), Error::<Test>::CannotCloseUnacknowledgedByVerifiedIssuer);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name));
+
+        assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_closes_loc_and_acknowledges_link() {
+fn it_deletes_acknowledged_metadata_as_owner() {
     new_test_ext().execute_with(|| {
-        let link = create_loc_with_link_from_requester();
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name));
+
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 0);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_link_when_loc_voided() {
+fn it_fails_deleting_unknown_metadata() {
     new_test_ext().execute_with(|| {
-        let link = create_loc_with_link_from_requester();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()), Error::<Test>::CannotMutateVoid);
+        create_loc_with_metadata_from_requester();
+
+        assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, sha256(&"unknown".as_bytes().to_vec())), Error::<Test>::ItemNotFound);
     });
 }
 
 #[test]
-fn it_closes_loc() {
+fn it_deletes_own_unacknowledged_file() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert!(loc.closed);
-        assert!(loc.seal.is_none());
+        let file = create_loc_with_file_from_requester();
+
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.hash));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().files_count, 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileDeleted(LOC_ID, file.hash)));
     });
 }
 
 #[test]
-fn it_closes_loc_and_auto_acknowledges() {
+fn it_fails_deleting_file_of_other_submitter() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let file = create_loc_with_file_from_requester();
 
-        // links
-        let link = create_loc_with_link_from_requester();
-
-        // metadata
-        let metadata0 = add_metadata(0, LOC_REQUESTER_ID);
-        let metadata1 = add_metadata(1, LOC_REQUESTER_ID);
-        let metadata2 = add_metadata(2, legal_officer_id(1));
-
-        // files
-        let file0 = add_file(&"0", LOC_REQUESTER_ID);
-        let file1 = add_file(&"1", LOC_REQUESTER_ID);
-        let file2 = add_file(&"2", legal_officer_id(1));
+        assert_err!(LogionLoc::delete_file(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, file.hash), Error::<Test>::Unauthorized);
+    });
+}
 
+#[test]
+fn it_fails_deleting_file_when_closed() {
+    new_test_ext().execute_with(|| {
+        let file = create_loc_with_file_from_requester();
         assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert!(loc.closed);
-        assert!(loc.seal.is_none());
 
-        assert_eq!(loc.links[0], expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-
-        assert_eq!(loc.metadata[0], expected_metadata(metadata0.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-        assert_eq!(loc.metadata[1], expected_metadata(metadata1.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-        assert_eq!(loc.metadata[2], expected_metadata(metadata2.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-
-        assert_eq!(loc.files[0], expected_file(&file0, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-        assert_eq!(loc.files[1], expected_file(&file1, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-        assert_eq!(loc.files[2], expected_file(&file2, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_err!(LogionLoc::delete_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.hash), Error::<Test>::CannotMutate);
     });
 }
 
-fn add_metadata(name: u8, submitter: u64) -> MetadataItemParams<AccountId, EthereumAddress, H256> {
-    let metadata = MetadataItemParams {
-        name: sha256(&vec![name]),
-        value: sha256(&vec![4, 5, 6]),
-        submitter: SupportedAccountId::Polkadot(submitter),
-    };
-    assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(submitter), LOC_ID, metadata.clone()));
-    metadata
-}
+#[test]
+fn it_fails_deleting_file_when_void() {
+    new_test_ext().execute_with(|| {
+        let file = create_loc_with_file_from_requester();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
 
-fn add_file(content: &str, submitter: u64) -> FileParams<H256, AccountId, EthereumAddress> {
-    let file = FileParams {
-        hash: sha256(&content.as_bytes().to_vec()),
-        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-        submitter: SupportedAccountId::Polkadot(submitter),
-        size: FILE_SIZE,
-    };
-    assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(submitter), LOC_ID, file.clone()));
-    file
+        assert_err!(LogionLoc::delete_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.hash), Error::<Test>::CannotMutateVoid);
+    });
 }
 
 #[test]
-fn it_fails_closing_loc_for_unauthorized_caller() {
+fn it_releases_part_of_storage_deposit_when_deleting_file() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, None, false), Error::<Test>::Unauthorized);
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        let file = add_file_to_transaction_loc();
+        let free_balance = <Test as Config>::Currency::free_balance(LOC_REQUESTER_ID);
+
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.hash));
+
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        let fee = deposit - DeletedFileStorageRefund::get() * deposit;
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), 0);
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), free_balance + deposit - fee);
+        assert!(LogionLoc::storage_deposits_of(&LOC_ID).unwrap().is_empty());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageDepositReleased(LOC_REQUESTER_ID, LOC_ID, deposit)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeWithdrawn(LOC_REQUESTER_ID, fee)));
     });
 }
 
 #[test]
-fn it_fails_closing_loc_for_already_closed() {
+fn it_releases_whole_storage_deposit_when_owner_deletes_file() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false), Error::<Test>::AlreadyClosed);
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        let file = add_file_to_transaction_loc();
+        let free_balance = <Test as Config>::Currency::free_balance(LOC_REQUESTER_ID);
+
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.hash));
+
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), 0);
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), free_balance + deposit);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageDepositReleased(LOC_REQUESTER_ID, LOC_ID, deposit)));
     });
 }
 
 #[test]
-fn it_links_locs_to_account() {
+fn it_refunds_part_of_storage_fee_when_deleting_file() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert!(LogionLoc::account_locs(LOC_REQUESTER_ID).is_some());
-		assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap().len(), 2);
-        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[0], REQUESTER_IDENTITY_LOC_ID);
-        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[1], LOC_ID);
+        let file = add_file_to_transaction_loc();
+        let fee = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(LogionLoc::loc_file_storage_fee(LOC_ID, file.hash), Some((LOC_REQUESTER_ID, fee)));
+        let free_balance = <Test as Config>::Currency::free_balance(LOC_REQUESTER_ID);
+        let refund_account_balance = <Test as Config>::Currency::free_balance(LOGION_TREASURY_ACCOUNT_ID);
+
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.hash));
+
+        let refund = DeletedFileStorageRefund::get() * fee;
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), free_balance + refund);
+        assert_eq!(<Test as Config>::Currency::free_balance(LOGION_TREASURY_ACCOUNT_ID), refund_account_balance - refund);
+        assert_eq!(LogionLoc::loc_file_storage_fee(LOC_ID, file.hash), None);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeRefunded(LOC_REQUESTER_ID, LOC_ID, refund)));
     });
 }
 
 #[test]
-fn it_fails_creating_loc_with_non_legal_officer() {
+fn it_refunds_whole_storage_fee_when_owner_deletes_file() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()), Error::<Test>::Unauthorized);
-        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()), Error::<Test>::Unauthorized);
-        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()), Error::<Test>::Unauthorized);
+        let file = add_file_to_transaction_loc();
+        let free_balance = <Test as Config>::Currency::free_balance(LOC_REQUESTER_ID);
+
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.hash));
+
+        let fee = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), free_balance + fee);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageFeeRefunded(LOC_REQUESTER_ID, LOC_ID, fee)));
     });
 }
 
 #[test]
-fn it_detects_existing_identity_loc() {
+fn it_deletes_link_as_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let link = create_loc_with_link_from_requester();
 
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(2), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), OTHER_LOC_ID, None, false));
+        assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id));
 
-        let legal_officers = Vec::from([legal_officer_id(1), legal_officer_id(2)]);
-        assert!(LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().links_count, 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LinkDeleted(LOC_ID, link.id)));
     });
 }
 
 #[test]
-fn it_detects_valid_loc_with_owner() {
+fn it_fails_deleting_link_of_other_submitter() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), true);
+        let link = create_loc_with_link_from_requester();
+
+        assert_err!(LogionLoc::delete_link(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, link.id), Error::<Test>::Unauthorized);
     });
 }
 
+fn add_file_to_transaction_loc() -> FileParams<H256, AccountId, EthereumAddress> {
+    assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+    let file = FileParams {
+        hash: sha256(&"test".as_bytes().to_vec()),
+        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        size: FILE_SIZE,
+    };
+    assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+    file
+}
+
+fn check_no_fees(previous_balances: BalancesSnapshot) {
+    let current_balances = BalancesSnapshot::take(
+        previous_balances.payer_account,
+        previous_balances.legal_officers.iter().map(|legal_officer|{ legal_officer.0 }).collect()
+    );
+    let balances_delta = current_balances.delta_since(&previous_balances);
+
+    assert_eq!(balances_delta.total_credited(), 0);
+    assert_eq!(balances_delta.total_debited(), 0);
+}
+
 #[test]
-fn it_detects_non_existing_loc_as_invalid() {
+fn it_fails_adding_file_when_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), false);
+        create_closed_loc();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::CannotMutate);
     });
 }
 
 #[test]
-fn it_detects_open_loc_as_invalid() {
+fn it_adds_link_with_owner() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), false);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()));
+        let item = LogionLoc::loc_link(LOC_ID, link.id).unwrap();
+        assert_eq!(item.id, link.id);
+        assert_eq!(item.nature, link.nature);
+        assert_eq!(item.submitter, link.submitter);
+        assert_eq!(item.acknowledged_by_owner, true);
+        assert_eq!(item.acknowledged_by_verified_issuer, false);
     });
 }
 
 #[test]
-fn it_detects_void_loc_as_invalid() {
+fn it_adds_link_with_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), false);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
     });
 }
 
 #[test]
-fn it_detects_loc_with_wrong_owner_as_invalid() {
+fn it_fails_adding_link_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(2)), false);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(UNAUTHORIZED_CALLER),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, link.clone()), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_creates_logion_identity_loc() {
+fn it_fails_adding_link_when_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let snapshot = BalancesSnapshot::take(legal_officer_id(1), legal_officers());
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
-
-        assert!(LogionLoc::loc(LOGION_IDENTITY_LOC_ID).is_some());
-
-        check_no_fees(snapshot);
+        create_closed_loc();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()), Error::<Test>::CannotMutate);
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_polkadot_identity_loc() {
+fn it_fails_adding_wrong_link() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()), Error::<Test>::LinkedLocNotFound);
     });
 }
 
+fn link_with_nature(target: LocId, nature: &str) -> LocLinkParams<LocId, crate::mock::Hash, AccountId, EthereumAddress> {
+    LocLinkParams {
+        id: target,
+        nature: sha256(&nature.as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+    }
+}
+
 #[test]
-fn it_fails_creating_logion_loc_with_polkadot_transaction_loc() {
+fn it_fails_adding_link_to_open_loc_if_closed_target_required() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = link_with_nature(OTHER_LOC_ID, "closed-target");
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.clone()), Error::<Test>::LinkedLocNotClosed);
 
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, None, false));
+
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link));
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_logion_transaction_loc() {
+fn it_fails_adding_link_to_void_loc_if_non_void_target_required() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID, None, false));
-        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, LOGION_IDENTITY_LOC_ID));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID));
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link_with_nature(OTHER_LOC_ID, "test-link-nature")));
+        assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID));
 
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link_with_nature(OTHER_LOC_ID, "non-void-target")), Error::<Test>::LinkedLocVoid);
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_open_logion_identity_loc() {
+fn it_fails_adding_link_to_loc_of_other_owner_if_same_owner_required() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(2), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
 
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link_with_nature(OTHER_LOC_ID, "same-owner-target")), Error::<Test>::LinkedLocOwnerMismatch);
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link_with_nature(REQUESTER_IDENTITY_LOC_ID, "same-owner-target")));
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_closed_void_logion_identity_loc() {
+fn it_fails_adding_link_to_loc_of_wrong_type() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID, None, false));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
 
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_links(Vec::from([ link_with_nature(OTHER_LOC_ID, "identity-target") ])), FeePayer::Requester), Error::<Test>::LinkedLocTypeMismatch);
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ItemsParams::only_links(Vec::from([ link_with_nature(REQUESTER_IDENTITY_LOC_ID, "identity-target") ])), FeePayer::Requester));
     });
 }
 
 #[test]
-fn it_creates_collection_loc() {
+fn it_fails_creating_loc_with_initial_link_to_open_loc_if_closed_target_required() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: legal_officer_id(1),
-            requester: LOC_REQUESTER,
-            metadata: BoundedVec::new(),
-            files: BoundedVec::new(),
-            closed: false,
-            loc_type: LocType::Collection,
-            links: BoundedVec::new(),
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: Some(10),
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: None,
-            value_fee: 0,
-            legal_fee: OTHER_LOC_DEFAULT_LEGAL_FEE,
-            collection_item_fee: 0,
-            tokens_record_fee: 0,
-            imported: false,
-        }));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let mut link = link_with_nature(OTHER_LOC_ID, "closed-target");
+        link.submitter = SupportedAccountId::Polkadot(LOC_REQUESTER_ID);
 
-        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(legal_officer_id(1)));
-        fees.assert_balances_events(snapshot);
+        assert_err!(
+            LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link ]))),
+            Error::<Test>::LinkedLocNotClosed
+        );
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_without_limit() {
+fn it_fails_importing_loc_with_link_breaking_rules() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, None, false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()), Error::<Test>::CollectionHasNoLimit);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let mut items = build_items(false);
+        items.links[0].nature = sha256(&"closed-target".as_bytes().to_vec());
+
+        assert_err!(
+            LogionLoc::import_loc(
+                RuntimeOrigin::root(),
+                LOC_ID,
+                LOC_REQUESTER,
+                legal_officer_id(1),
+                LocType::Transaction,
+                items,
+                None,
+                None,
+                false,
+                0,
+                2000,
+                0,
+                0,
+                None,
+                None,
+                None,
+                None,
+                false,
+            ),
+            Error::<Test>::LinkedLocNotClosed
+        );
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_not_identified_requester() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()), Error::<Test>::AccountNotIdentified);
-	});
+fn it_acknowledges_link() {
+    new_test_ext().execute_with(|| {
+        let link = create_loc_with_link_from_requester();
+        assert_ok!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+fn expected_link(link: &LocLinkParams<LocId, crate::mock::Hash, AccountId, EthereumAddress>, acknowledged_by_owner: bool, acknowledged_by_verified_issuer: bool) -> LocLink<LocId, crate::mock::Hash, AccountId, EthereumAddress, BlockNumberFor<Test>> {
+    return LocLink {
+        id: link.id,
+        nature: link.nature.clone(),
+        submitter: link.submitter,
+        acknowledged_by_owner,
+        acknowledged_by_verified_issuer,
+        submitted_on: Some(System::block_number()),
+        acknowledged_by_owner_on: acknowledged_on(acknowledged_by_owner),
+        acknowledged_by_verified_issuer_on: acknowledged_on(acknowledged_by_verified_issuer),
+    }
+}
+
+fn create_loc_with_link_from_requester() -> LocLinkParams<LocId, crate::mock::Hash, AccountId, EthereumAddress> {
+    setup_default_balances();
+    assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+    let link = LocLinkParams {
+        id: OTHER_LOC_ID,
+        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+    };
+    assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
+    assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LinkAdded(LOC_ID, link.id, link.submitter)));
+    link
 }
 
 #[test]
-fn it_fails_adding_item_to_open_collection_loc() {
+fn it_indexes_links_to_loc() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+        create_loc_with_link_from_requester();
+        assert_eq!(LogionLoc::locs_linking_to(&OTHER_LOC_ID), Ok(Vec::from([LOC_ID])));
+        assert!(LogionLoc::locs_linking_to(&LOC_ID).unwrap().is_empty());
+
+        assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, OTHER_LOC_ID));
+
+        assert!(LogionLoc::locs_linking_to(&OTHER_LOC_ID).unwrap().is_empty());
     });
 }
 
 #[test]
-fn it_adds_item_to_closed_collection_loc() {
+fn it_reports_more_linking_locs_on_void() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        create_loc_with_link_from_requester();
+        MaxReportedLinkingLocs::set(&0);
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
-        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
-            description: collection_item_description,
-            files: BoundedVec::new(),
-            token: None,
-            restricted_delivery: false,
-            terms_and_conditions: BoundedVec::new(),
-            imported: false,
-        }));
-        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID));
+
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::VoidedLocLinkedBy(OTHER_LOC_ID, BoundedVec::new(), true)));
     });
 }
 
 #[test]
-fn it_fails_to_item_with_terms_and_conditions_when_non_existent_tc_loc() {
+fn it_reports_linking_locs_on_void() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        create_loc_with_link_from_requester();
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
-        let terms_and_conditions = vec![TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: sha256(&terms_and_conditions_details),
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotFound);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID));
+
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::VoidedLocLinkedBy(OTHER_LOC_ID, BoundedVec::truncate_from(Vec::from([LOC_ID])), false)));
     });
 }
 
 #[test]
-fn it_fails_to_add_item_with_terms_and_conditions_when_open_tc_loc() {
+fn it_acknowledges_link_as_verified_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
-        let terms_and_conditions = vec![TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: terms_and_conditions_details.clone(),
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotClosed);
+        assert_ok!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, link.id.clone()));
+
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_fails_to_item_with_terms_and_conditions_when_void_tc_loc() {
+fn it_acknowledges_items_as_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_CLASSIFICATION_LOC_ID));
+        let link = create_loc_with_link_from_requester();
+        let metadata = requester_metadata();
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+        let file = requester_file();
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
-        let terms_and_conditions = vec![TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: terms_and_conditions_details,
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocVoid);
+        assert_ok!(LogionLoc::acknowledge_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, vec![metadata.name], vec![file.hash], vec![link.id]));
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::Metadata(metadata.name), legal_officer_id(1))));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::File(file.hash), legal_officer_id(1))));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::Link(link.id), legal_officer_id(1))));
     });
 }
 
 #[test]
-fn it_adds_item_with_terms_and_conditions_to_closed_collection_loc() {
+fn it_acknowledges_items_as_verified_issuer() {
     new_test_ext().execute_with(|| {
-		setup_terms_and_conditions_locs();
-		let tcs = create_terms_and_conditions();
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions = vec![tcs[0].clone(), tcs[1].clone()];
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions.clone()));
-        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
-            description: collection_item_description,
-            files: BoundedVec::new(),
-            token: None,
-            restricted_delivery: false,
-            terms_and_conditions: BoundedVec::try_from(terms_and_conditions.clone()).expect("Failed to convert to BoundedVec"),
-            imported: false,
-        }));
-        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+        let metadata = MetadataItemParams {
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            ..requester_metadata()
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+        let file = FileParams {
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            ..requester_file()
+        };
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+
+        assert_ok!(LogionLoc::acknowledge_items(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, vec![metadata.name], vec![file.hash], vec![]));
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_fails_to_add_item_with_too_much_terms_and_conditions() {
+fn it_acknowledges_no_item_if_one_not_found() {
     new_test_ext().execute_with(|| {
-		setup_terms_and_conditions_locs();
-		let tcs = create_terms_and_conditions();
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions = vec![tcs[0].clone(), tcs[1].clone(), tcs[2].clone()];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions.clone()), Error::<Test>::CollectionItemTCsTooMuchData);
+        let link = create_loc_with_link_from_requester();
+        let metadata = requester_metadata();
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+
+        assert_err!(LogionLoc::acknowledge_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, vec![metadata.name], vec![sha256(&vec![7, 8, 9])], vec![link.id]), Error::<Test>::ItemNotFound);
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
-fn setup_terms_and_conditions_locs() {
-	setup_default_balances();
-	assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-	assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_CLASSIFICATION_LOC_ID, None, false));
-	assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), ADDITIONAL_TC_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), ADDITIONAL_TC_LOC_ID, None, false));
-}
+#[test]
+fn it_acknowledges_no_item_if_verified_issuer_not_submitter_of_one() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+        let issuer_metadata = MetadataItemParams {
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            ..requester_metadata()
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, issuer_metadata.clone()));
+        let file = requester_file();
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
 
-fn create_terms_and_conditions() -> [TermsAndConditionsElementOf<Test>; 3] {
-	let tc1 = TermsAndConditionsElement {
-		tc_type: sha256(&"Logion".as_bytes().to_vec()),
-		tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-		details: sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec()),
-	};
-	let tc2 = TermsAndConditionsElement {
-		tc_type: sha256(&"Specific".as_bytes().to_vec()),
-		tc_loc: ADDITIONAL_TC_LOC_ID,
-		details: sha256(&"Some more details".as_bytes().to_vec()),
-	};
-	let tc3 = TermsAndConditionsElement {
-		tc_type: sha256(&"Specific".as_bytes().to_vec()),
-		tc_loc: ADDITIONAL_TC_LOC_ID,
-		details: sha256(&"Even more details".as_bytes().to_vec()),
-	};
-	[tc1, tc2, tc3]
+        assert_err!(LogionLoc::acknowledge_items(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, vec![issuer_metadata.name], vec![file.hash], vec![]), Error::<Test>::Unauthorized);
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, issuer_metadata.name).unwrap(), expected_metadata(issuer_metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
 }
 
 #[test]
-fn it_fails_adding_item_to_collection_loc_if_not_requester() {
+fn it_fails_acknowledging_same_item_twice_in_batch() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let metadata = create_loc_with_metadata_from_requester();
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+        assert_err!(LogionLoc::acknowledge_items(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, vec![metadata.name, metadata.name], vec![], vec![]), Error::<Test>::ItemAlreadyAcknowledged);
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_duplicate_key() {
+fn it_adds_items() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata = requester_metadata();
+        let file1 = requester_file();
+        let file2 = FileParams {
+            hash: sha256(&vec![7, 8, 9]),
+            size: FILE_SIZE,
+            ..requester_file()
+        };
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let items = ItemsParams {
+            metadata: vec![metadata.clone()],
+            files: vec![file1.clone(), file2.clone()],
+            links: vec![link.clone()],
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, items, FeePayer::Requester));
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file1.hash).unwrap(), expected_file(&file1, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file2.hash).unwrap(), expected_file(&file2, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        let fees = Fees::only_storage(2, file1.size + file2.size);
+        fees.assert_balances_events(snapshot);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemsAdded(LOC_ID, vec![metadata.name], vec![file1.hash, file2.hash], vec![link.id])));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataAdded(LOC_ID, metadata.name, metadata.submitter)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileAdded(LOC_ID, file2.hash, file2.submitter)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LinkAdded(LOC_ID, link.id, link.submitter)));
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_size_limit_reached() {
+fn it_emits_item_events_when_creating_loc_with_items() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let metadata = requester_metadata();
+        let file = requester_file();
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
-        let collection_item_id2 = BlakeTwo256::hash_of(&"item-id2".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id2, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams {
+            metadata: vec![metadata.clone()],
+            files: vec![file.clone()],
+            links: vec![],
+        }));
+
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataAdded(LOC_ID, metadata.name, metadata.submitter)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileAdded(LOC_ID, file.hash, file.submitter)));
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_block_limit_reached() {
-    let current_block: u64 = 10;
-    new_test_ext_at_block(current_block).execute_with(|| {
+fn it_adds_items_without_files_free_of_charge() {
+    new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), Some(current_block - 1), None, false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata = requester_metadata();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ItemsParams::only_metadata(vec![metadata.clone()]), FeePayer::Requester));
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_collection_void() {
+fn it_fails_adding_items_with_duplicate() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        let metadata = create_loc_with_metadata_from_requester();
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ItemsParams::only_metadata(vec![metadata]), FeePayer::Requester), Error::<Test>::DuplicateLocMetadata);
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_files_attached_but_upload_not_enabled() {
+fn it_fails_adding_items_with_unknown_link_target() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::CannotUpload);
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ItemsParams::only_links(vec![link]), FeePayer::Requester), Error::<Test>::LinkedLocNotFound);
     });
 }
 
 #[test]
-fn it_adds_item_if_no_files_attached_and_upload_enabled() {
+fn it_fails_adding_items_with_invalid_submitter() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let items = ItemsParams {
+            metadata: vec![requester_metadata()],
+            files: vec![FileParams {
+                submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+                ..requester_file()
+            }],
+            links: vec![],
+        };
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()));
+        assert_err!(LogionLoc::add_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, items, FeePayer::Requester), Error::<Test>::CannotSubmit);
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 0);
     });
 }
 
-#[test]
-fn it_adds_item_with_one_file_attached() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+fn requester_metadata() -> MetadataItemParams<AccountId, EthereumAddress, crate::mock::Hash> {
+    MetadataItemParams {
+        name: sha256(&vec![1, 2, 3]),
+        value: sha256(&vec![4, 5, 6]),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+    }
+}
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: FILE_SIZE,
-        }];
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
-        let fees = Fees::only_storage(1, FILE_SIZE);
-        fees.assert_balances_events(snapshot);
-    });
+fn requester_file() -> FileParams<crate::mock::Hash, AccountId, EthereumAddress> {
+    FileParams {
+        hash: sha256(&vec![1, 2, 3]),
+        nature: sha256(&vec![4, 5, 6]),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        size: 456,
+    }
 }
 
 #[test]
-fn it_fails_adding_item_with_insufficient_balance() {
+fn it_fails_to_close_with_link_unacknowledged_by_verified_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: FILE_SIZE,
-        }];
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::InsufficientFunds);
-        check_no_fees(snapshot);
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true), Error::<Test>::CannotCloseUnacknowledgedByVerifiedIssuer);
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(!loc.closed);
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_adds_item_with_token() {
+fn it_fails_to_acknowledge_requester_link_as_issuer() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        let collection_item_token = CollectionItemToken {
-            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
-            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
-            token_issuance: 2,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()));
-        let fees = Fees {
-            storage_fees: Fees::storage_fees(1, collection_item_files[0].size),
-            legal_fees: 0,
-            fee_beneficiary: None,
-            certificate_fees: 8_000_000_000_000_000,
-            value_fee: 0,
-            collection_item_fee: 0,
-            tokens_record_fee: 0,
-        };
-        fees.assert_balances_events(snapshot);
+        let link = create_loc_with_link_from_requester();
+        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, link.id.clone()), Error::<Test>::Unauthorized);
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
-
 #[test]
-fn it_fails_adding_item_with_missing_token() {
+fn it_fails_to_acknowledge_unknown_link() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, true, Vec::new()), Error::<Test>::MissingToken);
+        create_loc_with_link_from_requester();
+        let id = 42;
+        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, id), Error::<Test>::ItemNotFound);
     });
 }
 
 #[test]
-fn it_fails_adding_item_with_missing_files() {
+fn it_fails_to_acknowledge_already_acknowledged_link() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![];
-        let collection_item_token = CollectionItemToken {
-            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
-            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
-            token_issuance: 1,
-        };
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, Some(collection_item_token), true, Vec::new()), Error::<Test>::MissingFiles);
+        let link = create_loc_with_link_from_requester();
+        assert_ok!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()));
+        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()), Error::<Test>::ItemAlreadyAcknowledged);
     });
 }
 
 #[test]
-fn it_adds_item_with_two_files_attached() {
+fn it_fails_to_acknowledge_link_when_unauthorized_caller() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-		let files = create_files();
-        let collection_item_files = vec![files[0].clone(), files[1].clone()];
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
-
-        let fees = Fees::only_storage(2, 123456 + 789);
-        fees.assert_balances_events(snapshot);
+        let link = create_loc_with_link_from_requester();
+        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, link.id.clone()), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_to_add_item_with_too_much_files_attached() {
+fn it_fails_to_close_loc_with_unacknowledged_link() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-		let files = create_files();
-        let collection_item_files = vec![files[0].clone(), files[1].clone(), files[2].clone()];
-        assert_err!(
-			LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()),
-			Error::<Test>::CollectionItemFilesTooMuchData
-		);
+        create_loc_with_link_from_requester();
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false), Error::<Test>::CannotCloseUnacknowledgedByVerifiedIssuer);
     });
 }
 
-fn create_files() -> [CollectionItemFile<<Test as Config>::Hash>; 3] {
-	[
-		CollectionItemFile {
-			name: sha256(&"picture.png".as_bytes().to_vec()),
-			content_type: sha256(&"image/png".as_bytes().to_vec()),
-			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-			size: 123456,
-		},
-		CollectionItemFile {
-			name: sha256(&"doc.pdf".as_bytes().to_vec()),
-			content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-			hash: BlakeTwo256::hash_of(&"some other content".as_bytes().to_vec()),
-			size: 789,
-		},
-		CollectionItemFile {
-			name: sha256(&"file.txt".as_bytes().to_vec()),
-			content_type: sha256(&"text/plain".as_bytes().to_vec()),
-			hash: BlakeTwo256::hash_of(&"some other, different content".as_bytes().to_vec()),
-			size: 29,
-		},
-	]
+#[test]
+fn it_closes_loc_and_acknowledges_link() {
+    new_test_ext().execute_with(|| {
+        let link = create_loc_with_link_from_requester();
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
 }
 
 #[test]
-fn it_fails_to_add_item_with_duplicate_hash() {
+fn it_fails_to_acknowledge_link_when_loc_voided() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
-        let collection_item_files = vec![
-            CollectionItemFile {
-                name: sha256(&"picture.png".as_bytes().to_vec()),
-                content_type: sha256(&"image/png".as_bytes().to_vec()),
-                hash: same_hash,
-                size: 123456,
-            },
-            CollectionItemFile {
-                name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: same_hash,
-                size: 789,
-            },
-        ];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::DuplicateFile);
+        let link = create_loc_with_link_from_requester();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        assert_err!(LogionLoc::acknowledge_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link.id.clone()), Error::<Test>::CannotMutateVoid);
     });
 }
 
 #[test]
-fn it_closes_and_seals_loc() {
+fn it_closes_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let seal = BlakeTwo256::hash_of(&"some external private data".as_bytes().to_vec());
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, Some(seal), false));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
         let loc = LogionLoc::loc(LOC_ID).unwrap();
         assert!(loc.closed);
-        assert!(loc.seal.is_some());
-        assert_eq!(loc.seal.unwrap(), seal);
+        assert!(loc.seal.is_none());
     });
 }
 
 #[test]
-fn it_fails_adding_file_with_same_hash() {
+fn it_closes_loc_and_auto_acknowledges() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let file1 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file1.clone()));
-        let file2 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file2-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-            size: FILE_SIZE,
-        };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file2.clone()), Error::<Test>::DuplicateLocFile);
-        let fees = Fees::only_storage(1, FILE_SIZE);
-        fees.assert_balances_events(snapshot);
+
+        // links
+        let link = create_loc_with_link_from_requester();
+
+        // metadata
+        let metadata0 = add_metadata(0, LOC_REQUESTER_ID);
+        let metadata1 = add_metadata(1, LOC_REQUESTER_ID);
+        let metadata2 = add_metadata(2, legal_officer_id(1));
+
+        // files
+        let file0 = add_file(&"0", LOC_REQUESTER_ID);
+        let file1 = add_file(&"1", LOC_REQUESTER_ID);
+        let file2 = add_file(&"2", legal_officer_id(1));
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(loc.closed);
+        assert!(loc.seal.is_none());
+
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata0.name).unwrap(), expected_metadata(metadata0.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata1.name).unwrap(), expected_metadata(metadata1.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata2.name).unwrap(), expected_metadata(metadata2.clone(), ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file0.hash).unwrap(), expected_file(&file0, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file1.hash).unwrap(), expected_file(&file1, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file2.hash).unwrap(), expected_file(&file2, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
-#[test]
-fn it_fails_adding_metadata_with_same_name() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let metadata1 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata1.clone()));
-        let metadata2 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata2.clone()), Error::<Test>::DuplicateLocMetadata);
-    });
+fn add_metadata(name: u8, submitter: u64) -> MetadataItemParams<AccountId, EthereumAddress, H256> {
+    let metadata = MetadataItemParams {
+        name: sha256(&vec![name]),
+        value: sha256(&vec![4, 5, 6]),
+        submitter: SupportedAccountId::Polkadot(submitter),
+    };
+    assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(submitter), LOC_ID, metadata.clone()));
+    metadata
 }
 
-#[test]
-fn it_fails_adding_link_with_same_target() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link1 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link1.clone()));
-        let link2 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link2.clone()), Error::<Test>::DuplicateLocLink);
-    });
+fn add_file(content: &str, submitter: u64) -> FileParams<H256, AccountId, EthereumAddress> {
+    let file = FileParams {
+        hash: sha256(&content.as_bytes().to_vec()),
+        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(submitter),
+        size: FILE_SIZE,
+    };
+    assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(submitter), LOC_ID, file.clone()));
+    file
 }
 
 #[test]
-fn it_adds_several_metadata() {
+fn it_fails_closing_loc_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let metadata1 = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata1.clone()));
-        let metadata2 = MetadataItemParams {
-            name: sha256(&vec![1, 2, 4]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata2.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata1, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-        assert_eq!(loc.metadata[1], expected_metadata(metadata2, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, None, false), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_to_add_too_much_metadata() {
+fn it_fails_closing_loc_for_already_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-		for i in 0..MAX_LOC_ITEMS {
-			let _ = add_metadata(i, LOC_REQUESTER_ID);
-		}
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-		assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()), Error::<Test>::LocMetadataTooMuchData);
+        create_closed_loc();
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false), Error::<Test>::AlreadyClosed);
     });
 }
 
 #[test]
-fn it_fails_to_add_too_much_files() {
+fn it_links_locs_to_account() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-		for i in 0..MAX_LOC_ITEMS {
-			let _ = add_file(&i.to_string(), LOC_REQUESTER_ID);
-		}
-		let file = FileParams {
-			hash: sha256(&"abcde".as_bytes().to_vec()),
-			nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-			submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-			size: FILE_SIZE,
-		};
-		assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::LocFilesTooMuchData);
+        assert!(LogionLoc::account_locs(LOC_REQUESTER_ID).is_some());
+		assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap().len(), 2);
+        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[0], REQUESTER_IDENTITY_LOC_ID);
+        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[1], LOC_ID);
     });
 }
 
 #[test]
-fn it_nominates_an_issuer() {
+fn it_fails_creating_loc_with_non_legal_officer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-
-        assert_eq!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1), Some(VerifiedIssuer { identity_loc: ISSUER1_IDENTITY_LOC_ID, imported: false }));
+        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_dismisses_an_issuer() {
+fn it_detects_existing_identity_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(legal_officer_id(1)), ISSUER_ID1));
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(2), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), OTHER_LOC_ID, None, false));
 
-        assert_eq!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1), None);
+        let legal_officers = Vec::from([legal_officer_id(1), legal_officer_id(2)]);
+        assert_eq!(LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers), Ok(true));
     });
 }
 
-fn nominate_issuer(issuer: u64, identity_loc: u32) {
-    assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(issuer), identity_loc, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), identity_loc, None, false));
-    assert_ok!(LogionLoc::nominate_issuer(RuntimeOrigin::signed(legal_officer_id(1)), issuer, identity_loc));
-}
-
 #[test]
-fn it_selects_an_issuer() {
+fn it_detects_valid_loc_with_owner() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
-
-        assert_eq!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1), Some(()));
-        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)), Some(()));
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), Ok(true));
     });
 }
 
-fn create_collection_and_nominated_issuer() {
-    assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
-    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-}
-
 #[test]
-fn it_fails_selecting_an_issuer_loc_not_found() {
+fn it_detects_non_existing_loc_as_invalid() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotFound);
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), Ok(false));
     });
 }
 
 #[test]
-fn it_fails_selecting_an_issuer_not_nominated() {
+fn it_detects_open_loc_as_invalid() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotNominated);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), Ok(false));
     });
 }
 
 #[test]
-fn it_fails_selecting_an_issuer_unauthorized() {
+fn it_detects_void_loc_as_invalid() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, ISSUER_ID1, true), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), Ok(false));
     });
 }
 
 #[test]
-fn it_selects_an_issuer_closed() {
+fn it_detects_loc_with_wrong_owner_as_invalid() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
         assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(2)), Ok(false));
     });
 }
 
 #[test]
-fn it_fails_selecting_an_issuer_void() {
+fn it_creates_logion_identity_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true), Error::<Test>::CannotMutateVoid);
-    });
-}
+        let snapshot = BalancesSnapshot::take(legal_officer_id(1), legal_officers());
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
 
-#[test]
-fn it_selects_an_issuer_not_collection() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert!(LogionLoc::loc(LOGION_IDENTITY_LOC_ID).is_some());
 
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_unselects_an_issuer() {
+fn it_fails_creating_logion_loc_with_polkadot_identity_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false));
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
 
-        assert_eq!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1), None);
-        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)), None);
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
     });
 }
 
-fn create_collection_with_selected_issuer() {
-    create_collection_and_nominated_issuer();
-    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
-}
-
 #[test]
-fn it_fails_unselecting_an_issuer_loc_not_found() {
+fn it_fails_creating_logion_loc_with_polkadot_transaction_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false), Error::<Test>::NotFound);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
     });
 }
 
 #[test]
-fn it_fails_unselecting_an_issuer_unauthorized() {
+fn it_fails_creating_logion_loc_with_logion_transaction_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID, None, false));
+        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, LOGION_IDENTITY_LOC_ID));
 
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, ISSUER_ID1, false), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
     });
 }
 
 #[test]
-fn it_unselects_an_issuer_closed() {
+fn it_fails_creating_logion_loc_with_open_logion_identity_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
 
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false));
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
     });
 }
 
 #[test]
-fn it_fails_unselecting_an_issuer_void() {
+fn it_fails_creating_logion_loc_with_closed_void_logion_identity_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID, None, false));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_IDENTITY_LOC_ID));
 
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false), Error::<Test>::CannotMutateVoid);
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
     });
 }
 
 #[test]
-fn it_unselects_issuer_on_dismiss() {
+fn it_creates_collection_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        nominate_issuer(ISSUER_ID2, ISSUER2_IDENTITY_LOC_ID);
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID2, true));
-        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1).is_some());
-        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID2).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, legal_officer_id(1), LOC_ID)).is_some());
-
-        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(legal_officer_id(1)), ISSUER_ID1));
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: legal_officer_id(1),
+            requester: LOC_REQUESTER,
+            metadata_count: 0,
+            files_count: 0,
+            closed: false,
+            loc_type: LocType::Collection,
+            links_count: 0,
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: Some(10),
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: None,
+            value_fee: 0,
+            legal_fee: OTHER_LOC_DEFAULT_LEGAL_FEE,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
+            imported: false,
+            created_on: Some(1),
+            closed_on: None,
+            voided_on: None,
+        }));
 
-        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1).is_none());
-        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID2).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)).is_none());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, legal_officer_id(1), LOC_ID)).is_some());
+        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(legal_officer_id(1)));
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_adds_tokens_record_issuer() {
-    it_adds_tokens_record(ISSUER_ID1);
-}
-
-fn it_adds_tokens_record(submitter: AccountId) {
-    it_adds_tokens_record_charging(submitter, LOC_REQUESTER_ID, false);
-}
-
-fn it_adds_tokens_record_charging(submitter: AccountId, payer: AccountId, charge_submitter: bool) {
+fn it_fails_creating_collection_loc_without_limit() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
-		assert_ok!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true));
-
-		let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
-
-        let snapshot = BalancesSnapshot::take(payer, legal_officers());
-        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(submitter), LOC_ID, record_id, record_description.clone(), record_files.clone(), charge_submitter));
-
-        let record = LogionLoc::tokens_records(LOC_ID, record_id).unwrap();
-        assert_eq!(record.description, record_description);
-        assert_eq!(record.submitter, submitter);
-        assert_eq!(record.files.len(), 1);
-        assert_eq!(record.files[0].name, record_files[0].name);
-        assert_eq!(record.files[0].content_type, record_files[0].content_type);
-        assert_eq!(record.files[0].size, record_files[0].size);
-        assert_eq!(record.files[0].hash, record_files[0].hash);
-
-        let fees = Fees::only_storage_and_tokens_record(1, record_files[0].size, TOKENS_RECORD_FEE, Beneficiary::LegalOfficer(legal_officer_id(1)));
-        fees.assert_balances_events(snapshot);
+        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, None, false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()), Error::<Test>::CollectionHasNoLimit);
     });
 }
 
-fn create_closed_collection_with_selected_issuer() {
-    create_collection_with_selected_issuer();
-    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-}
-
-fn build_record_id() -> H256 {
-    BlakeTwo256::hash_of(&"Record ID".as_bytes().to_vec())
-}
-
-fn build_record_description() -> H256 {
-    sha256(&"Some description".as_bytes().to_vec())
-}
-
-fn build_record_files(files: usize) -> Vec<TokensRecordFileOf<Test>> {
-    let mut record_files = Vec::with_capacity(files);
-    for i in 0..files {
-        let file = TokensRecordFile {
-            name: sha256(&"File name".as_bytes().to_vec()),
-            content_type: sha256(&"text/plain".as_bytes().to_vec()),
-            size: i as u32 % 10,
-            hash: BlakeTwo256::hash_of(&i.to_string().as_bytes().to_vec()),
-        };
-        record_files.push(file);
-    }
-    record_files
-}
-
-#[test]
-fn it_adds_tokens_record_requester() {
-    it_adds_tokens_record(LOC_REQUESTER_ID);
-}
-
 #[test]
-fn it_adds_tokens_record_owner() {
-    it_adds_tokens_record(legal_officer_id(1));
+fn it_fails_creating_collection_loc_with_not_identified_requester() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()), Error::<Test>::AccountNotIdentified);
+	});
 }
 
 #[test]
-fn it_fails_adding_tokens_record_already_exists() {
+fn it_fails_adding_item_to_open_collection_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
-
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description.clone(), record_files.clone(), false));
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files.clone(), false), Error::<Test>::TokensRecordAlreadyExists);
-        let file = record_files.get(0).unwrap();
-
-        let fees = Fees::only_storage_and_tokens_record(1, file.size, TOKENS_RECORD_FEE, Beneficiary::LegalOfficer(legal_officer_id(1)));
-        fees.assert_balances_events(snapshot);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_not_contributor() {
+fn it_adds_item_to_closed_collection_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID2), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
+        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
+            description: collection_item_description,
+            files: BoundedVec::new(),
+            token: None,
+            restricted_delivery: false,
+            terms_and_conditions: BoundedVec::new(),
+            imported: false,
+        }));
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_collection_open() {
+fn it_fails_to_item_with_terms_and_conditions_when_non_existent_tc_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
+        let terms_and_conditions = vec![TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: sha256(&terms_and_conditions_details),
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotFound);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_collection_void() {
+fn it_fails_to_add_item_with_terms_and_conditions_when_open_tc_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
+        let terms_and_conditions = vec![TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: terms_and_conditions_details.clone(),
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotClosed);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_not_collection() {
+fn it_fails_to_item_with_terms_and_conditions_when_void_tc_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_CLASSIFICATION_LOC_ID));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
+        let terms_and_conditions = vec![TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: terms_and_conditions_details,
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocVoid);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_no_files() {
+fn it_adds_item_with_terms_and_conditions_to_closed_collection_loc() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = vec![];
-
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::MustUpload);
+		setup_terms_and_conditions_locs();
+		let tcs = create_terms_and_conditions();
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions = vec![tcs[0].clone(), tcs[1].clone()];
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions.clone()));
+        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
+            description: collection_item_description,
+            files: BoundedVec::new(),
+            token: None,
+            restricted_delivery: false,
+            terms_and_conditions: BoundedVec::try_from(terms_and_conditions.clone()).expect("Failed to convert to BoundedVec"),
+            imported: false,
+        }));
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_duplicate_file() {
+fn it_fails_to_add_item_with_too_much_terms_and_conditions() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let file1 = TokensRecordFile {
-            name: sha256(&"File name".as_bytes().to_vec()),
-            content_type: sha256(&"text/plain".as_bytes().to_vec()),
-            size: 4,
-            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
-        };
-        let file2 = TokensRecordFile {
-            name: sha256(&"File name 2".as_bytes().to_vec()),
-            content_type: sha256(&"text/plain".as_bytes().to_vec()),
-            size: 4,
-            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
-        };
-        let record_files = vec![file1, file2];
-
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::DuplicateFile);
+		setup_terms_and_conditions_locs();
+		let tcs = create_terms_and_conditions();
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions = vec![tcs[0].clone(), tcs[1].clone(), tcs[2].clone()];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions.clone()), Error::<Test>::CollectionItemTCsTooMuchData);
     });
 }
 
-#[test]
-fn it_fails_adding_tokens_record_too_many_files() {
+fn setup_terms_and_conditions_locs() {
+	setup_default_balances();
+	assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+	assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOGION_CLASSIFICATION_LOC_ID, None, false));
+	assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), ADDITIONAL_TC_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), ADDITIONAL_TC_LOC_ID, None, false));
+}
+
+fn create_terms_and_conditions() -> [TermsAndConditionsElementOf<Test>; 3] {
+	let tc1 = TermsAndConditionsElement {
+		tc_type: sha256(&"Logion".as_bytes().to_vec()),
+		tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+		details: sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec()),
+	};
+	let tc2 = TermsAndConditionsElement {
+		tc_type: sha256(&"Specific".as_bytes().to_vec()),
+		tc_loc: ADDITIONAL_TC_LOC_ID,
+		details: sha256(&"Some more details".as_bytes().to_vec()),
+	};
+	let tc3 = TermsAndConditionsElement {
+		tc_type: sha256(&"Specific".as_bytes().to_vec()),
+		tc_loc: ADDITIONAL_TC_LOC_ID,
+		details: sha256(&"Even more details".as_bytes().to_vec()),
+	};
+	[tc1, tc2, tc3]
+}
+
+#[test]
+fn it_fails_adding_item_to_collection_loc_if_not_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(256);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::TokensRecordTooMuchData);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_when_insufficient_funds() {
+fn it_fails_adding_item_if_duplicate_key() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::InsufficientFunds);
-        check_no_fees(snapshot);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
     });
 }
 
-fn nominated_and_select_issuer(loc_id: u32) {
-    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), loc_id, ISSUER_ID1, true));
+#[test]
+fn it_fails_adding_item_if_size_limit_reached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
+        let collection_item_id2 = BlakeTwo256::hash_of(&"item-id2".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id2, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
+    });
 }
 
 #[test]
-fn it_adds_file_on_polkadot_transaction_loc_when_caller_is_requester_and_submitter_is_issuer() {
-    new_test_ext().execute_with(|| {
+fn it_fails_adding_item_if_block_limit_reached() {
+    let current_block: u64 = 10;
+    new_test_ext_at_block(current_block).execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        nominated_and_select_issuer(LOC_ID);
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), Some(current_block - 1), None, false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        let fees = Fees::only_storage(1, FILE_SIZE);
-        fees.assert_balances_events(snapshot);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
     });
 }
 
 #[test]
-fn it_fails_adding_file_on_polkadot_transaction_loc_cannot_submit() {
+fn it_fails_adding_item_if_collection_void() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(2)),
-            size: FILE_SIZE,
-        };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::CannotSubmit);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_on_logion_identity_loc_cannot_submit() {
+fn it_fails_adding_item_if_files_attached_but_upload_not_enabled() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(2)),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::CannotUpload);
     });
 }
 
 #[test]
-fn it_adds_metadata_on_polkadot_transaction_loc_when_submitter_is_issuer() {
+fn it_adds_item_if_no_files_attached_and_upload_enabled() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        nominated_and_select_issuer(LOC_ID);
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()));
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_on_polkadot_transaction_loc_cannot_submit() {
+fn it_adds_item_with_one_file_attached() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(2)),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        }];
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_creates_sponsorship() {
+fn it_fails_adding_item_with_insufficient_balance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
 
-        let sponsorship = LogionLoc::sponsorship(sponsorship_id).unwrap();
-        assert_eq!(sponsorship.legal_officer, legal_officer_id(1));
-        assert_eq!(sponsorship.sponsor, SPONSOR_ID);
-        assert_eq!(sponsorship.sponsored_account, sponsored_account);
-        assert_eq!(sponsorship.loc_id, None);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        }];
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::InsufficientFunds);
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_fails_creating_sponsorship_with_duplicate_id() {
+fn it_adds_item_with_token() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-		let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert_err!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)), Error::<Test>::AlreadyExists);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        let collection_item_token = CollectionItemToken {
+            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+            token_issuance: 2,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()));
+        let fees = Fees {
+            storage_fees: Fees::storage_fees(1, collection_item_files[0].size),
+            legal_fees: 0,
+            fee_beneficiary: None,
+            certificate_fees: 8_000_000_000_000_000,
+            value_fee: 0,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
+        };
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_withdraws_unused_sponsorship() {
+fn it_fails_adding_item_with_missing_token() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert!(LogionLoc::sponsorship(sponsorship_id).is_some());
-
-        assert_ok!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert!(LogionLoc::sponsorship(sponsorship_id).is_none());
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, true, Vec::new()), Error::<Test>::MissingToken);
     });
 }
 
 #[test]
-fn it_creates_ethereum_identity_loc() {
+fn it_fails_adding_item_with_missing_files() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_account_id = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(ethereum_address));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        let snapshot = BalancesSnapshot::take(SPONSOR_ID, legal_officers());
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_account_id.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: legal_officer_id(1),
-            requester: OtherAccount(requester_account_id.clone()),
-            metadata: BoundedVec::new(),
-            files: BoundedVec::new(),
-            closed: false,
-            loc_type: LocType::Identity,
-            links: BoundedVec::new(),
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: None,
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: Some(sponsorship_id),
-            value_fee: 0,
-            legal_fee: ID_LOC_DEFAULT_LEGAL_FEE,
-            collection_item_fee: 0,
-            tokens_record_fee: 0,
-            imported: false,
-        }));
-		assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().loc_id, Some(LOC_ID));
-        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Other);
-        fees.assert_balances_events(snapshot);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![];
+        let collection_item_token = CollectionItemToken {
+            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+            token_issuance: 1,
+        };
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, Some(collection_item_token), true, Vec::new()), Error::<Test>::MissingFiles);
     });
 }
 
 #[test]
-fn it_creates_polkadot_identity_loc() {
+fn it_adds_item_with_two_files_attached() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+		let files = create_files();
+        let collection_item_files = vec![files[0].clone(), files[1].clone()];
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: legal_officer_id(1),
-            requester: Account(LOC_REQUESTER_ID),
-            metadata: BoundedVec::new(),
-            files: BoundedVec::new(),
-            closed: false,
-            loc_type: LocType::Identity,
-            links: BoundedVec::new(),
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: None,
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: None,
-            value_fee: 0,
-            legal_fee: ID_LOC_DEFAULT_LEGAL_FEE,
-            collection_item_fee: 0,
-            tokens_record_fee: 0,
-            imported: false,
-        }));
-		let loc_ids = BoundedVec::try_from(vec![LOC_ID]).expect("Failed to create expected BoundedVec");
-        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID), Some(loc_ids));
-        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
 
-        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Other);
+        let fees = Fees::only_storage(2, 123456 + 789);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_creating_ethereum_identity_loc_if_duplicate_loc_id() {
+fn it_fails_to_add_item_with_too_much_files_attached() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_address = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
-        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE), Error::<Test>::AlreadyExists);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+		let files = create_files();
+        let collection_item_files = vec![files[0].clone(), files[1].clone(), files[2].clone()];
+        assert_err!(
+			LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()),
+			Error::<Test>::CollectionItemFilesTooMuchData
+		);
     });
 }
 
-#[test]
-fn it_fails_creating_several_ethereum_identity_loc_with_single_sponsorship() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_address = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
-        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE), Error::<Test>::CannotLinkToSponsorship);
-    });
+fn create_files() -> [CollectionItemFile<<Test as Config>::Hash>; 3] {
+	[
+		CollectionItemFile {
+			name: sha256(&"picture.png".as_bytes().to_vec()),
+			content_type: sha256(&"image/png".as_bytes().to_vec()),
+			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+			size: 123456,
+		},
+		CollectionItemFile {
+			name: sha256(&"doc.pdf".as_bytes().to_vec()),
+			content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+			hash: BlakeTwo256::hash_of(&"some other content".as_bytes().to_vec()),
+			size: 789,
+		},
+		CollectionItemFile {
+			name: sha256(&"file.txt".as_bytes().to_vec()),
+			content_type: sha256(&"text/plain".as_bytes().to_vec()),
+			hash: BlakeTwo256::hash_of(&"some other, different content".as_bytes().to_vec()),
+			size: 29,
+		},
+	]
 }
 
 #[test]
-fn it_fails_withdrawing_used_sponsorship() {
+fn it_fails_to_add_item_with_duplicate_hash() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let requester_address = OtherAccountId::Ethereum(beneficiary);
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address, sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        assert_err!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id), Error::<Test>::AlreadyUsed);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
+        let collection_item_files = vec![
+            CollectionItemFile {
+                name: sha256(&"picture.png".as_bytes().to_vec()),
+                content_type: sha256(&"image/png".as_bytes().to_vec()),
+                hash: same_hash,
+                size: 123456,
+            },
+            CollectionItemFile {
+                name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: same_hash,
+                size: 789,
+            },
+        ];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::DuplicateFile);
     });
 }
 
 #[test]
-fn it_adds_metadata_when_submitter_is_ethereum_requester() {
+fn it_closes_and_seals_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_address = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account = SupportedAccountId::Other(requester_address);
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address, sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: sponsored_account,
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let seal = BlakeTwo256::hash_of(&"some external private data".as_bytes().to_vec());
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, Some(seal), false));
         let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert!(loc.closed);
+        assert!(loc.seal.is_some());
+        assert_eq!(loc.seal.unwrap(), seal);
     });
 }
 
 #[test]
-fn it_adds_file_when_submitter_is_ethereum_requester() {
+fn it_fails_adding_file_with_same_hash() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let requester = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsorship_id = 1;
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(requester));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OtherAccountId::Ethereum(requester), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
-        let file = FileParams {
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let file1 = FileParams {
             hash: sha256(&"test".as_bytes().to_vec()),
             nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Other(OtherAccountId::Ethereum(requester)),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
             size: FILE_SIZE,
         };
-        let snapshot = BalancesSnapshot::take(SPONSOR_ID, legal_officers());
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-
-        let fees = Fees::only_storage(1, file.size);
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file1.clone()));
+        let file2 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file2-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file2.clone()), Error::<Test>::DuplicateLocFile);
+        let fees = Fees::only_storage(1, FILE_SIZE);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_item_with_token_with_zero_issuance() {
+fn it_fails_adding_metadata_with_same_name() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata1 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata1.clone()));
+        let metadata2 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata2.clone()), Error::<Test>::DuplicateLocMetadata);
+    });
+}
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        let collection_item_token = CollectionItemToken {
-            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
-            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
-            token_issuance: 0,
+#[test]
+fn it_fails_adding_link_with_same_target() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link1 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
         };
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()), Error::<Test>::BadTokenIssuance);
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link1.clone()));
+        let link2 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, link2.clone()), Error::<Test>::DuplicateLocLink);
     });
 }
 
 #[test]
-fn it_reserves_value_fees() {
+fn it_adds_several_metadata() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let legal_fee = 2000 * ONE_LGNT;
-        let value_fee = 100;
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: legal_officer_id(1),
-            requester: LOC_REQUESTER,
-            metadata: BoundedVec::new(),
-            files: BoundedVec::new(),
-            closed: false,
-            loc_type: LocType::Collection,
-            links: BoundedVec::new(),
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: Some(10),
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: None,
-            value_fee,
-            legal_fee: OTHER_LOC_DEFAULT_LEGAL_FEE,
-            collection_item_fee: 0,
-            tokens_record_fee: 0,
-            imported: false,
-        }));
-
-        let expected_free_balance = INITIAL_BALANCE.saturating_sub(legal_fee).saturating_sub(value_fee);
-        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), expected_free_balance);
-        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), value_fee);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata1 = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata1.clone()));
+        let metadata2 = MetadataItemParams {
+            name: sha256(&vec![1, 2, 4]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata2.clone()));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata1.name).unwrap(), expected_metadata(metadata1, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata2.name).unwrap(), expected_metadata(metadata2, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_captures_value_fees() {
+fn it_fails_to_add_too_much_metadata() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let value_fee = 100;
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        let previous_balances = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
-
-        let fees = Fees {
-            certificate_fees: 0,
-            fee_beneficiary: None,
-            legal_fees: 0,
-            storage_fees: 0,
-            value_fee,
-            collection_item_fee: 0,
-            tokens_record_fee: 0,
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+		for i in 0..MAX_LOC_ITEMS {
+			let _ = add_metadata(i, LOC_REQUESTER_ID);
+		}
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
         };
-        fees.assert_balances_events(previous_balances);
+		assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()), Error::<Test>::LocMetadataTooMuchData);
     });
 }
 
 #[test]
-fn it_frees_value_fees_community_treasury_on_void() {
+fn it_fails_to_add_too_much_files() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let value_fee = 100;
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-
-        let legal_fee = 2000 * ONE_LGNT;
-        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), INITIAL_BALANCE.saturating_sub(legal_fee));
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+		for i in 0..MAX_LOC_ITEMS {
+			let _ = add_file(&i.to_string(), LOC_REQUESTER_ID);
+		}
+		let file = FileParams {
+			hash: sha256(&"abcde".as_bytes().to_vec()),
+			nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+			submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+			size: FILE_SIZE,
+		};
+		assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::LocFilesTooMuchData);
     });
 }
 
 #[test]
-fn it_does_not_free_value_fees_community_treasury_on_void_closed() {
+fn it_nominates_an_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let value_fee = 100;
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, None, false));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID));
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
 
-        let legal_fee = 2000 * ONE_LGNT;
-        let expected_free_balance = INITIAL_BALANCE.saturating_sub(2 * legal_fee).saturating_sub(2 * value_fee);
-        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), expected_free_balance);
-        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), value_fee);
+        assert_eq!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1), Some(VerifiedIssuer { identity_loc: ISSUER1_IDENTITY_LOC_ID, imported: false }));
     });
 }
 
 #[test]
-fn it_creates_transaction_loc_with_initial_metadata() {
+fn it_dismisses_an_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+
+        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(legal_officer_id(1)), ISSUER_ID1));
+
+        assert_eq!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1), None);
     });
 }
 
+fn nominate_issuer(issuer: u64, identity_loc: u32) {
+    assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(issuer), identity_loc, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), identity_loc, None, false));
+    assert_ok!(LogionLoc::nominate_issuer(RuntimeOrigin::signed(legal_officer_id(1)), issuer, identity_loc));
+}
+
 #[test]
-fn it_fails_creating_transaction_loc_with_duplicate_initial_metadata() {
+fn it_selects_an_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata1 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        let metadata2 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata1.clone(), metadata2.clone() ]))),
-            Error::<Test>::DuplicateLocMetadata
-        );
+        create_collection_and_nominated_issuer();
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+
+        assert_eq!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1), Some(()));
+        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)), Some(()));
     });
 }
 
-#[test]
-fn it_fails_creating_transaction_loc_with_not_identified_requester() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		assert_err!(
-            LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()),
-            Error::<Test>::AccountNotIdentified
-        );
-	});
+fn create_collection_and_nominated_issuer() {
+    assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
+    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
 }
 
 #[test]
-fn it_fails_creating_transaction_loc_with_invalid_metadata_submitter() {
+fn it_fails_selecting_an_issuer_loc_not_found() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))),
-            Error::<Test>::CannotSubmit
-        );
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotFound);
     });
 }
 
 #[test]
-fn it_creates_transaction_loc_with_initial_files() {
+fn it_fails_selecting_an_issuer_not_nominated() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotNominated);
     });
 }
 
 #[test]
-fn it_fails_creating_transaction_loc_with_duplicate_initial_files() {
+fn it_fails_selecting_an_issuer_unauthorized() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file1 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test 1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        let file2 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test 2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file1, file2 ]))),
-            Error::<Test>::DuplicateLocFile
-        );
+        create_collection_and_nominated_issuer();
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, ISSUER_ID1, true), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_creating_transaction_loc_with_invalid_file_submitter() {
+fn it_selects_an_issuer_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-            size: 4,
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file ]))),
-            Error::<Test>::CannotSubmit
-        );
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
     });
 }
 
 #[test]
-fn it_creates_transaction_loc_with_initial_links() {
+fn it_fails_selecting_an_issuer_void() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true), Error::<Test>::CannotMutateVoid);
     });
 }
 
 #[test]
-fn it_fails_creating_transaction_loc_with_initial_links_if_not_found() {
+fn it_selects_an_issuer_not_collection() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))),
-            Error::<Test>::LinkedLocNotFound
-        );
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
     });
 }
 
 #[test]
-fn it_fails_creating_transaction_loc_with_duplicate_initial_links() {
+fn it_unselects_an_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link1 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        let link2 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link1, link2 ]))),
-            Error::<Test>::DuplicateLocLink
-        );
+        create_collection_with_selected_issuer();
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false));
+
+        assert_eq!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1), None);
+        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)), None);
     });
 }
 
+fn create_collection_with_selected_issuer() {
+    create_collection_and_nominated_issuer();
+    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+}
+
 #[test]
-fn it_fails_creating_transaction_loc_with_invalid_link_submitter() {
+fn it_fails_unselecting_an_issuer_loc_not_found() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(
-            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link ]))),
-            Error::<Test>::CannotSubmit
-        );
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false), Error::<Test>::NotFound);
     });
 }
 
 #[test]
-fn it_creates_identity_loc_with_initial_metadata() {
+fn it_fails_unselecting_an_issuer_unauthorized() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        create_collection_with_selected_issuer();
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, ISSUER_ID1, false), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_to_create_too_much_identity_locs() {
+fn it_unselects_an_issuer_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-		for i in 0u32..MaxAccountLocs::get() {
-			assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), i, legal_officer_id(1), 0, ItemsParams::empty()));
-		}
-		assert_err!(
-			LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), MaxAccountLocs::get(), legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()),
-			Error::<Test>::AccountLocsTooMuchData
-		);
+        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false));
     });
 }
 
 #[test]
-fn it_fails_to_create_too_much_transaction_locs() {
+fn it_fails_unselecting_an_issuer_void() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), 0);
-		for i in 1u32..MaxAccountLocs::get() {
-			assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), i, legal_officer_id(1), 0, ItemsParams::empty()));
-		}
-		assert_err!(
-			LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), MaxAccountLocs::get(), legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()),
-			Error::<Test>::AccountLocsTooMuchData
-		);
+        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, false), Error::<Test>::CannotMutateVoid);
     });
 }
 
 #[test]
-fn it_fails_to_create_too_much_collection_locs() {
+fn it_unselects_issuer_on_dismiss() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), 0);
-		for i in 1u32..MaxAccountLocs::get() {
-			assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), i, legal_officer_id(1), None, Some(10), false, 0, 0, 0, 0, ItemsParams::empty()));
-		}
-		assert_err!(
-			LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), MaxAccountLocs::get(), legal_officer_id(1), None, Some(10), false, 0, 0, 0, 0, ItemsParams::empty()),
-			Error::<Test>::AccountLocsTooMuchData
-		);
+        create_closed_collection_with_selected_issuer();
+        nominate_issuer(ISSUER_ID2, ISSUER2_IDENTITY_LOC_ID);
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID2, true));
+        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1).is_some());
+        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID2).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, legal_officer_id(1), LOC_ID)).is_some());
+
+        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(legal_officer_id(1)), ISSUER_ID1));
+
+        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1).is_none());
+        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID2).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)).is_none());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, legal_officer_id(1), LOC_ID)).is_some());
     });
 }
 
 #[test]
-fn it_fails_creating_identity_loc_with_duplicate_initial_metadata() {
+fn it_adds_tokens_record_issuer() {
+    it_adds_tokens_record(ISSUER_ID1);
+}
+
+fn it_adds_tokens_record(submitter: AccountId) {
+    it_adds_tokens_record_charging(submitter, LOC_REQUESTER_ID, false);
+}
+
+fn it_adds_tokens_record_charging(submitter: AccountId, payer: AccountId, charge_submitter: bool) {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata1 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        let metadata2 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata1.clone(), metadata2.clone() ]))),
-            Error::<Test>::DuplicateLocMetadata
-        );
+        create_closed_collection_with_selected_issuer();
+
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
+		assert_ok!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true));
+
+		let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        let snapshot = BalancesSnapshot::take(payer, legal_officers());
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(submitter), LOC_ID, record_id, record_description.clone(), record_files.clone(), charge_submitter));
+
+        let record = LogionLoc::tokens_records(LOC_ID, record_id).unwrap();
+        assert_eq!(record.description, record_description);
+        assert_eq!(record.submitter, submitter);
+        assert_eq!(record.files.len(), 1);
+        assert_eq!(record.files[0].name, record_files[0].name);
+        assert_eq!(record.files[0].content_type, record_files[0].content_type);
+        assert_eq!(record.files[0].size, record_files[0].size);
+        assert_eq!(record.files[0].hash, record_files[0].hash);
+
+        let fees = Fees::only_storage_and_tokens_record(1, record_files[0].size, TOKENS_RECORD_FEE, Beneficiary::LegalOfficer(legal_officer_id(1)));
+        fees.assert_balances_events(snapshot);
     });
 }
 
-#[test]
-fn it_fails_creating_identity_loc_with_invalid_metadata_submitter() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+fn create_closed_collection_with_selected_issuer() {
+    create_collection_with_selected_issuer();
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+}
+
+fn build_record_id() -> H256 {
+    BlakeTwo256::hash_of(&"Record ID".as_bytes().to_vec())
+}
+
+fn build_record_description() -> H256 {
+    sha256(&"Some description".as_bytes().to_vec())
+}
+
+fn build_record_files(files: usize) -> Vec<TokensRecordFileOf<Test>> {
+    let mut record_files = Vec::with_capacity(files);
+    for i in 0..files {
+        let file = TokensRecordFile {
+            name: sha256(&"File name".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: i as u32 % 10,
+            hash: BlakeTwo256::hash_of(&i.to_string().as_bytes().to_vec()),
         };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))),
-            Error::<Test>::CannotSubmit
-        );
-    });
+        record_files.push(file);
+    }
+    record_files
 }
 
 #[test]
-fn it_creates_identity_loc_with_initial_files() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
-    });
+fn it_adds_tokens_record_requester() {
+    it_adds_tokens_record(LOC_REQUESTER_ID);
 }
 
 #[test]
-fn it_fails_creating_identity_loc_with_duplicate_initial_files() {
+fn it_adds_tokens_record_owner() {
+    it_adds_tokens_record(legal_officer_id(1));
+}
+
+#[test]
+fn it_fails_adding_tokens_record_already_exists() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file1 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test 1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        let file2 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test 2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file1, file2 ]))),
-            Error::<Test>::DuplicateLocFile
-        );
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description.clone(), record_files.clone(), false));
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files.clone(), false), Error::<Test>::TokensRecordAlreadyExists);
+        let file = record_files.get(0).unwrap();
+
+        let fees = Fees::only_storage_and_tokens_record(1, file.size, TOKENS_RECORD_FEE, Beneficiary::LegalOfficer(legal_officer_id(1)));
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_creating_identity_loc_with_invalid_file_submitter() {
+fn it_fails_adding_tokens_record_not_contributor() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-            size: 4,
-        };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file ]))),
-            Error::<Test>::CannotSubmit
-        );
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID2), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
     });
 }
 
 #[test]
-fn it_creates_identity_loc_with_initial_links() {
+fn it_fails_adding_tokens_record_collection_open() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        create_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
     });
 }
 
 #[test]
-fn it_fails_creating_identity_loc_with_initial_links_if_not_found() {
+fn it_fails_adding_tokens_record_collection_void() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))),
-            Error::<Test>::LinkedLocNotFound
-        );
+        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
     });
 }
 
 #[test]
-fn it_fails_creating_identity_loc_with_duplicate_initial_links() {
+fn it_fails_adding_tokens_record_not_collection() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link1 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        let link2 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link1, link2 ]))),
-            Error::<Test>::DuplicateLocLink
-        );
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::CannotAddRecord);
     });
 }
 
 #[test]
-fn it_fails_creating_identity_loc_with_invalid_link_submitter() {
+fn it_fails_adding_tokens_record_charging_non_polkadot_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(
-            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link ]))),
-            Error::<Test>::CannotSubmit
-        );
+        let requester = OtherAccountId::Ethereum(H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap());
+        assert_ok!(LogionLoc::import_loc(
+            RuntimeOrigin::root(),
+            LOC_ID,
+            OtherAccount(requester),
+            legal_officer_id(1),
+            LocType::Collection,
+            Items { metadata: Vec::new(), files: Vec::new(), links: Vec::new() },
+            None,
+            Some(10),
+            false,
+            0,
+            OTHER_LOC_DEFAULT_LEGAL_FEE,
+            0,
+            TOKENS_RECORD_FEE,
+            None,
+            None,
+            None,
+            None,
+            true,
+        ));
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::InvalidFeePayer);
     });
 }
 
 #[test]
-fn it_creates_collection_loc_with_initial_metadata() {
+fn it_fails_adding_tokens_record_no_files() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = vec![];
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::MustUpload);
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_duplicate_initial_metadata() {
+fn it_fails_adding_tokens_record_duplicate_file() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata1 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let file1 = TokensRecordFile {
+            name: sha256(&"File name".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: 4,
+            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
         };
-        let metadata2 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        let file2 = TokensRecordFile {
+            name: sha256(&"File name 2".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: 4,
+            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
         };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_metadata(Vec::from([ metadata1.clone(), metadata2.clone() ]))),
-            Error::<Test>::DuplicateLocMetadata
-        );
+        let record_files = vec![file1, file2];
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::DuplicateFile);
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_invalid_metadata_submitter() {
+fn it_fails_adding_tokens_record_too_many_files() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))),
-            Error::<Test>::CannotSubmit
-        );
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(256);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::TokensRecordTooMuchData);
     });
 }
 
 #[test]
-fn it_creates_collection_loc_with_initial_files() {
+fn it_fails_adding_tokens_record_when_insufficient_funds() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_files(Vec::from([ file.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        create_closed_collection_with_selected_issuer();
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files, false), Error::<Test>::InsufficientFunds);
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_duplicate_initial_files() {
+fn it_grants_fee_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file1 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test 1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        let file2 = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test 2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_files(Vec::from([ file1, file2 ]))),
-            Error::<Test>::DuplicateLocFile
-        );
+        create_closed_collection_with_selected_issuer();
+
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ISSUER_ID1, TOKENS_RECORD_FEE));
+
+        assert_eq!(LogionLoc::fee_allowance((LOC_ID, LOC_REQUESTER_ID, ISSUER_ID1)), Some(TOKENS_RECORD_FEE));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FeeAllowanceGranted(LOC_ID, LOC_REQUESTER_ID, ISSUER_ID1, TOKENS_RECORD_FEE)));
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_invalid_file_submitter() {
+fn it_fails_granting_fee_allowance_if_not_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-            size: 4,
-        };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_files(Vec::from([ file ]))),
-            Error::<Test>::CannotSubmit
-        );
+        create_closed_collection_with_selected_issuer();
+
+        assert_err!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, TOKENS_RECORD_FEE), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_creates_collection_loc_with_initial_links() {
+fn it_revokes_fee_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link.clone() ]))));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        create_closed_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ISSUER_ID1, TOKENS_RECORD_FEE));
+
+        assert_ok!(LogionLoc::revoke_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ISSUER_ID1));
+
+        assert_eq!(LogionLoc::fee_allowance((LOC_ID, LOC_REQUESTER_ID, ISSUER_ID1)), None);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FeeAllowanceRevoked(LOC_ID, LOC_REQUESTER_ID, ISSUER_ID1)));
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_initial_links_if_not_found() {
+fn it_fails_revoking_missing_fee_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link.clone() ]))),
-            Error::<Test>::LinkedLocNotFound
-        );
+        create_closed_collection_with_selected_issuer();
+
+        assert_err!(LogionLoc::revoke_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ISSUER_ID1), Error::<Test>::NoFeeAllowance);
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_duplicate_initial_links() {
+fn it_decrements_fee_allowance_when_adding_tokens_record() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link1 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        let link2 = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link1, link2 ]))),
-            Error::<Test>::DuplicateLocLink
-        );
+        create_closed_collection_with_selected_issuer();
+        let record_files = build_record_files(1);
+        let fees = Fees::storage_fees(1, record_files[0].size) + TOKENS_RECORD_FEE;
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ISSUER_ID1, fees + 1));
+
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, build_record_id(), build_record_description(), record_files, false));
+
+        assert_eq!(LogionLoc::fee_allowance((LOC_ID, LOC_REQUESTER_ID, ISSUER_ID1)), Some(1));
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_with_invalid_link_submitter() {
+fn it_fails_adding_tokens_record_when_insufficient_fee_allowance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let link = LocLinkParams {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
-        };
-        assert_err!(
-            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link ]))),
-            Error::<Test>::CannotSubmit
-        );
+        create_closed_collection_with_selected_issuer();
+        let record_files = build_record_files(1);
+        let fees = Fees::storage_fees(1, record_files[0].size) + TOKENS_RECORD_FEE;
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ISSUER_ID1, fees - 1));
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, build_record_id(), build_record_description(), record_files, false), Error::<Test>::InsufficientFeeAllowance);
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_applies_storage_fees_on_transaction_creation() {
+fn it_decrements_fee_allowance_when_adding_file_charged_to_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let fees = Fees::storage_fees(1, FILE_SIZE);
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), fees - 1));
         let file = FileParams {
             hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: FILE_SIZE,
         };
-        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), 0u128, ItemsParams::only_files(Vec::from([ file.clone() ]))));
-        let fees = Fees::only_storage(1, 4);
-        fees.assert_balances_events(snapshot);
+
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::InsufficientFeeAllowance);
+        assert_ok!(LogionLoc::grant_fee_allowance(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), fees));
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()));
+        assert_eq!(LogionLoc::fee_allowance((LOC_ID, LOC_REQUESTER_ID, legal_officer_id(1))), Some(0));
     });
 }
 
-#[test]
-fn it_applies_storage_fees_on_identity_creation() {
-    new_test_ext().execute_with(|| {
+fn nominated_and_select_issuer(loc_id: u32) {
+    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), loc_id, ISSUER_ID1, true));
+}
+
+#[test]
+fn it_adds_file_on_polkadot_transaction_loc_when_caller_is_requester_and_submitter_is_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        nominated_and_select_issuer(LOC_ID);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_file_on_polkadot_transaction_loc_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(2)),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()), Error::<Test>::CannotSubmit);
+    });
+}
+
+#[test]
+fn it_fails_adding_metadata_on_logion_identity_loc_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(2)),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+    });
+}
+
+#[test]
+fn it_adds_metadata_on_polkadot_transaction_loc_when_submitter_is_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        nominated_and_select_issuer(LOC_ID);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+    });
+}
+
+#[test]
+fn it_fails_adding_metadata_on_polkadot_transaction_loc_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(2)),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+    });
+}
+
+#[test]
+fn it_creates_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+
+        let sponsorship = LogionLoc::sponsorship(sponsorship_id).unwrap();
+        assert_eq!(sponsorship.legal_officer, legal_officer_id(1));
+        assert_eq!(sponsorship.sponsor, SPONSOR_ID);
+        assert_eq!(sponsorship.sponsored_account, sponsored_account);
+        assert_eq!(sponsorship.loc_id, None);
+    });
+}
+
+#[test]
+fn it_fails_creating_sponsorship_with_duplicate_id() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+		let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_err!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)), Error::<Test>::AlreadyExists);
+    });
+}
+
+#[test]
+fn it_withdraws_unused_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert!(LogionLoc::sponsorship(sponsorship_id).is_some());
+
+        assert_ok!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id));
+
+        assert!(LogionLoc::sponsorship(sponsorship_id).is_none());
+    });
+}
+
+#[test]
+fn it_creates_ethereum_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_account_id = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(ethereum_address));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, legal_officers());
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_account_id.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: legal_officer_id(1),
+            requester: OtherAccount(requester_account_id.clone()),
+            metadata_count: 0,
+            files_count: 0,
+            closed: false,
+            loc_type: LocType::Identity,
+            links_count: 0,
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: None,
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: Some(sponsorship_id),
+            value_fee: 0,
+            legal_fee: ID_LOC_DEFAULT_LEGAL_FEE,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
+            imported: false,
+            created_on: Some(1),
+            closed_on: None,
+            voided_on: None,
+        }));
+		assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().loc_id, Some(LOC_ID));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+
+        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Other);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_creates_polkadot_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: legal_officer_id(1),
+            requester: Account(LOC_REQUESTER_ID),
+            metadata_count: 0,
+            files_count: 0,
+            closed: false,
+            loc_type: LocType::Identity,
+            links_count: 0,
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: None,
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: None,
+            value_fee: 0,
+            legal_fee: ID_LOC_DEFAULT_LEGAL_FEE,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
+            imported: false,
+            created_on: Some(1),
+            closed_on: None,
+            voided_on: None,
+        }));
+		let loc_ids = BoundedVec::try_from(vec![LOC_ID]).expect("Failed to create expected BoundedVec");
+        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID), Some(loc_ids));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+
+        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Other);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_creating_ethereum_identity_loc_if_duplicate_loc_id() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_address = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE), Error::<Test>::AlreadyExists);
+    });
+}
+
+#[test]
+fn it_fails_creating_several_ethereum_identity_loc_with_single_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_address = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, requester_address.clone(), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE), Error::<Test>::CannotLinkToSponsorship);
+    });
+}
+
+#[test]
+fn it_fails_withdrawing_used_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let requester_address = OtherAccountId::Ethereum(beneficiary);
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address, sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+
+        assert_err!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id), Error::<Test>::AlreadyUsed);
+    });
+}
+
+#[test]
+fn it_adds_metadata_when_submitter_is_ethereum_requester() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_address = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(requester_address);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_address, sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: sponsored_account,
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_adds_file_when_submitter_is_ethereum_requester() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let requester = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(requester));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, OtherAccountId::Ethereum(requester), sponsorship_id, ID_LOC_DEFAULT_LEGAL_FEE));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Other(OtherAccountId::Ethereum(requester)),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, legal_officers());
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, file.clone()));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_with_token_with_zero_issuance() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        let collection_item_token = CollectionItemToken {
+            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+            token_issuance: 0,
+        };
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()), Error::<Test>::BadTokenIssuance);
+    });
+}
+
+#[test]
+fn it_reserves_value_fees() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_fee = 2000 * ONE_LGNT;
+        let value_fee = 100;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: legal_officer_id(1),
+            requester: LOC_REQUESTER,
+            metadata_count: 0,
+            files_count: 0,
+            closed: false,
+            loc_type: LocType::Collection,
+            links_count: 0,
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: Some(10),
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: None,
+            value_fee,
+            legal_fee: OTHER_LOC_DEFAULT_LEGAL_FEE,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
+            imported: false,
+            created_on: Some(1),
+            closed_on: None,
+            voided_on: None,
+        }));
+
+        let expected_free_balance = INITIAL_BALANCE.saturating_sub(legal_fee).saturating_sub(value_fee);
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), expected_free_balance);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), value_fee);
+    });
+}
+
+#[test]
+fn it_captures_value_fees() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let value_fee = 100;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        let previous_balances = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let fees = Fees {
+            certificate_fees: 0,
+            fee_beneficiary: None,
+            legal_fees: 0,
+            storage_fees: 0,
+            value_fee,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
+        };
+        fees.assert_balances_events(previous_balances);
+    });
+}
+
+#[test]
+fn it_frees_value_fees_community_treasury_on_void() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let value_fee = 100;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        let legal_fee = 2000 * ONE_LGNT;
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), INITIAL_BALANCE.saturating_sub(legal_fee));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ValueFeeUnreserved(LOC_REQUESTER_ID, value_fee)));
+    });
+}
+
+#[test]
+fn it_refunds_legal_fee_on_void() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let value_fee = 100;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void_with_refund(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        let refunded = CollectionLocLegalFeeDistributionKey::get().loc_owner_percent * OTHER_LOC_DEFAULT_LEGAL_FEE;
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), INITIAL_BALANCE.saturating_sub(OTHER_LOC_DEFAULT_LEGAL_FEE - refunded));
+        assert_eq!(<Test as Config>::Currency::free_balance(legal_officer_id(1)), INITIAL_BALANCE);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeRefunded(LOC_REQUESTER_ID, refunded)));
+    });
+}
+
+#[test]
+fn it_refunds_nothing_on_void_if_owner_got_no_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::make_void_with_refund(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), INITIAL_BALANCE.saturating_sub(ID_LOC_DEFAULT_LEGAL_FEE));
+        assert_eq!(<Test as Config>::Currency::free_balance(legal_officer_id(1)), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn it_refunds_nothing_on_void_of_imported_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::import_loc(
+            RuntimeOrigin::root(),
+            LOC_ID,
+            LOC_REQUESTER,
+            legal_officer_id(1),
+            LocType::Transaction,
+            Items { metadata: Vec::new(), files: Vec::new(), links: Vec::new() },
+            None,
+            None,
+            false,
+            0,
+            OTHER_LOC_DEFAULT_LEGAL_FEE,
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+            false,
+        ));
+        assert_ok!(LogionLoc::make_void_with_refund(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        assert!(LogionLoc::loc(LOC_ID).unwrap().void_info.is_some());
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), INITIAL_BALANCE);
+        assert_eq!(<Test as Config>::Currency::free_balance(legal_officer_id(1)), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn it_fails_refunding_legal_fee_on_void_closed() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        assert_err!(LogionLoc::make_void_with_refund(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID), Error::<Test>::CannotRefundClosedLoc);
+    });
+}
+
+#[test]
+fn it_does_not_free_value_fees_community_treasury_on_void_closed() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let value_fee = 100;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), None, Some(10), false, value_fee, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID, None, false));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), OTHER_LOC_ID));
+
+        let legal_fee = 2000 * ONE_LGNT;
+        let expected_free_balance = INITIAL_BALANCE.saturating_sub(2 * legal_fee).saturating_sub(2 * value_fee);
+        assert_eq!(<Test as Config>::Currency::free_balance(LOC_REQUESTER_ID), expected_free_balance);
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), value_fee);
+    });
+}
+
+#[test]
+fn it_creates_transaction_loc_with_initial_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_duplicate_initial_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata1 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let metadata2 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata1.clone(), metadata2.clone() ]))),
+            Error::<Test>::DuplicateLocMetadata
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_not_identified_requester() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		assert_err!(
+            LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()),
+            Error::<Test>::AccountNotIdentified
+        );
+	});
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_invalid_metadata_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_transaction_loc_with_initial_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file.clone() ]))));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_duplicate_initial_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file1 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test 1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        let file2 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test 2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file1, file2 ]))),
+            Error::<Test>::DuplicateLocFile
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_invalid_file_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: 4,
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_transaction_loc_with_initial_links() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::locs_linking_to(&OTHER_LOC_ID), Ok(Vec::from([LOC_ID])));
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_initial_links_if_not_found() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))),
+            Error::<Test>::LinkedLocNotFound
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_duplicate_initial_links() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link1 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let link2 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link1, link2 ]))),
+            Error::<Test>::DuplicateLocLink
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_transaction_loc_with_invalid_link_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(
+            create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_identity_loc_with_initial_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_to_create_too_much_identity_locs() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+		for i in 0u32..MaxAccountLocs::get() {
+			assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), i, legal_officer_id(1), 0, ItemsParams::empty()));
+		}
+		assert_err!(
+			LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), MaxAccountLocs::get(), legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()),
+			Error::<Test>::AccountLocsTooMuchData
+		);
+    });
+}
+
+#[test]
+fn it_fails_to_create_too_much_transaction_locs() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), 0);
+		for i in 1u32..MaxAccountLocs::get() {
+			assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), i, legal_officer_id(1), 0, ItemsParams::empty()));
+		}
+		assert_err!(
+			LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), MaxAccountLocs::get(), legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()),
+			Error::<Test>::AccountLocsTooMuchData
+		);
+    });
+}
+
+#[test]
+fn it_fails_to_create_too_much_collection_locs() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), 0);
+		for i in 1u32..MaxAccountLocs::get() {
+			assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), i, legal_officer_id(1), None, Some(10), false, 0, 0, 0, 0, ItemsParams::empty()));
+		}
+		assert_err!(
+			LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), MaxAccountLocs::get(), legal_officer_id(1), None, Some(10), false, 0, 0, 0, 0, ItemsParams::empty()),
+			Error::<Test>::AccountLocsTooMuchData
+		);
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_duplicate_initial_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata1 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let metadata2 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata1.clone(), metadata2.clone() ]))),
+            Error::<Test>::DuplicateLocMetadata
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_invalid_metadata_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_identity_loc_with_initial_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file.clone() ]))));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_duplicate_initial_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file1 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test 1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        let file2 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test 2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file1, file2 ]))),
+            Error::<Test>::DuplicateLocFile
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_invalid_file_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: 4,
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_files(Vec::from([ file ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_identity_loc_with_initial_links() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_initial_links_if_not_found() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link.clone() ]))),
+            Error::<Test>::LinkedLocNotFound
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_duplicate_initial_links() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link1 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let link2 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link1, link2 ]))),
+            Error::<Test>::DuplicateLocLink
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_identity_loc_with_invalid_link_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(
+            LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::only_links(Vec::from([ link ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_collection_loc_with_initial_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))));
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap(), expected_metadata(metadata, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_duplicate_initial_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata1 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let metadata2 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_metadata(Vec::from([ metadata1.clone(), metadata2.clone() ]))),
+            Error::<Test>::DuplicateLocMetadata
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_invalid_metadata_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_metadata(Vec::from([ metadata.clone() ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_collection_loc_with_initial_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_files(Vec::from([ file.clone() ]))));
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).unwrap(), expected_file(&file, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_duplicate_initial_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file1 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test 1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        let file2 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test 2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_files(Vec::from([ file1, file2 ]))),
+            Error::<Test>::DuplicateLocFile
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_invalid_file_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+            size: 4,
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_files(Vec::from([ file ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_creates_collection_loc_with_initial_links() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(1), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link.clone() ]))));
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).unwrap(), expected_link(&link, NOT_ACKNOWLEDGED, NOT_ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_initial_links_if_not_found() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link.clone() ]))),
+            Error::<Test>::LinkedLocNotFound
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_duplicate_initial_links() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link1 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        let link2 = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link1, link2 ]))),
+            Error::<Test>::DuplicateLocLink
+        );
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_with_invalid_link_submitter() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let link = LocLinkParams {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_err!(
+            create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::only_links(Vec::from([ link ]))),
+            Error::<Test>::CannotSubmit
+        );
+    });
+}
+
+#[test]
+fn it_applies_storage_fees_on_transaction_creation() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), 0u128, ItemsParams::only_files(Vec::from([ file.clone() ]))));
+        let fees = Fees::only_storage(1, 4);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_applies_storage_fees_on_identity_creation() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), 0u128, ItemsParams::only_files(Vec::from([ file.clone() ]))));
+        let fees = Fees::only_storage(1, 4);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_applies_storage_fees_on_collection_creation() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"Test".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: 4,
+        };
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, 0u128, 0, 0, ItemsParams::only_files(Vec::from([ file ]))));
+        let fees = Fees::only_storage(1, 4);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_applies_collection_item_fee() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let collection_item_fee: Balance = 5 * ONE_LGNT;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, collection_item_fee, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
+        let fees = Fees::only_collection_item(collection_item_fee, Beneficiary::LegalOfficer(legal_officer_id(1)));
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_to_add_collection_item_when_insufficient_funds() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let collection_item_fee: Balance = INITIAL_BALANCE;
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, collection_item_fee, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()), Error::<Test>::InsufficientFunds);
+    });
+}
+
+#[test]
+fn it_fails_to_add_tokens_record_when_insufficient_funds() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        let storage_fees = Fees::storage_fees(1, record_files[0].size);
+        set_balance(LOC_REQUESTER_ID, 2 * storage_fees); // Requester can pay storage but not tokens record
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, record_description.clone(), record_files.clone(), false), Error::<Test>::InsufficientFunds);
+    });
+}
+
+#[test]
+fn it_routes_storage_fees() {
+    for loc_type in LocType::ALL {
+        assert_eq!(LogionLoc::fee_distribution_key(FeeKind::Storage, loc_type), FileStorageFeeDistributionKey::get());
+    }
+}
+
+#[test]
+fn it_routes_legal_fees() {
+    assert_eq!(LogionLoc::fee_distribution_key(FeeKind::Legal, LocType::Identity), IdentityLocLegalFeeDistributionKey::get());
+    assert_eq!(LogionLoc::fee_distribution_key(FeeKind::Legal, LocType::Transaction), TransactionLocLegalFeeDistributionKey::get());
+    assert_eq!(LogionLoc::fee_distribution_key(FeeKind::Legal, LocType::Collection), CollectionLocLegalFeeDistributionKey::get());
+}
+
+#[test]
+fn it_routes_certificate_fees() {
+    for loc_type in LocType::ALL {
+        assert_eq!(LogionLoc::fee_distribution_key(FeeKind::Certificate, loc_type), CertificateFeeDistributionKey::get());
+    }
+}
+
+#[test]
+fn it_routes_value_fees() {
+    for loc_type in LocType::ALL {
+        assert_eq!(LogionLoc::fee_distribution_key(FeeKind::Value, loc_type), ValueFeeDistributionKey::get());
+    }
+}
+
+#[test]
+fn it_routes_collection_item_fees() {
+    for loc_type in LocType::ALL {
+        assert_eq!(LogionLoc::fee_distribution_key(FeeKind::CollectionItem, loc_type), CollectionItemFeeDistributionKey::get());
+    }
+}
+
+#[test]
+fn it_routes_tokens_record_fees() {
+    for loc_type in LocType::ALL {
+        assert_eq!(LogionLoc::fee_distribution_key(FeeKind::TokensRecord, loc_type), TokensRecordFeeDistributionKey::get());
+    }
+}
+
+#[test]
+fn it_uses_distinct_keys_for_each_fee_route() {
+    let keys = [
+        FileStorageFeeDistributionKey::get(),
+        CertificateFeeDistributionKey::get(),
+        ValueFeeDistributionKey::get(),
+        CollectionItemFeeDistributionKey::get(),
+        TokensRecordFeeDistributionKey::get(),
+        IdentityLocLegalFeeDistributionKey::get(),
+        TransactionLocLegalFeeDistributionKey::get(),
+        CollectionLocLegalFeeDistributionKey::get(),
+    ];
+    for (i, key) in keys.iter().enumerate() {
+        assert!(keys[i + 1..].iter().all(|other| other != key));
+    }
+}
+
+#[test]
+fn it_has_valid_fee_routes() {
+    <LogionLoc as Hooks<BlockNumberFor<Test>>>::integrity_test();
+}
+
+#[test]
+fn it_distributes_collection_loc_legal_fee_with_collection_key() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        let legal_officer_balance = <Test as Config>::Currency::free_balance(legal_officer_id(1));
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+
+        let fees = Fees::only_legal(OTHER_LOC_DEFAULT_LEGAL_FEE, Beneficiary::LegalOfficer(legal_officer_id(1)));
+        fees.assert_balances_events(snapshot);
+        let expected_share = CollectionLocLegalFeeDistributionKey::get().loc_owner_percent * OTHER_LOC_DEFAULT_LEGAL_FEE;
+        assert_eq!(<Test as Config>::Currency::free_balance(legal_officer_id(1)), legal_officer_balance + expected_share);
+    });
+}
+
+fn create_closed_polkadot_identity_loc(origin: OriginFor<Test>, identity_loc: LocId) {
+	assert_ok!(LogionLoc::create_polkadot_identity_loc(origin, identity_loc, legal_officer_id(1), 0, ItemsParams::empty()));
+	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), identity_loc, None, false));
+}
+
+fn create_identity_and_transaction_loc(
+	origin: OriginFor<Test>,
+	loc_id: LocId,
+	legal_officer: AccountId,
+	legal_fee: Balance,
+	items: ItemsParamsOf<Test>,
+) -> DispatchResultWithPostInfo {
+	create_closed_polkadot_identity_loc(origin.clone(), REQUESTER_IDENTITY_LOC_ID);
+	LogionLoc::create_polkadot_transaction_loc(origin, loc_id, legal_officer, legal_fee, items)
+}
+
+fn create_identity_and_collection_loc(
+	origin: OriginFor<Test>,
+	loc_id: LocId,
+	legal_officer: AccountId,
+	collection_last_block_submission: Option<BlockNumberFor<Test>>,
+	collection_max_size: Option<u32>,
+	collection_can_upload: bool,
+	value_fee: Balance,
+	legal_fee: Balance,
+	collection_item_fee: Balance,
+	tokens_record_fee: Balance,
+	items: ItemsParamsOf<Test>,
+) -> DispatchResultWithPostInfo {
+	create_closed_polkadot_identity_loc(origin.clone(), REQUESTER_IDENTITY_LOC_ID);
+	LogionLoc::create_collection_loc(origin, loc_id, legal_officer, collection_last_block_submission, collection_max_size, collection_can_upload, value_fee, legal_fee, collection_item_fee, tokens_record_fee, items)
+}
+
+#[test]
+fn it_sets_invited_contributor_selection() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
+
+		assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), None);
+		assert_ok!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true));
+		assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), Some(()));
+		assert_ok!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, false));
+		assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), None);
+	});
+}
+
+#[test]
+fn it_fails_to_set_invited_contributor_selection_on_non_existent_loc() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
+		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::NotFound);
+	});
+}
+
+#[test]
+fn it_fails_to_set_invited_contributor_selection_when_not_requester() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
+		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_fails_to_set_invited_contributor_selection_when_not_identified() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
+		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::AccountNotIdentified);
+	});
+}
+
+#[test]
+fn it_fails_to_set_invited_contributor_selection_on_void_loc() {
+	new_test_ext().execute_with(|| {
+		setup_default_balances();
+		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
+		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
+		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::CannotMutateVoid);
+	});
+}
+
+#[test]
+fn it_adds_tokens_record_as_invited_contributor() {
+	it_adds_tokens_record(INVITED_CONTRIBUTOR_ID);
+}
+
+#[test]
+fn it_fails_importing_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let items = build_items(false);
+
+        assert_err!(LogionLoc::import_loc(
+            RuntimeOrigin::signed(LOC_REQUESTER_ID),
+            LOC_ID,
+            LOC_REQUESTER,
+            legal_officer_id(1),
+            LocType::Transaction,
+            items,
+            None,
+            None,
+            false,
+            0,
+            0,
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+            false,
+        ), BadOrigin);
+    });
+}
+
+fn build_items(closed: bool) -> ItemsOf<Test> {
+    let metadata = MetadataItem {
+        name: sha256(&vec![1, 2, 3]),
+        value: sha256(&vec![4, 5, 6]),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        acknowledged_by_owner: closed,
+        acknowledged_by_verified_issuer: closed,
+        submitted_on: None,
+        acknowledged_by_owner_on: None,
+        acknowledged_by_verified_issuer_on: None,
+    };
+    let file = File {
+        hash: sha256(&"test".as_bytes().to_vec()),
+        nature: sha256(&"Test".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        size: 4,
+        acknowledged_by_owner: closed,
+        acknowledged_by_verified_issuer: closed,
+        submitted_on: None,
+        acknowledged_by_owner_on: None,
+        acknowledged_by_verified_issuer_on: None,
+    };
+    let link = LocLink {
+        id: OTHER_LOC_ID,
+        nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        acknowledged_by_owner: closed,
+        acknowledged_by_verified_issuer: closed,
+        submitted_on: None,
+        acknowledged_by_owner_on: None,
+        acknowledged_by_verified_issuer_on: None,
+    };
+    Items {
+        metadata: Vec::from([ metadata.clone() ]),
+        files: Vec::from([ file.clone() ]),
+        links: Vec::from([ link.clone() ]),
+    }
+}
+
+#[test]
+fn it_imports_open_transaction_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Transaction,
+            false,
+            false,
+        );
+    });
+}
+
+fn test_import_loc(
+    requester: RequesterOf<Test>,
+    loc_type: LocType,
+    closed: bool,
+    void: bool,
+) {
+    let items = build_items(closed);
+
+    let mut void_info = None;
+    let mut replacer_of = None;
+    if void {
+        void_info = Some(LocVoidInfo {
+            replacer: Some(OTHER_LOC_ID),
+        });
+    } else {
+        replacer_of = Some(OTHER_LOC_ID);
+    }
+
+    let mut seal = None;
+    if loc_type == LocType::Identity && closed {
+        seal = Some(sha256(&vec![42]));
+    }
+
+    let mut legal_fee = 2000;
+    if loc_type == LocType::Identity {
+        legal_fee = 160;
+    }
+
+    let mut collection_last_block_submission = None;
+    let mut collection_max_size = None;
+    let mut collection_can_upload = false;
+    let mut value_fee = 0;
+    let mut collection_item_fee = 0;
+    let mut tokens_record_fee = 0;
+    if loc_type == LocType::Collection {
+        collection_last_block_submission = Some(424242);
+        collection_max_size = Some(200);
+        collection_can_upload = true;
+        value_fee = 200;
+        collection_item_fee = 10;
+        tokens_record_fee = 20;
+    }
+
+    let mut sponsorship_id = None;
+    match requester {
+        OtherAccount(_) => sponsorship_id = Some(1u32),
+        _ => {},
+    }
+
+    assert_ok!(LogionLoc::import_loc(
+        RuntimeOrigin::root(),
+        LOC_ID,
+        requester.clone(),
+        legal_officer_id(1),
+        loc_type,
+        items.clone(),
+        collection_last_block_submission,
+        collection_max_size,
+        collection_can_upload,
+        value_fee,
+        legal_fee,
+        collection_item_fee,
+        tokens_record_fee,
+        sponsorship_id,
+        seal,
+        void_info.clone(),
+        replacer_of.clone(),
+        closed,
+    ));
+    System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocImported {
+        0: LOC_ID,
+    }));
+    assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+        owner: legal_officer_id(1),
+        requester: requester.clone(),
+        metadata_count: items.metadata.len() as u32,
+        files_count: items.files.len() as u32,
+        closed,
+        loc_type,
+        links_count: items.links.len() as u32,
+        void_info,
+        replacer_of,
+        collection_last_block_submission,
+        collection_max_size,
+        collection_can_upload,
+        seal,
+        sponsorship_id,
+        value_fee,
+        legal_fee,
+        collection_item_fee,
+        tokens_record_fee,
+        imported: true,
+        created_on: None,
+        closed_on: None,
+        voided_on: None,
+    }));
+    for item in items.metadata.iter() {
+        assert_eq!(LogionLoc::loc_metadata(LOC_ID, item.name).as_ref(), Some(item));
+    }
+    for file in items.files.iter() {
+        assert_eq!(LogionLoc::loc_file(LOC_ID, file.hash).as_ref(), Some(file));
+    }
+    for link in items.links.iter() {
+        assert_eq!(LogionLoc::loc_link(LOC_ID, link.id).as_ref(), Some(link));
+    }
+    assert_eq!(LogionLoc::locs_linking_to(&OTHER_LOC_ID), Ok(Vec::from([LOC_ID])));
+    match requester {
+        Account(requester_account_id) => assert!(
+            LogionLoc::account_locs(requester_account_id).is_some()
+        ),
+        _ => {},
+    }
+}
+
+#[test]
+fn it_imports_closed_transaction_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Transaction,
+            true,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_imports_void_transaction_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Transaction,
+            true,
+            true,
+        );
+    });
+}
+
+#[test]
+fn it_imports_open_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Identity,
+            false,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_imports_closed_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Identity,
+            true,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_imports_void_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Identity,
+            true,
+            true,
+        );
+    });
+}
+
+#[test]
+fn it_imports_open_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Collection,
+            false,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_imports_closed_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Collection,
+            true,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_imports_void_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            LOC_REQUESTER,
+            LocType::Collection,
+            true,
+            true,
+        );
+    });
+}
+
+#[test]
+fn it_imports_open_identity_loc_other_account() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let ethereum_account = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let requester = OtherAccountId::Ethereum(ethereum_account);
+        test_import_loc(
+            OtherAccount(requester),
+            LocType::Identity,
+            false,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_imports_open_identity_loc_logion() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        test_import_loc(
+            Requester::None,
+            LocType::Identity,
+            false,
+            false,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_item_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(
+            LogionLoc::import_collection_item(
+                RuntimeOrigin::signed(LOC_REQUESTER_ID),
+                LOC_ID,
+                collection_item_id,
+                collection_item_description.clone(),
+                vec![],
+                None,
+                false,
+                Vec::new()
+            ),
+            BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn it_imports_valid_item() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::import_collection_item(
+            RuntimeOrigin::root(),
+            LOC_ID,
+            collection_item_id,
+            collection_item_description.clone(),
+            vec![],
+            None,
+            false,
+            Vec::new()
+        ));
+        assert_eq!(
+            LogionLoc::collection_items(LOC_ID, collection_item_id),
+            Some(CollectionItem {
+                description: collection_item_description,
+                files: BoundedVec::new(),
+                token: None,
+                restricted_delivery: false,
+                terms_and_conditions: BoundedVec::new(),
+                imported: true,
+            }
+        ));
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+    });
+}
+
+#[test]
+fn it_fails_import_item_if_duplicate_key() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::import_collection_item(RuntimeOrigin::root(), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
+        assert_err!(LogionLoc::import_collection_item(RuntimeOrigin::root(), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
+    });
+}
+
+#[test]
+fn it_fails_import_item_with_duplicate_hash() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
+        let collection_item_files = vec![
+            CollectionItemFile {
+                name: sha256(&"picture.png".as_bytes().to_vec()),
+                content_type: sha256(&"image/png".as_bytes().to_vec()),
+                hash: same_hash,
+                size: 123456,
+            },
+            CollectionItemFile {
+                name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: same_hash,
+                size: 789,
+            },
+        ];
+        assert_err!(LogionLoc::import_collection_item(RuntimeOrigin::root(), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::DuplicateFile);
+    });
+}
+
+#[test]
+fn it_fails_import_missing_token() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(
+            LogionLoc::import_collection_item(
+                RuntimeOrigin::root(),
+                LOC_ID,
+                collection_item_id,
+                collection_item_description.clone(),
+                vec![],
+                None,
+                true,
+                Vec::new()
+            ),
+            Error::<Test>::MissingToken,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_missing_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(
+            LogionLoc::import_collection_item(
+                RuntimeOrigin::root(),
+                LOC_ID,
+                collection_item_id,
+                collection_item_description.clone(),
+                vec![],
+                Some(CollectionItemToken {
+                    token_issuance: 1,
+                    token_id: sha256(&"some_id".as_bytes().to_vec()),
+                    token_type: sha256(&"some_type".as_bytes().to_vec()),
+                }),
+                true,
+                Vec::new()
+            ),
+            Error::<Test>::MissingFiles,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_record_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let record_id = sha256(&"record-id".as_bytes().to_vec());
+        let record_description = sha256(&"record-description".as_bytes().to_vec());
+        let file_hash = sha256(&"file content".as_bytes().to_vec());
+        assert_err!(
+            LogionLoc::import_tokens_record(
+                RuntimeOrigin::signed(LOC_REQUESTER_ID),
+                LOC_ID,
+                record_id,
+                record_description.clone(),
+                vec![
+                    TokensRecordFile {
+                        name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                        content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                        hash: file_hash,
+                        size: 789,
+                    },
+                ],
+                LOC_REQUESTER_ID,
+            ),
+            BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn it_imports_valid_record() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let record_id = sha256(&"record-id".as_bytes().to_vec());
+        let record_description = sha256(&"record-description".as_bytes().to_vec());
+        let file_hash = sha256(&"file content".as_bytes().to_vec());
+        let files = vec![
+            TokensRecordFile {
+                name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: file_hash,
+                size: 789,
+            },
+        ];
+        assert_ok!(LogionLoc::import_tokens_record(
+            RuntimeOrigin::root(),
+            LOC_ID,
+            record_id,
+            record_description.clone(),
+            files.clone(),
+            LOC_REQUESTER_ID,
+        ));
+        assert_eq!(
+            LogionLoc::tokens_records(LOC_ID, record_id),
+            Some(TokensRecord {
+                description: record_description,
+                files: BoundedVec::try_from(files).unwrap(),
+                submitter: LOC_REQUESTER_ID,
+                imported: true,
+            }
+        ));
+    });
+}
+
+#[test]
+fn it_fails_import_record_if_duplicate_key() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let record_id = sha256(&"record-id".as_bytes().to_vec());
+        let record_description = sha256(&"record-description".as_bytes().to_vec());
+        let file_hash = sha256(&"file content".as_bytes().to_vec());
+        assert_ok!(LogionLoc::import_tokens_record(RuntimeOrigin::root(), LOC_ID,
+                record_id,
+                record_description.clone(),
+                vec![
+                    TokensRecordFile {
+                        name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                        content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                        hash: file_hash,
+                        size: 789,
+                    },
+                ],
+                LOC_REQUESTER_ID,
+        ));
+        assert_err!(LogionLoc::import_tokens_record(
+                RuntimeOrigin::root(),
+                LOC_ID,
+                record_id,
+                record_description.clone(),
+                vec![
+                    TokensRecordFile {
+                        name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                        content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                        hash: file_hash,
+                        size: 789,
+                    },
+                ],
+                LOC_REQUESTER_ID,
+            ),
+            Error::<Test>::TokensRecordAlreadyExists,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_record_with_duplicate_hash() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let record_id = sha256(&"record-id".as_bytes().to_vec());
+        let record_description = sha256(&"record-description".as_bytes().to_vec());
+        let file_hash = sha256(&"file content".as_bytes().to_vec());
+        let files = vec![
+            TokensRecordFile {
+                name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: file_hash,
+                size: 789,
+            },
+            TokensRecordFile {
+                name: sha256(&"doc2.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: file_hash,
+                size: 789,
+            },
+        ];
+        assert_err!(LogionLoc::import_tokens_record(RuntimeOrigin::root(), LOC_ID,
+                record_id,
+                record_description.clone(),
+                files,
+                LOC_REQUESTER_ID,
+            ),
+            Error::<Test>::DuplicateFile,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_must_upload() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        let record_id = sha256(&"record-id".as_bytes().to_vec());
+        let record_description = sha256(&"record-description".as_bytes().to_vec());
+        assert_err!(
+            LogionLoc::import_tokens_record(
+                RuntimeOrigin::root(),
+                LOC_ID,
+                record_id,
+                record_description.clone(),
+                vec![],
+                LOC_REQUESTER_ID,
+            ),
+            Error::<Test>::MustUpload,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_invited_contributor_selection_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        assert_err!(
+            LogionLoc::import_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID),
+            BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn it_imports_invited_contributor_selection() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        assert_ok!(LogionLoc::import_invited_contributor_selection(RuntimeOrigin::root(), LOC_ID, INVITED_CONTRIBUTOR_ID));
+        assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), Some(()));
+    });
+}
+
+#[test]
+fn it_fails_reimport_invited_contributor_selection() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+
+        assert_ok!(LogionLoc::import_invited_contributor_selection(RuntimeOrigin::root(), LOC_ID, INVITED_CONTRIBUTOR_ID));
+        assert_err!(
+            LogionLoc::import_invited_contributor_selection(RuntimeOrigin::root(), LOC_ID, INVITED_CONTRIBUTOR_ID),
+            Error::<Test>::AlreadyExists,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_an_issuer_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_officer = legal_officer_id(1);
+
+        assert_err!(
+            LogionLoc::import_verified_issuer(
+                RuntimeOrigin::signed(legal_officer),
+                legal_officer,
+                ISSUER_ID1,
+                ISSUER1_IDENTITY_LOC_ID,
+            ),
+            BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn it_imports_an_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_officer = legal_officer_id(1);
+
+        assert_ok!(LogionLoc::import_verified_issuer(
+            RuntimeOrigin::root(),
+            legal_officer,
+            ISSUER_ID1,
+            ISSUER1_IDENTITY_LOC_ID,
+        ));
+
+        assert_eq!(
+            LogionLoc::verified_issuers(legal_officer, ISSUER_ID1),
+            Some(VerifiedIssuer {
+                identity_loc: ISSUER1_IDENTITY_LOC_ID,
+                imported: true,
+            }),
+        );
+    });
+}
+
+#[test]
+fn it_fails_imports_an_issuer_twice() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_officer = legal_officer_id(1);
+
+        assert_ok!(LogionLoc::import_verified_issuer(
+            RuntimeOrigin::root(),
+            legal_officer,
+            ISSUER_ID1,
+            ISSUER1_IDENTITY_LOC_ID,
+        ));
+
+        assert_err!(
+            LogionLoc::import_verified_issuer(
+                RuntimeOrigin::root(),
+                legal_officer,
+                ISSUER_ID1,
+                ISSUER1_IDENTITY_LOC_ID,
+            ),
+            Error::<Test>::AlreadyExists,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_an_issuer_selection_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_officer = legal_officer_id(1);
+
+        assert_err!(
+            LogionLoc::import_verified_issuer_selection(
+                RuntimeOrigin::signed(LOC_REQUESTER_ID),
+                LOC_ID,
+                ISSUER_ID1,
+                legal_officer,
+            ),
+            BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn it_imports_an_issuer_selection() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_officer = legal_officer_id(1);
+
+        assert_ok!(LogionLoc::import_verified_issuer_selection(
+            RuntimeOrigin::root(),
+            LOC_ID,
+            ISSUER_ID1,
+            legal_officer,
+        ));
+
+        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer, LOC_ID)).is_some());
+    });
+}
+
+#[test]
+fn it_fails_importing_an_issuer_selection_twice() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let legal_officer = legal_officer_id(1);
+
+        assert_ok!(LogionLoc::import_verified_issuer_selection(
+            RuntimeOrigin::root(),
+            LOC_ID,
+            ISSUER_ID1,
+            legal_officer,
+        ));
+        assert_err!(
+            LogionLoc::import_verified_issuer_selection(
+                RuntimeOrigin::root(),
+                LOC_ID,
+                ISSUER_ID1,
+                legal_officer,
+            ),
+            Error::<Test>::AlreadyExists,
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_sponsorship_not_root() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        let legal_officer = legal_officer_id(1);
+        let loc_id = Some(LOC_ID);
+
+        assert_err!(
+            LogionLoc::import_sponsorship(
+                RuntimeOrigin::signed(legal_officer),
+                sponsorship_id,
+                SPONSOR_ID,
+                sponsored_account,
+                legal_officer,
+                loc_id,
+            ),
+            BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn it_imports_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        let legal_officer = legal_officer_id(1);
+        let loc_id = Some(LOC_ID);
+
+        assert_ok!(LogionLoc::import_sponsorship(
+            RuntimeOrigin::root(),
+            sponsorship_id,
+            SPONSOR_ID,
+            sponsored_account,
+            legal_officer,
+            loc_id,
+        ));
+
+        assert_eq!(
+            LogionLoc::sponsorship(sponsorship_id),
+            Some(Sponsorship {
+                sponsor: SPONSOR_ID,
+                sponsored_account,
+                legal_officer,
+                loc_id,
+                imported: true,
+            }),
+        );
+    });
+}
+
+#[test]
+fn it_fails_import_sponsorship_twice() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        let legal_officer = legal_officer_id(1);
+        let loc_id = Some(LOC_ID);
+
+        assert_ok!(LogionLoc::import_sponsorship(
+            RuntimeOrigin::root(),
+            sponsorship_id,
+            SPONSOR_ID,
+            sponsored_account,
+            legal_officer,
+            loc_id,
+        ));
+
+        assert_err!(
+            LogionLoc::import_sponsorship(
+                RuntimeOrigin::root(),
+                sponsorship_id,
+                SPONSOR_ID,
+                sponsored_account,
+                legal_officer,
+                loc_id,
+            ),
+            Error::<Test>::AlreadyExists,
+        );
+    });
+}
+
+#[test]
+fn it_adds_tokens_record_issuer_charged() {
+    it_adds_tokens_record_charging(INVITED_CONTRIBUTOR_ID, INVITED_CONTRIBUTOR_ID, true);
+}
+
+const TEMPLATE_ID: H256 = H256::repeat_byte(42);
+
+fn register_transaction_template() {
+    assert_ok!(LogionLoc::register_template(
+        RuntimeOrigin::signed(legal_officer_id(1)),
+        TEMPLATE_ID,
+        LocType::Transaction,
+        Vec::from([ requester_metadata().name ]),
+        Vec::from([ requester_file().nature ]),
+        Vec::new(),
+        OTHER_LOC_DEFAULT_LEGAL_FEE,
+        0,
+        0,
+        0,
+        None,
+        false,
+    ));
+}
+
+fn register_collection_template() {
+    assert_ok!(LogionLoc::register_template(
+        RuntimeOrigin::signed(legal_officer_id(1)),
+        TEMPLATE_ID,
+        LocType::Collection,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        ONE_LGNT,
+        2 * ONE_LGNT,
+        3,
+        4,
+        Some(10),
+        true,
+    ));
+}
+
+#[test]
+fn it_registers_template() {
+    new_test_ext().execute_with(|| {
+        register_transaction_template();
+
+        let template = LogionLoc::template(TEMPLATE_ID).unwrap();
+        assert_eq!(template.owner, legal_officer_id(1));
+        assert_eq!(template.loc_type, LocType::Transaction);
+        assert_eq!(template.metadata_names.to_vec(), Vec::from([ requester_metadata().name ]));
+        assert_eq!(template.file_natures.to_vec(), Vec::from([ requester_file().nature ]));
+        assert!(template.link_natures.is_empty());
+        assert_eq!(template.legal_fee, OTHER_LOC_DEFAULT_LEGAL_FEE);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::TemplateRegistered(TEMPLATE_ID, legal_officer_id(1))));
+    });
+}
+
+#[test]
+fn it_fails_registering_template_twice() {
+    new_test_ext().execute_with(|| {
+        register_transaction_template();
+
+        assert_err!(
+            LogionLoc::register_template(RuntimeOrigin::signed(legal_officer_id(2)), TEMPLATE_ID, LocType::Identity, Vec::new(), Vec::new(), Vec::new(), 0, 0, 0, 0, None, false),
+            Error::<Test>::TemplateAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn it_fails_registering_template_if_not_legal_officer() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            LogionLoc::register_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), TEMPLATE_ID, LocType::Transaction, Vec::new(), Vec::new(), Vec::new(), 0, 0, 0, 0, None, false),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn it_fails_registering_collection_template_without_max_size() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            LogionLoc::register_template(RuntimeOrigin::signed(legal_officer_id(1)), TEMPLATE_ID, LocType::Collection, Vec::new(), Vec::new(), Vec::new(), 0, 0, 0, 0, None, false),
+            Error::<Test>::CollectionHasNoLimit
+        );
+    });
+}
+
+#[test]
+fn it_fails_registering_transaction_template_with_collection_params() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            LogionLoc::register_template(RuntimeOrigin::signed(legal_officer_id(1)), TEMPLATE_ID, LocType::Transaction, Vec::new(), Vec::new(), Vec::new(), 0, 0, 1, 0, None, false),
+            Error::<Test>::UnexpectedTemplateCollectionParams
+        );
+    });
+}
+
+#[test]
+fn it_creates_collection_loc_from_template() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        register_collection_template();
+
+        assert_ok!(LogionLoc::create_loc_from_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, TEMPLATE_ID, ItemsParams::empty()));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.owner, legal_officer_id(1));
+        assert_eq!(loc.loc_type, LocType::Collection);
+        assert_eq!(loc.legal_fee, ONE_LGNT);
+        assert_eq!(loc.value_fee, 2 * ONE_LGNT);
+        assert_eq!(loc.collection_item_fee, 3);
+        assert_eq!(loc.tokens_record_fee, 4);
+        assert_eq!(loc.collection_last_block_submission, None);
+        assert_eq!(loc.collection_max_size, Some(10));
+        assert!(loc.collection_can_upload);
+        assert_eq!(LogionLoc::template_of_loc(LOC_ID), Some(TEMPLATE_ID));
+    });
+}
+
+#[test]
+fn it_creates_loc_from_template() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        register_transaction_template();
+
+        assert_ok!(LogionLoc::create_loc_from_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, TEMPLATE_ID, ItemsParams::empty()));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.owner, legal_officer_id(1));
+        assert_eq!(loc.loc_type, LocType::Transaction);
+        assert_eq!(loc.legal_fee, OTHER_LOC_DEFAULT_LEGAL_FEE);
+        assert_eq!(LogionLoc::template_of_loc(LOC_ID), Some(TEMPLATE_ID));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated(LOC_ID)));
+    });
+}
+
+#[test]
+fn it_fails_creating_loc_from_unknown_template() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            LogionLoc::create_loc_from_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, TEMPLATE_ID, ItemsParams::empty()),
+            Error::<Test>::TemplateNotFound
+        );
+    });
+}
+
+#[test]
+fn it_fails_closing_loc_from_template_with_missing_items() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        register_transaction_template();
+        assert_ok!(LogionLoc::create_loc_from_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, TEMPLATE_ID, ItemsParams::only_metadata(Vec::from([ requester_metadata() ]))));
+
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true), Error::<Test>::CannotCloseMissingTemplateItems);
+    });
+}
+
+#[test]
+fn it_fails_auto_acknowledging_and_closing_loc_from_template() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        register_transaction_template();
+        assert_ok!(LogionLoc::create_loc_from_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, TEMPLATE_ID, ItemsParams::only_metadata(Vec::from([ requester_metadata() ]))));
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, requester_file()));
+
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, true), Error::<Test>::CannotCloseMissingTemplateItems);
+    });
+}
+
+#[test]
+fn it_closes_loc_from_template_with_required_items() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        register_transaction_template();
+        assert_ok!(LogionLoc::create_loc_from_template(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, TEMPLATE_ID, ItemsParams::only_metadata(Vec::from([ requester_metadata() ]))));
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, requester_file()));
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_metadata().name));
+        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, requester_file().hash));
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        assert!(LogionLoc::loc(LOC_ID).unwrap().closed);
+    });
+}
+
+fn create_co_owned_loc(threshold: u32) {
+    create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+    assert_ok!(LogionLoc::create_polkadot_co_owned_transaction_loc(
+        RuntimeOrigin::signed(LOC_REQUESTER_ID),
+        LOC_ID,
+        legal_officer_id(1),
+        Vec::from([ legal_officer_id(2) ]),
+        threshold,
+        OTHER_LOC_DEFAULT_LEGAL_FEE,
+        ItemsParams::empty(),
+    ));
+}
+
+#[test]
+fn it_creates_co_owned_loc_splitting_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let owner_balance = Balances::free_balance(legal_officer_id(1));
+        let co_owner_balance = Balances::free_balance(legal_officer_id(2));
+
+        create_co_owned_loc(2);
+
+        let co_ownership = LogionLoc::co_ownership(LOC_ID).unwrap();
+        assert_eq!(co_ownership.co_owners.to_vec(), Vec::from([ legal_officer_id(2) ]));
+        assert_eq!(co_ownership.threshold, 2);
+        assert_eq!(Balances::free_balance(legal_officer_id(1)), owner_balance + OTHER_LOC_DEFAULT_LEGAL_FEE / 2);
+        assert_eq!(Balances::free_balance(legal_officer_id(2)), co_owner_balance + OTHER_LOC_DEFAULT_LEGAL_FEE / 2);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeWithdrawn(LOC_REQUESTER_ID, Beneficiary::LegalOfficer(legal_officer_id(1)), OTHER_LOC_DEFAULT_LEGAL_FEE)));
+        assert_eq!(System::events().iter()
+            .filter(|record| matches!(record.event, RuntimeEvent::LogionLoc(crate::Event::LegalFeeWithdrawn(..))))
+            .count(), 1);
+    });
+}
+
+#[test]
+fn it_fails_creating_co_owned_loc_with_invalid_co_owners() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID);
+        let create = |co_owners: Vec<AccountId>, threshold: u32| LogionLoc::create_polkadot_co_owned_transaction_loc(
+            RuntimeOrigin::signed(LOC_REQUESTER_ID),
+            LOC_ID,
+            legal_officer_id(1),
+            co_owners,
+            threshold,
+            OTHER_LOC_DEFAULT_LEGAL_FEE,
+            ItemsParams::empty(),
+        );
+
+        assert_err!(create(Vec::new(), 1), Error::<Test>::InvalidCoOwners);
+        assert_err!(create(Vec::from([ UNAUTHORIZED_CALLER ]), 1), Error::<Test>::InvalidCoOwners);
+        assert_err!(create(Vec::from([ legal_officer_id(1) ]), 1), Error::<Test>::InvalidCoOwners);
+        assert_err!(create(Vec::from([ legal_officer_id(2), legal_officer_id(2) ]), 1), Error::<Test>::InvalidCoOwners);
+        assert_err!(create(Vec::from([ legal_officer_id(2) ]), 0), Error::<Test>::InvalidThreshold);
+        assert_err!(create(Vec::from([ legal_officer_id(2) ]), 3), Error::<Test>::InvalidThreshold);
+    });
+}
+
+#[test]
+fn it_closes_co_owned_loc_when_threshold_reached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, None, false));
+
+        assert!(!LogionLoc::loc(LOC_ID).unwrap().closed);
+        assert!(LogionLoc::owner_approval(LOC_ID, legal_officer_id(2)).is_some());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::OwnerActionApproved(LOC_ID, OwnerAction::Close { seal: None, auto_ack: false }, legal_officer_id(2))));
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, None, false), Error::<Test>::AlreadyApproved);
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+
+        assert!(LogionLoc::loc(LOC_ID).unwrap().closed);
+        assert!(LogionLoc::owner_approval(LOC_ID, legal_officer_id(2)).is_none());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocClosed(LOC_ID)));
+    });
+}
+
+#[test]
+fn it_does_not_close_co_owned_loc_with_approvals_of_different_params() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, Some(H256::repeat_byte(1)), false));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, Some(H256::repeat_byte(2)), false));
+
+        assert!(!LogionLoc::loc(LOC_ID).unwrap().closed);
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, Some(H256::repeat_byte(2)), false));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(loc.closed);
+        assert_eq!(loc.seal, Some(H256::repeat_byte(2)));
+    });
+}
+
+#[test]
+fn it_clears_pending_void_approvals_on_close() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, None, false));
+        assert!(LogionLoc::loc(LOC_ID).unwrap().closed);
+        assert!(LogionLoc::owner_approval(LOC_ID, legal_officer_id(1)).is_none());
+
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID));
+
+        assert!(LogionLoc::loc(LOC_ID).unwrap().void_info.is_none());
+    });
+}
+
+#[test]
+fn it_fails_closing_co_owned_loc_if_not_owner() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(1);
+
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(3)), LOC_ID, None, false), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_voids_co_owned_loc_when_threshold_reached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let owner_balance = Balances::free_balance(legal_officer_id(1));
+        let co_owner_balance = Balances::free_balance(legal_officer_id(2));
+        create_co_owned_loc(2);
+
+        assert_ok!(LogionLoc::make_void_with_refund(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        assert!(LogionLoc::loc(LOC_ID).unwrap().void_info.is_none());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::OwnerActionApproved(LOC_ID, OwnerAction::Void { replacer: None, refund_legal_fee: true }, legal_officer_id(1))));
+
+        assert_ok!(LogionLoc::make_void_with_refund(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID));
+
+        assert!(LogionLoc::loc(LOC_ID).unwrap().void_info.is_some());
+        assert_eq!(Balances::free_balance(legal_officer_id(1)), owner_balance);
+        assert_eq!(Balances::free_balance(legal_officer_id(2)), co_owner_balance);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocVoid(LOC_ID)));
+    });
+}
+
+#[test]
+fn it_lets_co_owner_acknowledge_items() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+        let metadata = requester_metadata();
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, metadata.name));
+
+        assert!(!LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap().acknowledged_by_owner);
+        assert_eq!(LogionLoc::owner_acknowledgement((LOC_ID, LocItemKey::Metadata(metadata.name), legal_officer_id(2))), Some(()));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::Metadata(metadata.name), legal_officer_id(2))));
+        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, metadata.name), Error::<Test>::ItemAlreadyAcknowledged);
+
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name));
+
+        assert!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap().acknowledged_by_owner);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAcknowledged(LOC_ID, LocItemKey::Metadata(metadata.name), legal_officer_id(1))));
+    });
+}
+
+#[test]
+fn it_requires_co_owner_acknowledgement_of_owner_items() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(legal_officer_id(1)),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.clone()));
+
+        assert!(!LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap().acknowledged_by_owner);
+        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, metadata.name), Error::<Test>::ItemAlreadyAcknowledged);
+
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, metadata.name));
+
+        assert!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap().acknowledged_by_owner);
+    });
+}
+
+#[test]
+fn it_lets_single_co_owner_acknowledge_items_with_threshold_of_one() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(1);
+        let metadata = requester_metadata();
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, metadata.name));
+
+        assert!(LogionLoc::loc_metadata(LOC_ID, metadata.name).unwrap().acknowledged_by_owner);
+    });
+}
+
+#[test]
+fn it_transfers_locs_to_other_legal_officer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert!(LogionLoc::owns_locs(&legal_officer_id(1)));
+        assert!(!LogionLoc::owns_locs(&legal_officer_id(2)));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 10, Vec::new()));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, legal_officer_id(2));
+        assert_eq!(LogionLoc::loc(REQUESTER_IDENTITY_LOC_ID).unwrap().owner, legal_officer_id(2));
+        assert_eq!(LogionLoc::loc_of_owner(legal_officer_id(2), LOC_ID), Some(()));
+        assert_eq!(LogionLoc::loc_of_owner(legal_officer_id(1), LOC_ID), None);
+        assert!(!LogionLoc::owns_locs(&legal_officer_id(1)));
+        assert!(LogionLoc::owns_locs(&legal_officer_id(2)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocOwnershipTransferred(LOC_ID, legal_officer_id(1), legal_officer_id(2))));
+    });
+}
+
+#[test]
+fn it_transfers_at_most_max_locs() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 1, 10, Vec::new()));
+        assert!(LogionLoc::owns_locs(&legal_officer_id(1)));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 1, 10, Vec::new()));
+        assert!(!LogionLoc::owns_locs(&legal_officer_id(1)));
+    });
+}
+
+#[test]
+fn it_transfers_co_ownership() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(2), legal_officer_id(3), 10, 10, Vec::new()));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.owner, legal_officer_id(1));
+        assert_eq!(LogionLoc::co_ownership(LOC_ID).unwrap().co_owners.to_vec(), Vec::from([ legal_officer_id(3) ]));
+        assert_eq!(LogionLoc::loc_of_owner(legal_officer_id(3), LOC_ID), Some(()));
+        assert!(!LogionLoc::owns_locs(&legal_officer_id(2)));
+    });
+}
+
+#[test]
+fn it_merges_co_owner_into_owner_and_transfers_others() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, legal_officer_id(2), 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, None, false));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(2), legal_officer_id(1), 10, 10, Vec::new()));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, legal_officer_id(1));
+        assert!(LogionLoc::co_ownership(LOC_ID).is_none());
+        assert!(LogionLoc::owner_approval(LOC_ID, legal_officer_id(2)).is_none());
+        assert_eq!(LogionLoc::loc(OTHER_LOC_ID).unwrap().owner, legal_officer_id(1));
+        assert_eq!(LogionLoc::loc_of_owner(legal_officer_id(1), LOC_ID), Some(()));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocOwnershipTransferred(LOC_ID, legal_officer_id(2), legal_officer_id(1))));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocOwnershipTransferred(OTHER_LOC_ID, legal_officer_id(2), legal_officer_id(1))));
+
+        // The legal officer may then be removed from the authority list
+        assert!(!LogionLoc::owns_locs(&legal_officer_id(2)));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert!(LogionLoc::loc(LOC_ID).unwrap().closed);
+    });
+}
+
+#[test]
+fn it_merges_owner_into_co_owner() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 10, Vec::new()));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, legal_officer_id(2));
+        assert!(LogionLoc::co_ownership(LOC_ID).is_none());
+        assert_eq!(LogionLoc::loc_of_owner(legal_officer_id(2), LOC_ID), Some(()));
+        assert!(!LogionLoc::owns_locs(&legal_officer_id(1)));
+    });
+}
+
+#[test]
+fn it_transfers_pending_owner_approvals() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_co_owned_loc(2);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), LOC_ID, None, false));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(2), legal_officer_id(3), 10, 10, Vec::new()));
+
+        assert!(LogionLoc::owner_approval(LOC_ID, legal_officer_id(2)).is_none());
+        assert!(LogionLoc::owner_approval(LOC_ID, legal_officer_id(3)).is_some());
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        assert!(LogionLoc::loc(LOC_ID).unwrap().closed);
+    });
+}
+
+#[test]
+fn it_transfers_verified_issuers() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 10, Vec::new()));
+
+        assert!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1).is_none());
+        assert!(LogionLoc::verified_issuers(legal_officer_id(2), ISSUER_ID1).is_some());
+        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(1), LOC_ID)), None);
+        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer_id(2), LOC_ID)), Some(()));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::VerifiedIssuerTransferred(ISSUER_ID1, legal_officer_id(1), legal_officer_id(2))));
+    });
+}
+
+#[test]
+fn it_reports_verified_issuer_already_nominated_by_target() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(ISSUER_ID1), ISSUER2_IDENTITY_LOC_ID, legal_officer_id(2), ID_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(2)), ISSUER2_IDENTITY_LOC_ID, None, false));
+        assert_ok!(LogionLoc::nominate_issuer(RuntimeOrigin::signed(legal_officer_id(2)), ISSUER_ID1, ISSUER2_IDENTITY_LOC_ID));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 10, Vec::new()));
+
+        assert!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1).is_none());
+        assert_eq!(LogionLoc::verified_issuers(legal_officer_id(2), ISSUER_ID1).unwrap().identity_loc, ISSUER2_IDENTITY_LOC_ID);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::VerifiedIssuerAlreadyNominated(ISSUER_ID1, legal_officer_id(1), legal_officer_id(2))));
+    });
+}
+
+#[test]
+fn it_transfers_at_most_max_issuers() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, ISSUER_ID1, true));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 0, Vec::new()));
+
+        assert!(LogionLoc::verified_issuers(legal_officer_id(1), ISSUER_ID1).is_some());
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, legal_officer_id(1));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 2, Vec::new()));
+
+        assert!(LogionLoc::verified_issuers(legal_officer_id(2), ISSUER_ID1).is_some());
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, legal_officer_id(2));
+    });
+}
+
+#[test]
+fn it_transfers_pending_sponsorships() {
+    new_test_ext().execute_with(|| {
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, legal_officer_id(1)));
+
+        assert_ok!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(2), 10, 10, Vec::from([ sponsorship_id ])));
+
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().legal_officer, legal_officer_id(2));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::SponsorshipTransferred(sponsorship_id, legal_officer_id(1), legal_officer_id(2))));
+        assert_err!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(3), 10, 10, Vec::from([ sponsorship_id ])), Error::<Test>::CannotTransferSponsorship);
+    });
+}
+
+#[test]
+fn it_fails_transferring_data_to_invalid_target() {
+    new_test_ext().execute_with(|| {
+        assert_err!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), legal_officer_id(1), 10, 10, Vec::new()), Error::<Test>::InvalidTransferTarget);
+        assert_err!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::root(), legal_officer_id(1), UNAUTHORIZED_CALLER, 10, 10, Vec::new()), Error::<Test>::InvalidTransferTarget);
+    });
+}
+
+#[test]
+fn it_fails_transferring_data_if_not_root() {
+    new_test_ext().execute_with(|| {
+        assert_err!(LogionLoc::transfer_legal_officer_data(RuntimeOrigin::signed(legal_officer_id(1)), legal_officer_id(1), legal_officer_id(2), 10, 10, Vec::new()), BadOrigin);
+    });
+}
+
+#[test]
+fn it_counts_loc_items() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata = requester_metadata();
+        let file = requester_file();
+        assert_ok!(LogionLoc::add_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, ItemsParams {
+            metadata: Vec::from([ metadata.clone() ]),
+            files: Vec::from([ file.clone() ]),
+            links: Vec::new(),
+        }, FeePayer::Signer));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata_count, 1);
+        assert_eq!(loc.files_count, 1);
+        assert_eq!(loc.links_count, 0);
+
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 0);
+        assert!(LogionLoc::loc_metadata(LOC_ID, metadata.name).is_none());
+        assert!(LogionLoc::loc_file(LOC_ID, file.hash).is_some());
+    });
+}
+
+#[test]
+fn it_keeps_loc_items_order() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), OTHER_LOC_DEFAULT_LEGAL_FEE, ItemsParams::empty()));
+        let metadata1 = add_metadata(10, LOC_REQUESTER_ID);
+        let file = add_file("file", LOC_REQUESTER_ID);
+        let metadata2 = add_metadata(5, LOC_REQUESTER_ID);
+        let metadata3 = add_metadata(20, LOC_REQUESTER_ID);
+
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata2.name));
+
+        assert_eq!(LogionLoc::loc_item_keys(&LOC_ID), Vec::from([
+            LocItemKey::Metadata(metadata1.name),
+            LocItemKey::File(file.hash),
+            LocItemKey::Metadata(metadata3.name),
+        ]));
+    });
+}
+
+#[test]
+fn it_migrates_loc_items_over_several_blocks() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let metadata = expected_metadata(requester_metadata(), ACKNOWLEDGED, NOT_ACKNOWLEDGED);
+        let file = expected_file(&requester_file(), ACKNOWLEDGED, NOT_ACKNOWLEDGED);
+        for loc_id in [ LOC_ID, OTHER_LOC_ID, LOGION_CLASSIFICATION_LOC_ID ] {
+            v27::LocMap::<Test>::insert(loc_id, v26_loc(Vec::from([ metadata.clone() ]), Vec::from([ file.clone() ])));
+        }
+        StorageVersion::new(26).put::<LogionLoc>();
+
+        <LogionLoc as Hooks<BlockNumberFor<Test>>>::on_runtime_upgrade();
+
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::MigrationStarted(27)));
+        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID, legal_officer_id(1), 0, ItemsParams::empty()), Error::<Test>::MigrationInProgress);
+        assert_err!(LogionLoc::get_loc(&LOC_ID), Error::<Test>::MigrationInProgress);
+        assert_err!(LogionLoc::loc_valid_with_owner(&LOC_ID, &legal_officer_id(1)), Error::<Test>::MigrationInProgress);
+        assert_err!(LogionLoc::locs_linking_to(&LOC_ID), Error::<Test>::MigrationInProgress);
+
+        LogionLoc::on_initialize(2);
+
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::MigrationAdvanced(27, 2)));
+        assert_eq!(LogionLoc::migration_in_progress().unwrap().processed, 2);
+        assert_eq!(StorageVersion::get::<LogionLoc>(), StorageVersion::new(26));
+        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID, legal_officer_id(1), 0, ItemsParams::empty()), Error::<Test>::MigrationInProgress);
+
+        LogionLoc::on_initialize(3);
+
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::MigrationCompleted(27)));
+        assert!(LogionLoc::migration_in_progress().is_none());
+        assert_eq!(StorageVersion::get::<LogionLoc>(), StorageVersion::new(27));
+        for loc_id in [ LOC_ID, OTHER_LOC_ID, LOGION_CLASSIFICATION_LOC_ID ] {
+            let loc = LogionLoc::loc(loc_id).unwrap();
+            assert_eq!(loc.metadata_count, 1);
+            assert_eq!(loc.files_count, 1);
+            assert_eq!(loc.links_count, 0);
+            assert_eq!(LogionLoc::loc_metadata(loc_id, metadata.name), Some(metadata.clone()));
+            assert_eq!(LogionLoc::loc_file(loc_id, file.hash), Some(file.clone()));
+            assert_eq!(LogionLoc::loc_item_keys(&loc_id), Vec::from([ LocItemKey::Metadata(metadata.name), LocItemKey::File(file.hash) ]));
+        }
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REQUESTER_IDENTITY_LOC_ID, legal_officer_id(1), 0, ItemsParams::empty()));
+    });
+}
+
+#[test]
+fn it_does_not_start_migration_if_unexpected_storage_version() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(27).put::<LogionLoc>();
+
+        <LogionLoc as Hooks<BlockNumberFor<Test>>>::on_runtime_upgrade();
+
+        assert!(LogionLoc::migration_in_progress().is_none());
+        assert_eq!(StorageVersion::get::<LogionLoc>(), StorageVersion::new(27));
+    });
+}
+
+fn v26_loc(metadata: Vec<MetadataItem<AccountId, EthereumAddress, crate::mock::Hash, BlockNumberFor<Test>>>, files: Vec<File<H256, AccountId, EthereumAddress, BlockNumberFor<Test>>>) -> LegalOfficerCaseV26Of<Test> {
+    LegalOfficerCaseV26 {
+        owner: legal_officer_id(1),
+        requester: LOC_REQUESTER,
+        metadata: BoundedVec::try_from(metadata).unwrap(),
+        files: BoundedVec::try_from(files).unwrap(),
+        closed: false,
+        loc_type: LocType::Transaction,
+        links: BoundedVec::new(),
+        void_info: None,
+        replacer_of: None,
+        collection_last_block_submission: None,
+        collection_max_size: None,
+        collection_can_upload: false,
+        seal: None,
+        sponsorship_id: None,
+        value_fee: 0,
+        legal_fee: 0,
+        collection_item_fee: 0,
+        tokens_record_fee: 0,
+        imported: false,
+        created_on: Some(1),
+        closed_on: None,
+        voided_on: None,
+    }
+}
+
+#[test]
+fn it_migrates_from_v23_to_v26() {
+    new_test_ext().execute_with(|| {
+        v23::LocMap::<Test>::insert(OTHER_LOC_ID, v23_loc(Vec::new()));
+        v23::LocMap::<Test>::insert(LOC_ID, v23_loc(Vec::from([ LocLinkV23 {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"link-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            acknowledged_by_owner: true,
+            acknowledged_by_verified_issuer: false,
+        } ])));
+        StorageVersion::new(23).put::<LogionLoc>();
+
+        v24::AddBlockNumbers::<Test>::on_runtime_upgrade();
+        v25::IndexLinksToLoc::<Test>::on_runtime_upgrade();
+        v26::IndexLocsByOwner::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<LogionLoc>(), StorageVersion::new(26));
+        let loc = v24::LocMap::<Test>::get(LOC_ID).unwrap();
+        assert_eq!(loc.links.len(), 1);
+        assert_eq!(loc.links[0].submitted_on, None);
+        assert_eq!(loc.created_on, None);
+        assert!(LogionLoc::link_to_loc(OTHER_LOC_ID, LOC_ID).is_some());
+        assert!(LogionLoc::loc_of_owner(legal_officer_id(1), LOC_ID).is_some());
+        assert!(LogionLoc::loc_of_owner(legal_officer_id(1), OTHER_LOC_ID).is_some());
+    });
+}
+
+fn v23_loc(links: Vec<LocLinkV23<LocId, H256, AccountId, EthereumAddress>>) -> LegalOfficerCaseV23Of<Test> {
+    LegalOfficerCaseV23 {
+        owner: legal_officer_id(1),
+        requester: LOC_REQUESTER,
+        metadata: BoundedVec::new(),
+        files: BoundedVec::new(),
+        closed: false,
+        loc_type: LocType::Transaction,
+        links: BoundedVec::try_from(links).unwrap(),
+        void_info: None,
+        replacer_of: None,
+        collection_last_block_submission: None,
+        collection_max_size: None,
+        collection_can_upload: false,
+        seal: None,
+        sponsorship_id: None,
+        value_fee: 0,
+        legal_fee: 0,
+        collection_item_fee: 0,
+        tokens_record_fee: 0,
+        imported: false,
+    }
+}
+
+#[test]
+fn it_replaces_legacy_storage_version() {
+    new_test_ext().execute_with(|| {
+        legacy::PalletStorageVersion::<Test>::put(25); // Index of V26IndexLocsByOwner
+
+        MigrateToFrameStorageVersion::<Test>::on_runtime_upgrade();
+
+        assert!(!legacy::PalletStorageVersion::<Test>::exists());
+        assert_eq!(StorageVersion::get::<LogionLoc>(), StorageVersion::new(26));
+    });
+}
+
+#[test]
+fn it_replaces_legacy_storage_version_before_starting_stepped_migration() {
+    new_test_ext().execute_with(|| {
+        legacy::PalletStorageVersion::<Test>::put(25); // Index of V26IndexLocsByOwner
+
+        <LogionLoc as Hooks<BlockNumberFor<Test>>>::on_runtime_upgrade();
+
+        assert!(!legacy::PalletStorageVersion::<Test>::exists());
+        assert!(LogionLoc::migration_in_progress().is_some());
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::MigrationStarted(27)));
+    });
+}
+
+#[test]
+fn it_revokes_collection_item_as_owner() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        let reason = sha256(&"fraudulent-certificate".as_bytes().to_vec());
+        System::set_block_number(3);
+
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, reason));
+
+        assert!(LogionLoc::is_collection_item_revoked(&LOC_ID, &collection_item_id));
+        assert_eq!(LogionLoc::collection_item_revocation(LOC_ID, collection_item_id), Some(CollectionItemRevocation {
+            revoked_by: legal_officer_id(1),
+            reason,
+            revoked_on: 3,
+        }));
+        assert!(LogionLoc::collection_items(LOC_ID, collection_item_id).is_some());
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemRevoked(LOC_ID, collection_item_id, reason)));
+    });
+}
+
+#[test]
+fn it_revokes_collection_item_as_requester() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        let reason = sha256(&"destroyed-asset".as_bytes().to_vec());
+
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, reason));
+
+        assert!(LogionLoc::is_collection_item_revoked(&LOC_ID, &collection_item_id));
+    });
+}
+
+#[test]
+fn it_fails_revoking_collection_item_as_requester_if_not_allowed() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        RequesterCanRevokeCollectionItems::set(&false);
+
+        assert_err!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1)), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_fails_revoking_collection_item_if_unauthorized() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, collection_item_id, H256::repeat_byte(1)), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_fails_revoking_collection_item_twice() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(1)));
+
+        assert_err!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(2)), Error::<Test>::CollectionItemAlreadyRevoked);
+    });
+}
+
+#[test]
+fn it_fails_revoking_unknown_collection_item() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, H256::repeat_byte(42), H256::repeat_byte(1)), Error::<Test>::CollectionItemNotFound);
+    });
+}
+
+#[test]
+fn it_removes_collection_item_during_grace_period() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        System::set_block_number(11);
+
+        assert_ok!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id));
+
+        assert!(LogionLoc::collection_items(LOC_ID, collection_item_id).is_none());
+        assert!(LogionLoc::collection_item_added_on(LOC_ID, collection_item_id).is_none());
+        assert_eq!(LogionLoc::collection_item_removed_on(LOC_ID, collection_item_id), Some(11));
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemRemoved(LOC_ID, collection_item_id)));
+    });
+}
+
+#[test]
+fn it_fails_removing_collection_item_after_grace_period() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        System::set_block_number(12);
+
+        assert_err!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id), Error::<Test>::CollectionItemRemovalGracePeriodExpired);
+    });
+}
+
+#[test]
+fn it_fails_re_adding_removed_collection_item() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id));
+
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, sha256(&"item-description".as_bytes().to_vec()), vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
+    });
+}
+
+#[test]
+fn it_fails_removing_revoked_collection_item() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(1)));
+
+        assert_err!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id), Error::<Test>::CollectionItemAlreadyRevoked);
+        assert!(LogionLoc::is_collection_item_revoked(&LOC_ID, &collection_item_id));
+    });
+}
+
+#[test]
+fn it_fails_removing_collection_item_of_void_collection() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+
+        assert_err!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id), Error::<Test>::CannotMutateVoid);
+    });
+}
+
+#[test]
+fn it_fails_removing_collection_item_if_not_requester() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_releases_collection_item_deposit_when_removing_item() {
+    new_test_ext().execute_with(|| {
         setup_default_balances();
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
-        };
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), 0u128, ItemsParams::only_files(Vec::from([ file.clone() ]))));
-        let fees = Fees::only_storage(1, 4);
-        fees.assert_balances_events(snapshot);
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let item = batch_item("item-1", true);
+        assert_ok!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(vec![item.clone()]).unwrap()));
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+        assert_eq!(LogionLoc::collection_item_deposit((LOC_ID, item.item_id, 0)), Some((LOC_REQUESTER_ID, deposit)));
+
+        assert_ok!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, item.item_id));
+
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), 0);
+        assert_eq!(LogionLoc::collection_item_deposit((LOC_ID, item.item_id, 0)), None);
+        assert!(LogionLoc::storage_deposits_of(&LOC_ID).unwrap().is_empty());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::StorageDepositReleased(LOC_REQUESTER_ID, LOC_ID, deposit)));
     });
 }
 
 #[test]
-fn it_applies_storage_fees_on_collection_creation() {
+fn it_releases_collection_item_deposits_of_void_collection_in_steps() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        FileStoragePaymentMode::set(&StoragePaymentMode::Deposit);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let items = [ batch_item("item-1", true), batch_item("item-2", true) ];
+        assert_ok!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(items.to_vec()).unwrap()));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
+        let deposit = Fees::storage_fees(1, FILE_SIZE);
+
+        assert_ok!(LogionLoc::release_storage_deposits(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, 1));
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), deposit);
+        assert_eq!(items.iter().filter(|item| LogionLoc::collection_items(LOC_ID, item.item_id).is_some()).count(), 1);
+
+        assert_ok!(LogionLoc::release_storage_deposits(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, 1));
+        assert_eq!(<Test as Config>::Currency::reserved_balance(LOC_REQUESTER_ID), 0);
+        assert!(items.iter().all(|item| LogionLoc::collection_items(LOC_ID, item.item_id).is_none()));
+        assert!(LogionLoc::storage_deposits_of(&LOC_ID).unwrap().is_empty());
+    });
+}
+
+fn create_closed_collection_with_item() -> H256 {
+    create_closed_collection_with_item_and_limits(None, false)
+}
+
+fn create_closed_collection_with_item_and_limits(collection_last_block_submission: Option<BlockNumberFor<Test>>, collection_can_upload: bool) -> H256 {
+    setup_default_balances();
+    assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), collection_last_block_submission, Some(10), collection_can_upload, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+    let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+    let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+    assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()));
+    collection_item_id
+}
+
+#[test]
+fn it_adds_collection_items_in_batch() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let items = [ batch_item("item-1", true), batch_item("item-2", true) ];
+
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"Test".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: 4,
+        assert_ok!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(items.to_vec()).unwrap()));
+
+        for item in items.iter() {
+            assert_eq!(LogionLoc::collection_items(LOC_ID, item.item_id), Some(CollectionItem {
+                description: item.item_description,
+                files: BoundedVec::try_from(item.item_files.clone()).unwrap(),
+                token: item.item_token.clone(),
+                restricted_delivery: true,
+                terms_and_conditions: BoundedVec::new(),
+                imported: false,
+            }));
+            System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemAdded(LOC_ID, item.item_id)));
+        }
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(2));
+        let fees = Fees {
+            storage_fees: Fees::storage_fees(2, 2 * FILE_SIZE),
+            legal_fees: 0,
+            fee_beneficiary: None,
+            certificate_fees: 2 * 8_000_000_000_000_000,
+            value_fee: 0,
+            collection_item_fee: 0,
+            tokens_record_fee: 0,
         };
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, 0u128, 0, 0, ItemsParams::only_files(Vec::from([ file ]))));
-        let fees = Fees::only_storage(1, 4);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_applies_collection_item_fee() {
+fn it_applies_collection_item_fee_for_each_item_of_batch() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         let collection_item_fee: Balance = 5 * ONE_LGNT;
@@ -3274,995 +6419,933 @@ fn it_applies_collection_item_fee() {
         assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
-        let fees = Fees::only_collection_item(collection_item_fee, Beneficiary::LegalOfficer(legal_officer_id(1)));
+        assert_ok!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ batch_item("item-1", false), batch_item("item-2", false) ])).unwrap()));
+
+        let fees = Fees::only_collection_item(2 * collection_item_fee, Beneficiary::LegalOfficer(legal_officer_id(1)));
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_to_add_collection_item_when_insufficient_funds() {
+fn it_fails_adding_collection_items_with_duplicate_ids() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let collection_item_fee: Balance = INITIAL_BALANCE;
-        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, collection_item_fee, 0, ItemsParams::empty()));
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
         assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()), Error::<Test>::InsufficientFunds);
+        assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ batch_item("item-1", false), batch_item("item-1", false) ])).unwrap()), Error::<Test>::CollectionItemAlreadyExists);
     });
 }
 
 #[test]
-fn it_fails_to_add_tokens_record_when_insufficient_funds() {
+fn it_fails_adding_collection_items_beyond_max_size() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(1), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
 
-        let storage_fees = Fees::storage_fees(1, record_files[0].size);
-        set_balance(LOC_REQUESTER_ID, 2 * storage_fees); // Requester can pay storage but not tokens record
+        assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ batch_item("item-1", false), batch_item("item-2", false) ])).unwrap()), Error::<Test>::CollectionLimitsReached);
+    });
+}
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, record_description.clone(), record_files.clone(), false), Error::<Test>::InsufficientFunds);
+#[test]
+fn it_fails_adding_collection_items_if_one_is_invalid() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let mut invalid_item = batch_item("item-2", false);
+        invalid_item.terms_and_conditions = Vec::from([ TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: sha256(&"ITEM-A".as_bytes().to_vec()),
+        } ]);
+
+        assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ batch_item("item-1", false), invalid_item ])).unwrap()), Error::<Test>::TermsAndConditionsLocNotFound);
+        assert_eq!(LogionLoc::collection_size(LOC_ID), None);
     });
 }
 
-fn create_closed_polkadot_identity_loc(origin: OriginFor<Test>, identity_loc: LocId) {
-	assert_ok!(LogionLoc::create_polkadot_identity_loc(origin, identity_loc, legal_officer_id(1), 0, ItemsParams::empty()));
-	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), identity_loc, None, false));
+#[test]
+fn it_validates_collection_item_params_before_collection() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let mut invalid_item = batch_item("item-1", false);
+        invalid_item.restricted_delivery = true;
+
+        assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ invalid_item ])).unwrap()), Error::<Test>::MissingToken);
+    });
 }
 
-fn create_identity_and_transaction_loc(
-	origin: OriginFor<Test>,
-	loc_id: LocId,
-	legal_officer: AccountId,
-	legal_fee: Balance,
-	items: ItemsParamsOf<Test>,
-) -> DispatchResultWithPostInfo {
-	create_closed_polkadot_identity_loc(origin.clone(), REQUESTER_IDENTITY_LOC_ID);
-	LogionLoc::create_polkadot_transaction_loc(origin, loc_id, legal_officer, legal_fee, items)
+fn batch_item(id: &str, with_file_and_token: bool) -> CollectionItemParamsOf<Test> {
+    CollectionItemParams {
+        item_id: BlakeTwo256::hash_of(&id.as_bytes().to_vec()),
+        item_description: sha256(&id.as_bytes().to_vec()),
+        item_files: if with_file_and_token {
+            Vec::from([ CollectionItemFile {
+                name: sha256(&"picture.png".as_bytes().to_vec()),
+                content_type: sha256(&"image/png".as_bytes().to_vec()),
+                hash: BlakeTwo256::hash_of(&id.as_bytes().to_vec()),
+                size: FILE_SIZE,
+            } ])
+        } else {
+            Vec::new()
+        },
+        item_token: if with_file_and_token {
+            Some(CollectionItemToken {
+                token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+                token_id: sha256(&id.as_bytes().to_vec()),
+                token_issuance: 2,
+            })
+        } else {
+            None
+        },
+        restricted_delivery: with_file_and_token,
+        terms_and_conditions: Vec::new(),
+        native_token: None,
+    }
 }
 
-fn create_identity_and_collection_loc(
-	origin: OriginFor<Test>,
-	loc_id: LocId,
-	legal_officer: AccountId,
-	collection_last_block_submission: Option<BlockNumberFor<Test>>,
-	collection_max_size: Option<u32>,
-	collection_can_upload: bool,
-	value_fee: Balance,
-	legal_fee: Balance,
-	collection_item_fee: Balance,
-	tokens_record_fee: Balance,
-	items: ItemsParamsOf<Test>,
-) -> DispatchResultWithPostInfo {
-	create_closed_polkadot_identity_loc(origin.clone(), REQUESTER_IDENTITY_LOC_ID);
-	LogionLoc::create_collection_loc(origin, loc_id, legal_officer, collection_last_block_submission, collection_max_size, collection_can_upload, value_fee, legal_fee, collection_item_fee, tokens_record_fee, items)
+#[test]
+fn it_updates_collection_item() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item_and_limits(None, true);
+        let original_item = LogionLoc::collection_items(LOC_ID, collection_item_id).unwrap();
+        let description = sha256(&"restored".as_bytes().to_vec());
+        let files = Vec::from([ CollectionItemFile {
+            name: sha256(&"appraisal.pdf".as_bytes().to_vec()),
+            content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"appraisal".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        } ]);
+        System::set_block_number(4);
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, legal_officers());
+        assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, description, files.clone()));
+
+        assert_eq!(LogionLoc::collection_item_version((LOC_ID, collection_item_id), 1), Some(CollectionItemVersion {
+            description,
+            files: BoundedVec::try_from(files).unwrap(),
+            updated_on: 4,
+        }));
+        assert_eq!(LogionLoc::collection_item_latest_version(LOC_ID, collection_item_id), Some(1));
+        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(original_item));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ItemUpdated(LOC_ID, collection_item_id, 1)));
+        Fees::only_storage(1, FILE_SIZE).assert_balances_events(snapshot);
+
+        assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, sha256(&"new custodian".as_bytes().to_vec()), Vec::new()));
+
+        assert!(LogionLoc::collection_item_version((LOC_ID, collection_item_id), 1).is_some());
+        assert_eq!(LogionLoc::collection_item_latest_version(LOC_ID, collection_item_id), Some(2));
+    });
 }
 
 #[test]
-fn it_sets_invited_contributor_selection() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
+fn it_fails_updating_collection_item_beyond_max_versions() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item_and_limits(None, false);
+        for i in 0..MaxCollectionItemVersions::get() {
+            assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(i as u8), Vec::new()));
+        }
 
-		assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), None);
-		assert_ok!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true));
-		assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), Some(()));
-		assert_ok!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, false));
-		assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), None);
-	});
+        assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(42), Vec::new()), Error::<Test>::CollectionItemVersionsTooMuchData);
+    });
 }
 
 #[test]
-fn it_fails_to_set_invited_contributor_selection_on_non_existent_loc() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
-		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::NotFound);
-	});
+fn it_fails_updating_collection_item_after_last_submission_block() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item_and_limits(Some(5), false);
+        System::set_block_number(5);
+
+        assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1), Vec::new()), Error::<Test>::CannotUpload);
+    });
 }
 
 #[test]
-fn it_fails_to_set_invited_contributor_selection_when_not_requester() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
-		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::Unauthorized);
-	});
+fn it_fails_updating_collection_item_with_files_if_upload_disabled() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item_and_limits(None, false);
+        let files = Vec::from([ CollectionItemFile {
+            name: sha256(&"appraisal.pdf".as_bytes().to_vec()),
+            content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"appraisal".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        } ]);
+
+        assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1), files), Error::<Test>::CannotUpload);
+    });
 }
 
 #[test]
-fn it_fails_to_set_invited_contributor_selection_when_not_identified() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
-		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::AccountNotIdentified);
-	});
+fn it_fails_updating_revoked_collection_item() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(1)));
+
+        assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1), Vec::new()), Error::<Test>::CannotUpdateCollectionItem);
+    });
+}
+
+#[test]
+fn it_fails_updating_collection_item_if_not_requester() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, collection_item_id, H256::repeat_byte(1), Vec::new()), Error::<Test>::WrongCollectionLoc);
+    });
+}
+
+#[test]
+fn it_removes_collection_item_versions() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1), Vec::new()));
+
+        assert_ok!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id));
+
+        assert!(LogionLoc::collection_item_version((LOC_ID, collection_item_id), 1).is_none());
+        assert!(LogionLoc::collection_item_latest_version(LOC_ID, collection_item_id).is_none());
+    });
+}
+
+const TOKEN_HOLDER_ID: AccountId = 42;
+
+fn create_closed_collection_with_restricted_item(restricted_delivery: bool) -> (H256, H256) {
+    setup_default_balances();
+    assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+    let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+    let file_hash = BlakeTwo256::hash_of(&"picture".as_bytes().to_vec());
+    let collection_item_files = Vec::from([ CollectionItemFile {
+        name: sha256(&"picture.png".as_bytes().to_vec()),
+        content_type: sha256(&"image/png".as_bytes().to_vec()),
+        hash: file_hash,
+        size: FILE_SIZE,
+    } ]);
+    let collection_item_token = CollectionItemToken {
+        token_type: sha256(&"owner".as_bytes().to_vec()),
+        token_id: owned_token_id(TOKEN_HOLDER_ID),
+        token_issuance: 1,
+    };
+    assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, sha256(&"item-description".as_bytes().to_vec()), collection_item_files, Some(collection_item_token), restricted_delivery, Vec::new()));
+    (collection_item_id, file_hash)
+}
+
+#[test]
+fn it_fails_updating_restricted_delivery_item_without_files() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+
+        assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1), Vec::new()), Error::<Test>::MissingFiles);
+    });
+}
+
+#[test]
+fn it_allows_restricted_delivery_to_token_holder() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, file_hash) = create_closed_collection_with_restricted_item(true);
+
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &TOKEN_HOLDER_ID), Ok(true));
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &UNAUTHORIZED_CALLER), Ok(false));
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &H256::repeat_byte(1), &TOKEN_HOLDER_ID), Ok(false));
+    });
+}
+
+#[test]
+fn it_allows_restricted_delivery_of_updated_files() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let file_hash = BlakeTwo256::hash_of(&"appraisal".as_bytes().to_vec());
+        assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, H256::repeat_byte(1), Vec::from([ CollectionItemFile {
+            name: sha256(&"appraisal.pdf".as_bytes().to_vec()),
+            content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+            hash: file_hash,
+            size: FILE_SIZE,
+        } ])));
+
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &TOKEN_HOLDER_ID), Ok(true));
+    });
+}
+
+#[test]
+fn it_denies_delivery_of_unrestricted_item() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, file_hash) = create_closed_collection_with_restricted_item(false);
+
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &TOKEN_HOLDER_ID), Ok(false));
+    });
+}
+
+#[test]
+fn it_denies_restricted_delivery_of_revoked_item() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, file_hash) = create_closed_collection_with_restricted_item(true);
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(1)));
+
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &TOKEN_HOLDER_ID), Ok(false));
+    });
+}
+
+#[test]
+fn it_allows_tokens_record_access_to_token_holder() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let record_id = build_record_id();
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, build_record_description(), build_record_files(1), false));
+
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &record_id, &collection_item_id, &TOKEN_HOLDER_ID), Ok(true));
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &record_id, &collection_item_id, &UNAUTHORIZED_CALLER), Ok(false));
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &H256::repeat_byte(1), &collection_item_id, &TOKEN_HOLDER_ID), Ok(false));
+    });
+}
+
+#[test]
+fn it_denies_tokens_record_access_through_unrestricted_item() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(false);
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
+
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &record_id, &collection_item_id, &TOKEN_HOLDER_ID), Ok(false));
+    });
+}
+
+#[test]
+fn it_claims_restricted_delivery() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, file_hash) = create_closed_collection_with_restricted_item(true);
+
+        assert_ok!(LogionLoc::claim_restricted_delivery(RuntimeOrigin::signed(TOKEN_HOLDER_ID), LOC_ID, collection_item_id, file_hash));
+
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::RestrictedDeliveryClaimed(LOC_ID, collection_item_id, file_hash, TOKEN_HOLDER_ID)));
+    });
+}
+
+#[test]
+fn it_fails_claiming_restricted_delivery_if_not_token_holder() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, file_hash) = create_closed_collection_with_restricted_item(true);
+
+        assert_err!(LogionLoc::claim_restricted_delivery(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, collection_item_id, file_hash), Error::<Test>::CannotClaimRestrictedDelivery);
+    });
 }
 
 #[test]
-fn it_fails_to_set_invited_contributor_selection_on_void_loc() {
-	new_test_ext().execute_with(|| {
-		setup_default_balances();
-		assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, TOKENS_RECORD_FEE, ItemsParams::empty()));
-		create_closed_polkadot_identity_loc(RuntimeOrigin::signed(INVITED_CONTRIBUTOR_ID), INVITED_CONTRIBUTOR_IDENTITY_LOC_ID);
-		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
-		assert_err!(LogionLoc::set_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID, true), Error::<Test>::CannotMutateVoid);
-	});
-}
+fn it_claims_tokens_record_access() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
 
-#[test]
-fn it_adds_tokens_record_as_invited_contributor() {
-	it_adds_tokens_record(INVITED_CONTRIBUTOR_ID);
+        assert_ok!(LogionLoc::claim_tokens_record_access(RuntimeOrigin::signed(TOKEN_HOLDER_ID), LOC_ID, record_id, collection_item_id));
+
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordAccessClaimed(LOC_ID, record_id, collection_item_id, TOKEN_HOLDER_ID)));
+    });
 }
 
 #[test]
-fn it_fails_importing_not_root() {
+fn it_fails_claiming_tokens_record_access_if_not_token_holder() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let items = build_items(false);
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
 
-        assert_err!(LogionLoc::import_loc(
-            RuntimeOrigin::signed(LOC_REQUESTER_ID),
-            LOC_ID,
-            LOC_REQUESTER,
-            legal_officer_id(1),
-            LocType::Transaction,
-            items,
-            None,
-            None,
-            false,
-            0,
-            0,
-            0,
-            0,
-            None,
-            None,
-            None,
-            None,
-            false,
-        ), BadOrigin);
+        assert_err!(LogionLoc::claim_tokens_record_access(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, record_id, collection_item_id), Error::<Test>::CannotAccessTokensRecord);
     });
 }
 
-fn build_items(closed: bool) -> ItemsOf<Test> {
-    let metadata = MetadataItem {
-        name: sha256(&vec![1, 2, 3]),
-        value: sha256(&vec![4, 5, 6]),
-        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        acknowledged_by_owner: closed,
-        acknowledged_by_verified_issuer: closed,
-    };
-    let file = File {
-        hash: sha256(&"test".as_bytes().to_vec()),
-        nature: sha256(&"Test".as_bytes().to_vec()),
-        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        size: 4,
-        acknowledged_by_owner: closed,
-        acknowledged_by_verified_issuer: closed,
-    };
-    let link = LocLink {
-        id: OTHER_LOC_ID,
-        nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        acknowledged_by_owner: closed,
-        acknowledged_by_verified_issuer: closed,
+fn create_closed_collection_for_native_tokens() {
+    setup_default_balances();
+    assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), false, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+}
+
+fn add_item_with_native_token(token_issuance: TokenIssuance, native_token: Option<NativeTokenKind>) -> DispatchResultWithPostInfo {
+    let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+    let collection_item_token = CollectionItemToken {
+        token_type: sha256(&"logion_native".as_bytes().to_vec()),
+        token_id: sha256(&"item-id".as_bytes().to_vec()),
+        token_issuance,
     };
-    Items {
-        metadata: Vec::from([ metadata.clone() ]),
-        files: Vec::from([ file.clone() ]),
-        links: Vec::from([ link.clone() ]),
-    }
+    LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ CollectionItemParams {
+        item_id: collection_item_id,
+        item_description: sha256(&"item-description".as_bytes().to_vec()),
+        item_files: Vec::new(),
+        item_token: Some(collection_item_token),
+        restricted_delivery: false,
+        terms_and_conditions: Vec::new(),
+        native_token,
+    } ])).unwrap())
 }
 
 #[test]
-fn it_imports_open_transaction_loc() {
+fn it_mints_native_non_fungible_token() {
+    it_mints_native_token(NativeTokenKind::NonFungible, 1);
+}
+
+#[test]
+fn it_mints_native_fungible_token() {
+    it_mints_native_token(NativeTokenKind::Fungible, 100);
+}
+
+fn it_mints_native_token(kind: NativeTokenKind, token_issuance: TokenIssuance) {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Transaction,
-            false,
-            false,
-        );
+        create_closed_collection_for_native_tokens();
+
+        assert_ok!(add_item_with_native_token(token_issuance, Some(kind)));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let token_id = sha256(&(LOC_ID, collection_item_id).encode());
+        assert_eq!(minted_native_tokens(), Vec::from([ (kind, token_id, LOC_REQUESTER_ID, token_issuance) ]));
+        assert_eq!(LogionLoc::collection_item_native_token(LOC_ID, collection_item_id), Some(NativeToken { kind, id: token_id }));
+        assert_eq!(LogionLoc::native_token_item(token_id), Some((LOC_ID, collection_item_id)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::NativeTokenMinted(LOC_ID, collection_item_id, token_id)));
     });
 }
 
-fn test_import_loc(
-    requester: RequesterOf<Test>,
-    loc_type: LocType,
-    closed: bool,
-    void: bool,
-) {
-    let items = build_items(closed);
+#[test]
+fn it_does_not_mint_native_token_if_not_requested() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_for_native_tokens();
 
-    let mut void_info = None;
-    let mut replacer_of = None;
-    if void {
-        void_info = Some(LocVoidInfo {
-            replacer: Some(OTHER_LOC_ID),
-        });
-    } else {
-        replacer_of = Some(OTHER_LOC_ID);
-    }
+        assert_ok!(add_item_with_native_token(1, None));
 
-    let mut seal = None;
-    if loc_type == LocType::Identity && closed {
-        seal = Some(sha256(&vec![42]));
-    }
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        assert!(minted_native_tokens().is_empty());
+        assert!(LogionLoc::collection_item_native_token(LOC_ID, collection_item_id).is_none());
+    });
+}
 
-    let mut legal_fee = 2000;
-    if loc_type == LocType::Identity {
-        legal_fee = 160;
-    }
+#[test]
+fn it_fails_minting_native_token_without_token() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_for_native_tokens();
+        let mut item = batch_item("item-id", false);
+        item.native_token = Some(NativeTokenKind::Fungible);
 
-    let mut collection_last_block_submission = None;
-    let mut collection_max_size = None;
-    let mut collection_can_upload = false;
-    let mut value_fee = 0;
-    let mut collection_item_fee = 0;
-    let mut tokens_record_fee = 0;
-    if loc_type == LocType::Collection {
-        collection_last_block_submission = Some(424242);
-        collection_max_size = Some(200);
-        collection_can_upload = true;
-        value_fee = 200;
-        collection_item_fee = 10;
-        tokens_record_fee = 20;
-    }
+        assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ item ])).unwrap()), Error::<Test>::MissingToken);
+    });
+}
 
-    let mut sponsorship_id = None;
-    match requester {
-        OtherAccount(_) => sponsorship_id = Some(1u32),
-        _ => {},
-    }
+#[test]
+fn it_fails_minting_native_non_fungible_token_with_issuance_greater_than_one() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_for_native_tokens();
 
-    assert_ok!(LogionLoc::import_loc(
-        RuntimeOrigin::root(),
-        LOC_ID,
-        requester.clone(),
-        legal_officer_id(1),
-        loc_type,
-        items.clone(),
-        collection_last_block_submission,
-        collection_max_size,
-        collection_can_upload,
-        value_fee,
-        legal_fee,
-        collection_item_fee,
-        tokens_record_fee,
-        sponsorship_id,
-        seal,
-        void_info.clone(),
-        replacer_of.clone(),
-        closed,
-    ));
-    System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocImported {
-        0: LOC_ID,
-    }));
-    assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-        owner: legal_officer_id(1),
-        requester: requester.clone(),
-        metadata: BoundedVec::try_from(Vec::from(items.metadata)).unwrap(),
-        files: BoundedVec::try_from(Vec::from(items.files)).unwrap(),
-        closed,
-        loc_type,
-        links: BoundedVec::try_from(Vec::from(items.links)).unwrap(),
-        void_info,
-        replacer_of,
-        collection_last_block_submission,
-        collection_max_size,
-        collection_can_upload,
-        seal,
-        sponsorship_id,
-        value_fee,
-        legal_fee,
-        collection_item_fee,
-        tokens_record_fee,
-        imported: true,
-    }));
-    match requester {
-        Account(requester_account_id) => assert!(
-            LogionLoc::account_locs(requester_account_id).is_some()
-        ),
-        _ => {},
-    }
+        assert_err!(add_item_with_native_token(2, Some(NativeTokenKind::NonFungible)), Error::<Test>::BadTokenIssuance);
+    });
 }
 
 #[test]
-fn it_imports_closed_transaction_loc() {
+fn it_checks_restricted_delivery_against_native_token() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Transaction,
-            true,
-            false,
-        );
+        assert_ok!(create_identity_and_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, legal_officer_id(1), None, Some(10), true, 0, OTHER_LOC_DEFAULT_LEGAL_FEE, 0, 0, ItemsParams::empty()));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, None, false));
+        let mut item = batch_item("item-id", true);
+        item.item_token = Some(CollectionItemToken {
+            token_type: sha256(&"owner".as_bytes().to_vec()),
+            token_id: owned_token_id(TOKEN_HOLDER_ID),
+            token_issuance: 1,
+        });
+        item.native_token = Some(NativeTokenKind::NonFungible);
+        let collection_item_id = item.item_id;
+        let file_hash = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+
+        assert_ok!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, BoundedVec::try_from(Vec::from([ item ])).unwrap()));
+
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &LOC_REQUESTER_ID), Ok(true));
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &TOKEN_HOLDER_ID), Ok(false));
     });
 }
 
 #[test]
-fn it_imports_void_transaction_loc() {
+fn it_does_not_add_item_if_minting_fails() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Transaction,
-            true,
-            true,
-        );
+        create_closed_collection_for_native_tokens();
+
+        assert_err!(add_item_with_native_token(1001, Some(NativeTokenKind::Fungible)), sp_runtime::DispatchError::Other("Supply too high"));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        assert!(LogionLoc::collection_items(LOC_ID, collection_item_id).is_none());
     });
 }
 
 #[test]
-fn it_imports_open_identity_loc() {
+fn it_fails_removing_collection_item_with_native_token() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Identity,
-            false,
-            false,
-        );
+        create_closed_collection_for_native_tokens();
+        assert_ok!(add_item_with_native_token(1, Some(NativeTokenKind::NonFungible)));
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+
+        assert_err!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id), Error::<Test>::CannotRemoveItemWithNativeToken);
     });
 }
 
+const ITEM_OWNER_ID: AccountId = 43;
+const NEW_ITEM_OWNER_ID: AccountId = 44;
+
 #[test]
-fn it_imports_closed_identity_loc() {
+fn it_sets_collection_item_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Identity,
-            true,
-            false,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+
+        assert_eq!(LogionLoc::collection_item_owner(LOC_ID, collection_item_id), Some(ITEM_OWNER_ID));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemOwnerSet(LOC_ID, collection_item_id, ITEM_OWNER_ID)));
     });
 }
 
 #[test]
-fn it_imports_void_identity_loc() {
+fn it_fails_setting_collection_item_owner_if_not_requester() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Identity,
-            true,
-            true,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, ITEM_OWNER_ID), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_imports_open_collection_loc() {
+fn it_fails_setting_collection_item_owner_twice() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Collection,
-            false,
-            false,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+
+        assert_err!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::CollectionItemOwnerAlreadySet);
+    });
+}
+
+#[test]
+fn it_fails_setting_owner_of_unknown_collection_item() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, H256::repeat_byte(1), ITEM_OWNER_ID), Error::<Test>::CollectionItemNotFound);
     });
 }
 
 #[test]
-fn it_imports_closed_collection_loc() {
+fn it_transfers_collection_item() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Collection,
-            true,
-            false,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+
+        assert_ok!(LogionLoc::transfer_collection_item(RuntimeOrigin::signed(ITEM_OWNER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID));
+
+        assert_eq!(LogionLoc::collection_item_owner(LOC_ID, collection_item_id), Some(NEW_ITEM_OWNER_ID));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemTransferred(LOC_ID, collection_item_id, ITEM_OWNER_ID, NEW_ITEM_OWNER_ID)));
+        assert_err!(LogionLoc::transfer_collection_item(RuntimeOrigin::signed(ITEM_OWNER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID), Error::<Test>::NotCollectionItemOwner);
     });
 }
 
 #[test]
-fn it_imports_void_collection_loc() {
+fn it_fails_transferring_collection_item_without_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            LOC_REQUESTER,
-            LocType::Collection,
-            true,
-            true,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::transfer_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::CollectionItemHasNoOwner);
     });
 }
 
 #[test]
-fn it_imports_open_identity_loc_other_account() {
+fn it_fails_transferring_revoked_collection_item() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let ethereum_account = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let requester = OtherAccountId::Ethereum(ethereum_account);
-        test_import_loc(
-            OtherAccount(requester),
-            LocType::Identity,
-            false,
-            false,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(1)));
+
+        assert_err!(LogionLoc::transfer_collection_item(RuntimeOrigin::signed(ITEM_OWNER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::CannotTransferCollectionItem);
     });
 }
 
 #[test]
-fn it_imports_open_identity_loc_logion() {
+fn it_force_transfers_collection_item() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        test_import_loc(
-            Requester::None,
-            LocType::Identity,
-            false,
-            false,
-        );
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+
+        assert_ok!(LogionLoc::force_transfer_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID));
+
+        assert_eq!(LogionLoc::collection_item_owner(LOC_ID, collection_item_id), Some(NEW_ITEM_OWNER_ID));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemForceTransferred(LOC_ID, collection_item_id, Some(ITEM_OWNER_ID), NEW_ITEM_OWNER_ID)));
     });
 }
 
 #[test]
-fn it_fails_import_item_not_root() {
+fn it_force_transfers_collection_item_without_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(
-            LogionLoc::import_collection_item(
-                RuntimeOrigin::signed(LOC_REQUESTER_ID),
-                LOC_ID,
-                collection_item_id,
-                collection_item_description.clone(),
-                vec![],
-                None,
-                false,
-                Vec::new()
-            ),
-            BadOrigin,
-        );
+        assert_ok!(LogionLoc::force_transfer_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID));
+
+        assert_eq!(LogionLoc::collection_item_owner(LOC_ID, collection_item_id), Some(NEW_ITEM_OWNER_ID));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemForceTransferred(LOC_ID, collection_item_id, None, NEW_ITEM_OWNER_ID)));
     });
 }
 
 #[test]
-fn it_imports_valid_item() {
+fn it_fails_force_transferring_revoked_collection_item() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+        assert_ok!(LogionLoc::revoke_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, H256::repeat_byte(1)));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::import_collection_item(
-            RuntimeOrigin::root(),
-            LOC_ID,
-            collection_item_id,
-            collection_item_description.clone(),
-            vec![],
-            None,
-            false,
-            Vec::new()
-        ));
-        assert_eq!(
-            LogionLoc::collection_items(LOC_ID, collection_item_id),
-            Some(CollectionItem {
-                description: collection_item_description,
-                files: BoundedVec::new(),
-                token: None,
-                restricted_delivery: false,
-                terms_and_conditions: BoundedVec::new(),
-                imported: true,
-            }
-        ));
-        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+        assert_err!(LogionLoc::force_transfer_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::CannotTransferCollectionItem);
     });
 }
 
 #[test]
-fn it_fails_import_item_if_duplicate_key() {
+fn it_fails_transferring_collection_item_of_void_collection() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::import_collection_item(RuntimeOrigin::root(), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
-        assert_err!(LogionLoc::import_collection_item(RuntimeOrigin::root(), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
+        assert_err!(LogionLoc::transfer_collection_item(RuntimeOrigin::signed(ITEM_OWNER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::CannotMutateVoid);
+        assert_err!(LogionLoc::force_transfer_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::CannotMutateVoid);
     });
 }
 
 #[test]
-fn it_fails_import_item_with_duplicate_hash() {
+fn it_fails_setting_owner_of_collection_item_with_native_token() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-
+        create_closed_collection_for_native_tokens();
+        assert_ok!(add_item_with_native_token(1, Some(NativeTokenKind::NonFungible)));
         let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
-        let collection_item_files = vec![
-            CollectionItemFile {
-                name: sha256(&"picture.png".as_bytes().to_vec()),
-                content_type: sha256(&"image/png".as_bytes().to_vec()),
-                hash: same_hash,
-                size: 123456,
-            },
-            CollectionItemFile {
-                name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: same_hash,
-                size: 789,
-            },
-        ];
-        assert_err!(LogionLoc::import_collection_item(RuntimeOrigin::root(), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::DuplicateFile);
+
+        assert_err!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID), Error::<Test>::CollectionItemHasNativeToken);
+        assert_err!(LogionLoc::force_transfer_collection_item(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, collection_item_id, ITEM_OWNER_ID), Error::<Test>::CollectionItemHasNativeToken);
     });
 }
 
 #[test]
-fn it_fails_import_missing_token() {
+fn it_fails_force_transferring_collection_item_if_not_collection_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(
-            LogionLoc::import_collection_item(
-                RuntimeOrigin::root(),
-                LOC_ID,
-                collection_item_id,
-                collection_item_description.clone(),
-                vec![],
-                None,
-                true,
-                Vec::new()
-            ),
-            Error::<Test>::MissingToken,
-        );
+        assert_err!(LogionLoc::force_transfer_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_import_missing_files() {
+fn it_allows_restricted_delivery_to_registered_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let (collection_item_id, file_hash) = create_closed_collection_with_restricted_item(true);
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(
-            LogionLoc::import_collection_item(
-                RuntimeOrigin::root(),
-                LOC_ID,
-                collection_item_id,
-                collection_item_description.clone(),
-                vec![],
-                Some(CollectionItemToken {
-                    token_issuance: 1,
-                    token_id: sha256(&"some_id".as_bytes().to_vec()),
-                    token_type: sha256(&"some_type".as_bytes().to_vec()),
-                }),
-                true,
-                Vec::new()
-            ),
-            Error::<Test>::MissingFiles,
-        );
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &ITEM_OWNER_ID), Ok(true));
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &NEW_ITEM_OWNER_ID), Ok(false));
+
+        assert_ok!(LogionLoc::transfer_collection_item(RuntimeOrigin::signed(ITEM_OWNER_ID), LOC_ID, collection_item_id, NEW_ITEM_OWNER_ID));
+
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &ITEM_OWNER_ID), Ok(false));
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &NEW_ITEM_OWNER_ID), Ok(true));
+        assert_eq!(LogionLoc::can_claim_restricted_delivery(&LOC_ID, &collection_item_id, &file_hash, &TOKEN_HOLDER_ID), Ok(true));
     });
 }
 
 #[test]
-fn it_fails_import_record_not_root() {
+fn it_removes_collection_item_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(LogionLoc::set_collection_item_owner(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, ITEM_OWNER_ID));
 
-        let record_id = sha256(&"record-id".as_bytes().to_vec());
-        let record_description = sha256(&"record-description".as_bytes().to_vec());
-        let file_hash = sha256(&"file content".as_bytes().to_vec());
-        assert_err!(
-            LogionLoc::import_tokens_record(
-                RuntimeOrigin::signed(LOC_REQUESTER_ID),
-                LOC_ID,
-                record_id,
-                record_description.clone(),
-                vec![
-                    TokensRecordFile {
-                        name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                        content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                        hash: file_hash,
-                        size: 789,
-                    },
-                ],
-                LOC_REQUESTER_ID,
-            ),
-            BadOrigin,
-        );
+        assert_ok!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id));
+
+        assert!(LogionLoc::collection_item_owner(LOC_ID, collection_item_id).is_none());
     });
 }
 
+fn other_record_id() -> H256 {
+    BlakeTwo256::hash_of(&"Other record ID".as_bytes().to_vec())
+}
+
+fn add_tokens_record_with(record_id: H256, target_items: Vec<H256>, supersedes: Option<H256>) -> DispatchResultWithPostInfo {
+    LogionLoc::add_tokens_record_with_targets(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, build_record_description(), build_record_files(1), false, target_items, supersedes)
+}
+
 #[test]
-fn it_imports_valid_record() {
+fn it_adds_tokens_record_targeting_items() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        let record_id = build_record_id();
 
-        let record_id = sha256(&"record-id".as_bytes().to_vec());
-        let record_description = sha256(&"record-description".as_bytes().to_vec());
-        let file_hash = sha256(&"file content".as_bytes().to_vec());
-        let files = vec![
-            TokensRecordFile {
-                name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: file_hash,
-                size: 789,
-            },
-        ];
-        assert_ok!(LogionLoc::import_tokens_record(
-            RuntimeOrigin::root(),
-            LOC_ID,
-            record_id,
-            record_description.clone(),
-            files.clone(),
-            LOC_REQUESTER_ID,
-        ));
-        assert_eq!(
-            LogionLoc::tokens_records(LOC_ID, record_id),
-            Some(TokensRecord {
-                description: record_description,
-                files: BoundedVec::try_from(files).unwrap(),
-                submitter: LOC_REQUESTER_ID,
-                imported: true,
-            }
-        ));
+        assert_ok!(add_tokens_record_with(record_id, Vec::from([ collection_item_id ]), None));
+
+        assert_eq!(LogionLoc::tokens_record_targets(LOC_ID, record_id), Some(BoundedVec::try_from(Vec::from([ collection_item_id ])).unwrap()));
+        assert_eq!(LogionLoc::tokens_records_of_item(&LOC_ID, &collection_item_id), Ok(Vec::from([ record_id ])));
     });
 }
 
 #[test]
-fn it_fails_import_record_if_duplicate_key() {
+fn it_adds_tokens_record_for_whole_collection() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        let record_id = build_record_id();
 
-        let record_id = sha256(&"record-id".as_bytes().to_vec());
-        let record_description = sha256(&"record-description".as_bytes().to_vec());
-        let file_hash = sha256(&"file content".as_bytes().to_vec());
-        assert_ok!(LogionLoc::import_tokens_record(RuntimeOrigin::root(), LOC_ID,
-                record_id,
-                record_description.clone(),
-                vec![
-                    TokensRecordFile {
-                        name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                        content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                        hash: file_hash,
-                        size: 789,
-                    },
-                ],
-                LOC_REQUESTER_ID,
-        ));
-        assert_err!(LogionLoc::import_tokens_record(
-                RuntimeOrigin::root(),
-                LOC_ID,
-                record_id,
-                record_description.clone(),
-                vec![
-                    TokensRecordFile {
-                        name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                        content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                        hash: file_hash,
-                        size: 789,
-                    },
-                ],
-                LOC_REQUESTER_ID,
-            ),
-            Error::<Test>::TokensRecordAlreadyExists,
-        );
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
+
+        assert!(LogionLoc::tokens_record_targets(LOC_ID, record_id).is_none());
+        assert!(LogionLoc::tokens_records_of_item(&LOC_ID, &collection_item_id).unwrap().is_empty());
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_targeting_unknown_item() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_with_item();
+
+        assert_err!(add_tokens_record_with(build_record_id(), Vec::from([ H256::repeat_byte(1) ]), None), Error::<Test>::CollectionItemNotFound);
     });
 }
 
 #[test]
-fn it_fails_import_record_with_duplicate_hash() {
+fn it_fails_adding_tokens_record_with_duplicate_targets() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
 
-        let record_id = sha256(&"record-id".as_bytes().to_vec());
-        let record_description = sha256(&"record-description".as_bytes().to_vec());
-        let file_hash = sha256(&"file content".as_bytes().to_vec());
-        let files = vec![
-            TokensRecordFile {
-                name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: file_hash,
-                size: 789,
-            },
-            TokensRecordFile {
-                name: sha256(&"doc2.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: file_hash,
-                size: 789,
-            },
-        ];
-        assert_err!(LogionLoc::import_tokens_record(RuntimeOrigin::root(), LOC_ID,
-                record_id,
-                record_description.clone(),
-                files,
-                LOC_REQUESTER_ID,
-            ),
-            Error::<Test>::DuplicateFile,
-        );
+        assert_err!(add_tokens_record_with(build_record_id(), Vec::from([ collection_item_id, collection_item_id ]), None), Error::<Test>::DuplicateTokensRecordTarget);
     });
 }
 
 #[test]
-fn it_fails_import_must_upload() {
+fn it_fails_adding_tokens_record_with_too_many_targets() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        let collection_item_id = create_closed_collection_with_item();
+        let targets = (0..=MaxTokensRecordTargets::get()).map(|_| collection_item_id).collect();
 
-        let record_id = sha256(&"record-id".as_bytes().to_vec());
-        let record_description = sha256(&"record-description".as_bytes().to_vec());
-        assert_err!(
-            LogionLoc::import_tokens_record(
-                RuntimeOrigin::root(),
-                LOC_ID,
-                record_id,
-                record_description.clone(),
-                vec![],
-                LOC_REQUESTER_ID,
-            ),
-            Error::<Test>::MustUpload,
-        );
+        assert_err!(add_tokens_record_with(build_record_id(), targets, None), Error::<Test>::TokensRecordTooManyTargets);
     });
 }
 
 #[test]
-fn it_fails_import_invited_contributor_selection_not_root() {
+fn it_adds_tokens_record_superseding_another() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        create_closed_collection_with_item();
+        let superseded_record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(superseded_record_id, Vec::new(), None));
+        let record_id = other_record_id();
 
-        assert_err!(
-            LogionLoc::import_invited_contributor_selection(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, INVITED_CONTRIBUTOR_ID),
-            BadOrigin,
-        );
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), Some(superseded_record_id)));
+
+        assert_eq!(LogionLoc::tokens_record_supersedes(LOC_ID, record_id), Some(superseded_record_id));
+        assert_eq!(LogionLoc::tokens_record_superseded_by(LOC_ID, superseded_record_id), Some(record_id));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordSuperseded(LOC_ID, record_id, superseded_record_id)));
     });
 }
 
 #[test]
-fn it_imports_invited_contributor_selection() {
+fn it_fails_superseding_tokens_record_twice() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        create_closed_collection_with_item();
+        let superseded_record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(superseded_record_id, Vec::new(), None));
+        assert_ok!(add_tokens_record_with(other_record_id(), Vec::new(), Some(superseded_record_id)));
 
-        assert_ok!(LogionLoc::import_invited_contributor_selection(RuntimeOrigin::root(), LOC_ID, INVITED_CONTRIBUTOR_ID));
-        assert_eq!(LogionLoc::selected_invited_contributors(LOC_ID, INVITED_CONTRIBUTOR_ID), Some(()));
+        assert_err!(add_tokens_record_with(H256::repeat_byte(1), Vec::new(), Some(superseded_record_id)), Error::<Test>::TokensRecordAlreadySuperseded);
     });
 }
 
 #[test]
-fn it_fails_reimport_invited_contributor_selection() {
+fn it_fails_superseding_unknown_tokens_record() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
+        create_closed_collection_with_item();
 
-        assert_ok!(LogionLoc::import_invited_contributor_selection(RuntimeOrigin::root(), LOC_ID, INVITED_CONTRIBUTOR_ID));
-        assert_err!(
-            LogionLoc::import_invited_contributor_selection(RuntimeOrigin::root(), LOC_ID, INVITED_CONTRIBUTOR_ID),
-            Error::<Test>::AlreadyExists,
-        );
+        assert_err!(add_tokens_record_with(build_record_id(), Vec::new(), Some(other_record_id())), Error::<Test>::TokensRecordNotFound);
     });
 }
 
 #[test]
-fn it_fails_import_an_issuer_not_root() {
+fn it_revokes_tokens_record_as_submitter() {
+    it_revokes_tokens_record(LOC_REQUESTER_ID);
+}
+
+#[test]
+fn it_revokes_tokens_record_as_collection_owner() {
+    it_revokes_tokens_record(legal_officer_id(1));
+}
+
+fn it_revokes_tokens_record(revoker: AccountId) {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let legal_officer = legal_officer_id(1);
+        create_closed_collection_with_item();
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
+        let reason = sha256(&"wrong file".as_bytes().to_vec());
 
-        assert_err!(
-            LogionLoc::import_verified_issuer(
-                RuntimeOrigin::signed(legal_officer),
-                legal_officer,
-                ISSUER_ID1,
-                ISSUER1_IDENTITY_LOC_ID,
-            ),
-            BadOrigin,
-        );
+        assert_ok!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(revoker), LOC_ID, record_id, reason));
+
+        assert_eq!(LogionLoc::tokens_record_revocation(LOC_ID, record_id), Some(TokensRecordRevocation {
+            revoked_by: revoker,
+            reason,
+            revoked_on: 1,
+        }));
+        assert!(LogionLoc::tokens_records(LOC_ID, record_id).is_some());
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordRevoked(LOC_ID, record_id, reason)));
     });
 }
 
 #[test]
-fn it_imports_an_issuer() {
+fn it_fails_revoking_tokens_record_if_unauthorized() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let legal_officer = legal_officer_id(1);
+        create_closed_collection_with_item();
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
 
-        assert_ok!(LogionLoc::import_verified_issuer(
-            RuntimeOrigin::root(),
-            legal_officer,
-            ISSUER_ID1,
-            ISSUER1_IDENTITY_LOC_ID,
-        ));
+        assert_err!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, record_id, H256::repeat_byte(1)), Error::<Test>::Unauthorized);
+    });
+}
 
-        assert_eq!(
-            LogionLoc::verified_issuers(legal_officer, ISSUER_ID1),
-            Some(VerifiedIssuer {
-                identity_loc: ISSUER1_IDENTITY_LOC_ID,
-                imported: true,
-            }),
-        );
+#[test]
+fn it_fails_revoking_tokens_record_twice() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_with_item();
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
+        assert_ok!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, H256::repeat_byte(1)));
+
+        assert_err!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, H256::repeat_byte(1)), Error::<Test>::TokensRecordAlreadyRevoked);
     });
 }
 
 #[test]
-fn it_fails_imports_an_issuer_twice() {
+fn it_fails_revoking_unknown_tokens_record() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let legal_officer = legal_officer_id(1);
+        create_closed_collection_with_item();
 
-        assert_ok!(LogionLoc::import_verified_issuer(
-            RuntimeOrigin::root(),
-            legal_officer,
-            ISSUER_ID1,
-            ISSUER1_IDENTITY_LOC_ID,
-        ));
+        assert_err!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, build_record_id(), H256::repeat_byte(1)), Error::<Test>::TokensRecordNotFound);
+    });
+}
 
-        assert_err!(
-            LogionLoc::import_verified_issuer(
-                RuntimeOrigin::root(),
-                legal_officer,
-                ISSUER_ID1,
-                ISSUER1_IDENTITY_LOC_ID,
-            ),
-            Error::<Test>::AlreadyExists,
-        );
+#[test]
+fn it_restricts_tokens_record_access_to_targeted_items() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let other_item_id = BlakeTwo256::hash_of(&"other-item-id".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, other_item_id, sha256(&"other-item-description".as_bytes().to_vec()), Vec::new(), None, false, Vec::new()));
+        let targeting_item = build_record_id();
+        assert_ok!(add_tokens_record_with(targeting_item, Vec::from([ collection_item_id ]), None));
+        let targeting_other_item = other_record_id();
+        assert_ok!(add_tokens_record_with(targeting_other_item, Vec::from([ other_item_id ]), None));
+
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &targeting_item, &collection_item_id, &TOKEN_HOLDER_ID), Ok(true));
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &targeting_other_item, &collection_item_id, &TOKEN_HOLDER_ID), Ok(false));
     });
 }
 
 #[test]
-fn it_fails_import_an_issuer_selection_not_root() {
+fn it_denies_access_to_revoked_tokens_record() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let legal_officer = legal_officer_id(1);
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), None));
+        assert_ok!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, H256::repeat_byte(1)));
 
-        assert_err!(
-            LogionLoc::import_verified_issuer_selection(
-                RuntimeOrigin::signed(LOC_REQUESTER_ID),
-                LOC_ID,
-                ISSUER_ID1,
-                legal_officer,
-            ),
-            BadOrigin,
-        );
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &record_id, &collection_item_id, &TOKEN_HOLDER_ID), Ok(false));
     });
 }
 
 #[test]
-fn it_imports_an_issuer_selection() {
+fn it_fails_removing_collection_item_targeted_by_tokens_record() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let legal_officer = legal_officer_id(1);
+        let collection_item_id = create_closed_collection_with_item();
+        assert_ok!(add_tokens_record_with(build_record_id(), Vec::from([ collection_item_id ]), None));
 
-        assert_ok!(LogionLoc::import_verified_issuer_selection(
-            RuntimeOrigin::root(),
-            LOC_ID,
-            ISSUER_ID1,
-            legal_officer,
-        ));
+        assert_err!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id), Error::<Test>::CannotRemoveItemWithTokensRecords);
+    });
+}
 
-        assert!(LogionLoc::selected_verified_issuers(LOC_ID, ISSUER_ID1).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, legal_officer, LOC_ID)).is_some());
+#[test]
+fn it_removes_collection_item_targeted_by_revoked_tokens_records_only() {
+    new_test_ext().execute_with(|| {
+        let collection_item_id = create_closed_collection_with_item();
+        let record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::from([ collection_item_id ]), None));
+        assert_ok!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, H256::repeat_byte(1)));
+
+        assert_ok!(LogionLoc::remove_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id));
+
+        assert!(LogionLoc::tokens_records_of_item(&LOC_ID, &collection_item_id).unwrap().is_empty());
     });
 }
 
 #[test]
-fn it_fails_importing_an_issuer_selection_twice() {
+fn it_supersedes_tokens_record_as_collection_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let legal_officer = legal_officer_id(1);
+        create_closed_collection_with_item();
+        let superseded_record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(superseded_record_id, Vec::new(), None));
+        let record_id = other_record_id();
 
-        assert_ok!(LogionLoc::import_verified_issuer_selection(
-            RuntimeOrigin::root(),
-            LOC_ID,
-            ISSUER_ID1,
-            legal_officer,
-        ));
-        assert_err!(
-            LogionLoc::import_verified_issuer_selection(
-                RuntimeOrigin::root(),
-                LOC_ID,
-                ISSUER_ID1,
-                legal_officer,
-            ),
-            Error::<Test>::AlreadyExists,
-        );
+        assert_ok!(LogionLoc::add_tokens_record_with_targets(RuntimeOrigin::signed(legal_officer_id(1)), LOC_ID, record_id, build_record_description(), build_record_files(1), false, Vec::new(), Some(superseded_record_id)));
+
+        assert_eq!(LogionLoc::tokens_record_superseded_by(LOC_ID, superseded_record_id), Some(record_id));
     });
 }
 
 #[test]
-fn it_fails_import_sponsorship_not_root() {
+fn it_fails_superseding_tokens_record_if_unauthorized() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        let legal_officer = legal_officer_id(1);
-        let loc_id = Some(LOC_ID);
+        create_closed_collection_with_selected_issuer();
+        let superseded_record_id = build_record_id();
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, superseded_record_id, build_record_description(), build_record_files(1), false));
 
-        assert_err!(
-            LogionLoc::import_sponsorship(
-                RuntimeOrigin::signed(legal_officer),
-                sponsorship_id,
-                SPONSOR_ID,
-                sponsored_account,
-                legal_officer,
-                loc_id,
-            ),
-            BadOrigin,
-        );
+        assert_err!(LogionLoc::add_tokens_record_with_targets(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, other_record_id(), build_record_description(), build_record_files(1), false, Vec::new(), Some(superseded_record_id)), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_imports_sponsorship() {
+fn it_fails_superseding_revoked_tokens_record() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        let legal_officer = legal_officer_id(1);
-        let loc_id = Some(LOC_ID);
+        create_closed_collection_with_item();
+        let superseded_record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(superseded_record_id, Vec::new(), None));
+        assert_ok!(LogionLoc::revoke_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, superseded_record_id, H256::repeat_byte(1)));
 
-        assert_ok!(LogionLoc::import_sponsorship(
-            RuntimeOrigin::root(),
-            sponsorship_id,
-            SPONSOR_ID,
-            sponsored_account,
-            legal_officer,
-            loc_id,
-        ));
+        assert_err!(add_tokens_record_with(other_record_id(), Vec::new(), Some(superseded_record_id)), Error::<Test>::CannotSupersedeRevokedTokensRecord);
+    });
+}
 
-        assert_eq!(
-            LogionLoc::sponsorship(sponsorship_id),
-            Some(Sponsorship {
-                sponsor: SPONSOR_ID,
-                sponsored_account,
-                legal_officer,
-                loc_id,
-                imported: true,
-            }),
-        );
+#[test]
+fn it_denies_access_to_superseded_tokens_record() {
+    new_test_ext().execute_with(|| {
+        let (collection_item_id, _) = create_closed_collection_with_restricted_item(true);
+        let superseded_record_id = build_record_id();
+        assert_ok!(add_tokens_record_with(superseded_record_id, Vec::new(), None));
+        let record_id = other_record_id();
+        assert_ok!(add_tokens_record_with(record_id, Vec::new(), Some(superseded_record_id)));
+
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &superseded_record_id, &collection_item_id, &TOKEN_HOLDER_ID), Ok(false));
+        assert_eq!(LogionLoc::can_access_tokens_record(&LOC_ID, &record_id, &collection_item_id, &TOKEN_HOLDER_ID), Ok(true));
     });
 }
 
 #[test]
-fn it_fails_import_sponsorship_twice() {
+fn it_imports_tokens_record_with_targets() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        let legal_officer = legal_officer_id(1);
-        let loc_id = Some(LOC_ID);
+        let collection_item_id = create_closed_collection_with_item();
+        let superseded_record_id = build_record_id();
+        assert_ok!(LogionLoc::import_tokens_record(RuntimeOrigin::root(), LOC_ID, superseded_record_id, build_record_description(), build_record_files(1), LOC_REQUESTER_ID));
+        let record_id = other_record_id();
 
-        assert_ok!(LogionLoc::import_sponsorship(
-            RuntimeOrigin::root(),
-            sponsorship_id,
-            SPONSOR_ID,
-            sponsored_account,
-            legal_officer,
-            loc_id,
-        ));
+        assert_ok!(LogionLoc::import_tokens_record_with_targets(RuntimeOrigin::root(), LOC_ID, record_id, build_record_description(), build_record_files(1), LOC_REQUESTER_ID, Vec::from([ collection_item_id ]), Some(superseded_record_id)));
 
-        assert_err!(
-            LogionLoc::import_sponsorship(
-                RuntimeOrigin::root(),
-                sponsorship_id,
-                SPONSOR_ID,
-                sponsored_account,
-                legal_officer,
-                loc_id,
-            ),
-            Error::<Test>::AlreadyExists,
-        );
+        assert!(LogionLoc::tokens_records(LOC_ID, record_id).unwrap().imported);
+        assert_eq!(LogionLoc::tokens_records_of_item(&LOC_ID, &collection_item_id), Ok(Vec::from([ record_id ])));
+        assert_eq!(LogionLoc::tokens_record_supersedes(LOC_ID, record_id), Some(superseded_record_id));
+        assert_eq!(LogionLoc::tokens_record_superseded_by(LOC_ID, superseded_record_id), Some(record_id));
+        System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordImported(LOC_ID, record_id)));
     });
 }
 
 #[test]
-fn it_adds_tokens_record_issuer_charged() {
-    it_adds_tokens_record_charging(INVITED_CONTRIBUTOR_ID, INVITED_CONTRIBUTOR_ID, true);
+fn it_fails_importing_tokens_record_targeting_unknown_item() {
+    new_test_ext().execute_with(|| {
+        create_closed_collection_with_item();
+
+        assert_err!(LogionLoc::import_tokens_record_with_targets(RuntimeOrigin::root(), LOC_ID, build_record_id(), build_record_description(), build_record_files(1), LOC_REQUESTER_ID, Vec::from([ H256::repeat_byte(1) ]), None), Error::<Test>::CollectionItemNotFound);
+    });
 }
@@ -14,7 +14,7 @@ pub trait WeightInfo {
     fn add_metadata() -> Weight;
     fn add_file() -> Weight;
     fn add_link() -> Weight;
-    fn close() -> Weight;
+    fn close(n: u32, ) -> Weight;
     fn make_void() -> Weight;
     fn make_void_and_replace() -> Weight;
     fn create_collection_loc() -> Weight;
@@ -37,430 +37,431 @@ pub trait WeightInfo {
 	fn import_verified_issuer() -> Weight;
 	fn import_verified_issuer_selection() -> Weight;
 	fn import_sponsorship() -> Weight;
+	fn grant_fee_allowance() -> Weight;
+	fn revoke_fee_allowance() -> Weight;
+	fn delete_metadata() -> Weight;
+	fn delete_file() -> Weight;
+	fn delete_link() -> Weight;
+	fn acknowledge_items(n: u32, ) -> Weight;
+	fn add_items(m: u32, f: u32, l: u32, ) -> Weight;
+	fn register_template() -> Weight;
+	fn create_loc_from_template() -> Weight;
+	fn create_polkadot_co_owned_transaction_loc() -> Weight;
+	fn transfer_legal_officer_data(l: u32, i: u32, s: u32, ) -> Weight;
+	fn revoke_collection_item() -> Weight;
+	fn remove_collection_item() -> Weight;
+	fn add_collection_items(n: u32, ) -> Weight;
+	fn update_collection_item() -> Weight;
+	fn set_collection_item_owner() -> Weight;
+	fn transfer_collection_item() -> Weight;
+	fn force_transfer_collection_item() -> Weight;
+	fn revoke_tokens_record() -> Weight;
+	fn make_void_with_refund() -> Weight;
+	fn release_storage_deposits(n: u32, ) -> Weight;
+	fn claim_restricted_delivery() -> Weight;
+	fn claim_tokens_record_access() -> Weight;
+	fn mint_non_fungible_native_token() -> Weight;
+	fn mint_fungible_native_token() -> Weight;
+	fn add_tokens_record_with_targets(t: u32, ) -> Weight;
+	fn import_tokens_record_with_targets(t: u32, ) -> Weight;
 }
 
 /// Weights for pallet_logion_loc using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:51 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::AccountLocsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::AccountLocsMap` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
 	fn create_polkadot_identity_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `10293`
-		//  Estimated: `867378`
-		// Minimum execution time: 226_327_000 picoseconds.
 		Weight::from_parts(233_772_000, 0)
-			.saturating_add(Weight::from_parts(0, 867378))
-			.saturating_add(T::DbWeight::get().reads(53))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 868008))
+			.saturating_add(T::DbWeight::get().reads(205))
+			.saturating_add(T::DbWeight::get().writes(354))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn create_logion_identity_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `271`
-		//  Estimated: `17978`
-		// Minimum execution time: 18_671_000 picoseconds.
 		Weight::from_parts(18_969_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:52 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::AccountLocsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::AccountLocsMap` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
 	fn create_polkadot_transaction_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `10440`
-		//  Estimated: `884366`
-		// Minimum execution time: 231_602_000 picoseconds.
 		Weight::from_parts(249_136_000, 0)
-			.saturating_add(Weight::from_parts(0, 884366))
-			.saturating_add(T::DbWeight::get().reads(54))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 884996))
+			.saturating_add(T::DbWeight::get().reads(206))
+			.saturating_add(T::DbWeight::get().writes(354))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:2 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn create_logion_transaction_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `440`
-		//  Estimated: `34966`
-		// Minimum execution time: 20_135_000 picoseconds.
 		Weight::from_parts(20_624_000, 0)
-			.saturating_add(Weight::from_parts(0, 34966))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 35596))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::AccountLocsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::AccountLocsMap` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::LocMap` (r:52 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn create_collection_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `10440`
-		//  Estimated: `884366`
-		// Minimum execution time: 231_018_000 picoseconds.
 		Weight::from_parts(236_069_000, 0)
-			.saturating_add(Weight::from_parts(0, 884366))
-			.saturating_add(T::DbWeight::get().reads(54))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 884996))
+			.saturating_add(T::DbWeight::get().reads(206))
+			.saturating_add(T::DbWeight::get().writes(354))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn add_metadata() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5190`
-		//  Estimated: `17978`
-		// Minimum execution time: 36_129_000 picoseconds.
 		Weight::from_parts(37_419_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn add_file() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5386`
-		//  Estimated: `17978`
-		// Minimum execution time: 42_500_000 picoseconds.
 		Weight::from_parts(45_224_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:2 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn add_link() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5249`
-		//  Estimated: `34966`
-		// Minimum execution time: 35_292_000 picoseconds.
 		Weight::from_parts(36_338_000, 0)
-			.saturating_add(Weight::from_parts(0, 34966))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 35596))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn make_void() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `339`
-		//  Estimated: `17978`
-		// Minimum execution time: 16_935_000 picoseconds.
-		Weight::from_parts(17_499_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(25_906_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:2 w:2)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
 	fn make_void_and_replace() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `521`
-		//  Estimated: `34966`
-		// Minimum execution time: 24_661_000 picoseconds.
 		Weight::from_parts(27_556_000, 0)
-			.saturating_add(Weight::from_parts(0, 34966))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 35596))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::CollectionItemsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::CollectionItemsMap` (`max_values`: None, `max_size`: Some(1989), added: 4464, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::CollectionSizeMap` (r:1 w:1)
-	/// Proof: `LogionLoc::CollectionSizeMap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn add_collection_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `410`
-		//  Estimated: `17978`
-		// Minimum execution time: 65_162_000 picoseconds.
 		Weight::from_parts(72_543_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 21167))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::VerifiedIssuersMap` (r:1 w:1)
-	/// Proof: `LogionLoc::VerifiedIssuersMap` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	fn nominate_issuer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `506`
-		//  Estimated: `17978`
-		// Minimum execution time: 18_969_000 picoseconds.
 		Weight::from_parts(19_436_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::VerifiedIssuersMap` (r:1 w:1)
-	/// Proof: `LogionLoc::VerifiedIssuersMap` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::LocsByVerifiedIssuerMap` (r:1 w:0)
-	/// Proof: `LogionLoc::LocsByVerifiedIssuerMap` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn dismiss_issuer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `457`
-		//  Estimated: `3922`
-		// Minimum execution time: 22_722_000 picoseconds.
 		Weight::from_parts(24_055_000, 0)
-			.saturating_add(Weight::from_parts(0, 3922))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(0, 4552))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::VerifiedIssuersMap` (r:1 w:0)
-	/// Proof: `LogionLoc::VerifiedIssuersMap` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::VerifiedIssuersByLocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::VerifiedIssuersByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::LocsByVerifiedIssuerMap` (r:0 w:1)
-	/// Proof: `LogionLoc::LocsByVerifiedIssuerMap` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn set_issuer_selection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `694`
-		//  Estimated: `17978`
-		// Minimum execution time: 29_004_000 picoseconds.
 		Weight::from_parts(30_911_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::TokensRecordsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::TokensRecordsMap` (`max_values`: None, `max_size`: Some(1146), added: 3621, mode: `MaxEncodedLen`)
 	fn add_tokens_record() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `343`
-		//  Estimated: `17978`
-		// Minimum execution time: 26_889_000 picoseconds.
-		Weight::from_parts(28_123_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(30_862_000, 0)
+			.saturating_add(Weight::from_parts(0, 4611))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::SponsorshipMap` (r:1 w:1)
-	/// Proof: `LogionLoc::SponsorshipMap` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	fn create_other_identity_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `415`
-		//  Estimated: `17978`
-		// Minimum execution time: 30_304_000 picoseconds.
 		Weight::from_parts(31_096_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `LoAuthorityList::LegalOfficerSet` (r:1 w:0)
-	/// Proof: `LoAuthorityList::LegalOfficerSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `LogionLoc::SponsorshipMap` (r:1 w:1)
-	/// Proof: `LogionLoc::SponsorshipMap` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	fn sponsor() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `271`
-		//  Estimated: `3736`
-		// Minimum execution time: 17_133_000 picoseconds.
 		Weight::from_parts(17_641_000, 0)
-			.saturating_add(Weight::from_parts(0, 3736))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 4366))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LogionLoc::SponsorshipMap` (r:1 w:1)
-	/// Proof: `LogionLoc::SponsorshipMap` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	fn withdraw_sponsorship() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `210`
-		//  Estimated: `3612`
-		// Minimum execution time: 12_343_000 picoseconds.
 		Weight::from_parts(13_411_000, 0)
-			.saturating_add(Weight::from_parts(0, 3612))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 4242))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::VerifiedIssuersByLocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::VerifiedIssuersByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn acknowledge_metadata() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5356`
-		//  Estimated: `17978`
-		// Minimum execution time: 34_384_000 picoseconds.
 		Weight::from_parts(35_457_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::VerifiedIssuersByLocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::VerifiedIssuersByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn acknowledge_file() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5556`
-		//  Estimated: `17978`
-		// Minimum execution time: 32_574_000 picoseconds.
 		Weight::from_parts(33_820_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::VerifiedIssuersByLocMap` (r:1 w:0)
-	/// Proof: `LogionLoc::VerifiedIssuersByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn acknowledge_link() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5056`
-		//  Estimated: `17978`
-		// Minimum execution time: 31_786_000 picoseconds.
 		Weight::from_parts(33_044_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	fn close() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `15089`
-		//  Estimated: `17978`
-		// Minimum execution time: 69_538_000 picoseconds.
-		Weight::from_parts(72_659_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+	/// The range of component `n` is `[0, 150]`.
+	fn close(n: u32, ) -> Weight {
+		Weight::from_parts(33_417_000, 0)
+			.saturating_add(Weight::from_parts(0, 5285))
+			.saturating_add(Weight::from_parts(3_802_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2673).saturating_mul(n.into()))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:2 w:0)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::AccountLocsMap` (r:1 w:0)
-	/// Proof: `LogionLoc::AccountLocsMap` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::InvitedContributorsByLocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::InvitedContributorsByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn set_invited_contributor_selection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `633`
-		//  Estimated: `34966`
-		// Minimum execution time: 23_595_000 picoseconds.
 		Weight::from_parts(24_072_000, 0)
-			.saturating_add(Weight::from_parts(0, 34966))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(Weight::from_parts(0, 35596))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LogionLoc::LocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::LocMap` (`max_values`: None, `max_size`: Some(14513), added: 16988, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::AccountLocsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::AccountLocsMap` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
 	fn import_loc() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `17978`
-		// Minimum execution time: 66_516_000 picoseconds.
 		Weight::from_parts(67_546_000, 0)
-			.saturating_add(Weight::from_parts(0, 17978))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(154))
+			.saturating_add(T::DbWeight::get().writes(354))
 	}
-	/// Storage: `LogionLoc::CollectionItemsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::CollectionItemsMap` (`max_values`: None, `max_size`: Some(1989), added: 4464, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::CollectionSizeMap` (r:1 w:1)
-	/// Proof: `LogionLoc::CollectionSizeMap` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn import_collection_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `5454`
-		// Minimum execution time: 22_633_000 picoseconds.
 		Weight::from_parts(23_501_000, 0)
-			.saturating_add(Weight::from_parts(0, 5454))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 8643))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LogionLoc::TokensRecordsMap` (r:1 w:1)
-	/// Proof: `LogionLoc::TokensRecordsMap` (`max_values`: None, `max_size`: Some(1146), added: 3621, mode: `MaxEncodedLen`)
 	fn import_tokens_record() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `4611`
-		// Minimum execution time: 17_527_000 picoseconds.
 		Weight::from_parts(18_025_000, 0)
-			.saturating_add(Weight::from_parts(0, 4611))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 5241))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LogionLoc::InvitedContributorsByLocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::InvitedContributorsByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn import_invited_contributor_selection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `3545`
-		// Minimum execution time: 8_832_000 picoseconds.
 		Weight::from_parts(9_897_000, 0)
-			.saturating_add(Weight::from_parts(0, 3545))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 4175))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LogionLoc::VerifiedIssuersMap` (r:1 w:1)
-	/// Proof: `LogionLoc::VerifiedIssuersMap` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	fn import_verified_issuer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `3578`
-		// Minimum execution time: 9_395_000 picoseconds.
 		Weight::from_parts(9_642_000, 0)
-			.saturating_add(Weight::from_parts(0, 3578))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 4208))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `LogionLoc::VerifiedIssuersByLocMap` (r:1 w:1)
-	/// Proof: `LogionLoc::VerifiedIssuersByLocMap` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
-	/// Storage: `LogionLoc::LocsByVerifiedIssuerMap` (r:0 w:1)
-	/// Proof: `LogionLoc::LocsByVerifiedIssuerMap` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn import_verified_issuer_selection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `3545`
-		// Minimum execution time: 12_877_000 picoseconds.
 		Weight::from_parts(13_142_000, 0)
-			.saturating_add(Weight::from_parts(0, 3545))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 4175))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `LogionLoc::SponsorshipMap` (r:1 w:1)
-	/// Proof: `LogionLoc::SponsorshipMap` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	fn import_sponsorship() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `66`
-		//  Estimated: `3612`
-		// Minimum execution time: 11_420_000 picoseconds.
 		Weight::from_parts(13_370_000, 0)
-			.saturating_add(Weight::from_parts(0, 3612))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 4242))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn grant_fee_allowance() -> Weight {
+		Weight::from_parts(16_301_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn revoke_fee_allowance() -> Weight {
+		Weight::from_parts(17_803_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn delete_metadata() -> Weight {
+		Weight::from_parts(28_940_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn delete_file() -> Weight {
+		Weight::from_parts(43_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	fn delete_link() -> Weight {
+		Weight::from_parts(28_335_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// The range of component `n` is `[1, 100]`.
+	fn acknowledge_items(n: u32, ) -> Weight {
+		Weight::from_parts(30_711_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(Weight::from_parts(1_208_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// The range of component `m` is `[0, 100]`.
+	/// The range of component `f` is `[0, 100]`.
+	/// The range of component `l` is `[0, 100]`.
+	fn add_items(m: u32, f: u32, l: u32, ) -> Weight {
+		Weight::from_parts(61_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(Weight::from_parts(1_122_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(1_354_000, 0).saturating_mul(f.into()))
+			.saturating_add(Weight::from_parts(5_871_000, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 16988).saturating_mul(l.into()))
+	}
+	fn register_template() -> Weight {
+		Weight::from_parts(43_012_000, 0)
+			.saturating_add(Weight::from_parts(0, 13750))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn create_loc_from_template() -> Weight {
+		Weight::from_parts(266_418_000, 0)
+			.saturating_add(Weight::from_parts(0, 884996))
+			.saturating_add(T::DbWeight::get().reads(207))
+			.saturating_add(T::DbWeight::get().writes(355))
+	}
+	fn create_polkadot_co_owned_transaction_loc() -> Weight {
+		Weight::from_parts(271_385_000, 0)
+			.saturating_add(Weight::from_parts(0, 884996))
+			.saturating_add(T::DbWeight::get().reads(210))
+			.saturating_add(T::DbWeight::get().writes(360))
+	}
+	/// The range of component `l` is `[0, 100]`.
+	/// The range of component `i` is `[0, 100]`.
+	/// The range of component `s` is `[0, 100]`.
+	fn transfer_legal_officer_data(l: u32, i: u32, s: u32, ) -> Weight {
+		Weight::from_parts(22_937_000, 0)
+			.saturating_add(Weight::from_parts(0, 4521))
+			.saturating_add(Weight::from_parts(33_104_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(14_266_000, 0).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(9_117_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 19575).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 5110).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
+	}
+	fn revoke_collection_item() -> Weight {
+		Weight::from_parts(20_034_000, 0)
+			.saturating_add(Weight::from_parts(0, 6084))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn remove_collection_item() -> Weight {
+		Weight::from_parts(66_852_000, 0)
+			.saturating_add(Weight::from_parts(0, 41964))
+			.saturating_add(T::DbWeight::get().reads(28))
+			.saturating_add(T::DbWeight::get().writes(25))
+	}
+	/// The range of component `n` is `[1, 100]`.
+	fn add_collection_items(n: u32, ) -> Weight {
+		Weight::from_parts(48_327_000, 0)
+			.saturating_add(Weight::from_parts(0, 9810))
+			.saturating_add(Weight::from_parts(27_519_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7023).saturating_mul(n.into()))
+	}
+	fn update_collection_item() -> Weight {
+		Weight::from_parts(50_372_000, 0)
+			.saturating_add(Weight::from_parts(0, 6084))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn set_collection_item_owner() -> Weight {
+		Weight::from_parts(22_183_000, 0)
+			.saturating_add(Weight::from_parts(0, 6084))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn transfer_collection_item() -> Weight {
+		Weight::from_parts(15_302_000, 0)
+			.saturating_add(Weight::from_parts(0, 4243))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn force_transfer_collection_item() -> Weight {
+		Weight::from_parts(17_839_000, 0)
+			.saturating_add(Weight::from_parts(0, 4350))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn revoke_tokens_record() -> Weight {
+		Weight::from_parts(20_716_000, 0)
+			.saturating_add(Weight::from_parts(0, 5241))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn make_void_with_refund() -> Weight {
+		Weight::from_parts(25_906_000, 0)
+			.saturating_add(Weight::from_parts(0, 18608))
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// The range of component `n` is `[1, 100]`.
+	fn release_storage_deposits(n: u32, ) -> Weight {
+		Weight::from_parts(21_442_000, 0)
+			.saturating_add(Weight::from_parts(0, 6350))
+			.saturating_add(Weight::from_parts(31_806_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4464).saturating_mul(n.into()))
+	}
+	fn claim_restricted_delivery() -> Weight {
+		Weight::from_parts(49_820_000, 0)
+			.saturating_add(Weight::from_parts(0, 46330))
+			.saturating_add(T::DbWeight::get().reads(16))
+	}
+	fn claim_tokens_record_access() -> Weight {
+		Weight::from_parts(28_714_000, 0)
+			.saturating_add(Weight::from_parts(0, 6753))
+			.saturating_add(T::DbWeight::get().reads(9))
+	}
+	fn mint_non_fungible_native_token() -> Weight {
+		Weight::from_parts(9_637_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn mint_fungible_native_token() -> Weight {
+		Weight::from_parts(9_781_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// The range of component `t` is `[0, 100]`.
+	fn add_tokens_record_with_targets(t: u32, ) -> Weight {
+		Weight::from_parts(39_884_000, 0)
+			.saturating_add(Weight::from_parts(0, 7601))
+			.saturating_add(Weight::from_parts(4_812_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 4464).saturating_mul(t.into()))
+	}
+	/// The range of component `t` is `[0, 100]`.
+	fn import_tokens_record_with_targets(t: u32, ) -> Weight {
+		Weight::from_parts(18_041_000, 0)
+			.saturating_add(Weight::from_parts(0, 7601))
+			.saturating_add(Weight::from_parts(3_907_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 4464).saturating_mul(t.into()))
+	}
 }
@@ -54,7 +54,7 @@ pub mod pallet {
         pallet_prelude::*,
     };
     use frame_system::ensure_signed;
-    use frame_system::pallet_prelude::OriginFor;
+    use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
     use logion_shared::{IsLegalOfficer, LegalOfficerCreation, LocQuery, LocValidity};
     use crate::BallotStatus::{NotVoted, VotedNo, VotedYes};
     use super::*;
@@ -75,7 +75,7 @@ pub mod pallet {
         type LocValidity: LocValidity<Self::LocId, Self::AccountId>;
 
         /// Query for retrieving LOC
-        type LocQuery: LocQuery<Self::LocId, Self::AccountId>;
+        type LocQuery: LocQuery<Self::LocId, Self::AccountId, BlockNumberFor<Self>>;
 
         /// Creation of a guest LO
         type LegalOfficerCreation: LegalOfficerCreation<Self::AccountId>;
@@ -138,7 +138,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
             let legal_officers = T::IsLegalOfficer::legal_officers();
-            if T::LocValidity::loc_valid_with_owner(&loc_id, &who) {
+            if T::LocValidity::loc_valid_with_owner(&loc_id, &who)? {
                 let all_ballots: Vec<Ballot<<T as frame_system::Config>::AccountId>> = legal_officers
                     .iter()
                     .map(|legal_officer| Ballot { voter: legal_officer.clone(), status: BallotStatus::NotVoted })
@@ -227,7 +227,7 @@ pub mod pallet {
         }
 
         fn add_guest_legal_officer(loc_id: T::LocId) -> DispatchResultWithPostInfo {
-            let option_loc = T::LocQuery::get_loc(&loc_id);
+            let option_loc = T::LocQuery::get_loc(&loc_id)?;
             match option_loc {
                 None => Err(Error::<T>::InvalidLoc)?,
                 Some(loc) => {
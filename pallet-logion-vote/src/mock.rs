@@ -5,9 +5,9 @@ use frame_support::{derive_impl, parameter_types};
 use frame_support::traits::EnsureOrigin;
 use sp_core::hash::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup}, BuildStorage,
+    traits::{BlakeTwo256, IdentityLookup}, BuildStorage, DispatchError,
 };
-use frame_system::{self as system, Config};
+use frame_system::{self as system, Config, pallet_prelude::BlockNumberFor};
 use logion_shared::{IsLegalOfficer, LegalOfficerCaseSummary, LegalOfficerCreation, LocQuery, LocValidity};
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -62,26 +62,29 @@ impl IsLegalOfficer<<Test as system::Config>::AccountId, RuntimeOrigin> for LoAu
 pub struct LocValidityMock;
 
 impl LocValidity<<Test as pallet_logion_vote::Config>::LocId, <Test as system::Config>::AccountId> for LocValidityMock {
-    fn loc_valid_with_owner(loc_id: &<Test as pallet_logion_vote::Config>::LocId, legal_officer: &<Test as Config>::AccountId) -> bool {
-        return *loc_id == LOC_ID && *legal_officer == legal_officer_id(1);
+    fn loc_valid_with_owner(loc_id: &<Test as pallet_logion_vote::Config>::LocId, legal_officer: &<Test as Config>::AccountId) -> Result<bool, DispatchError> {
+        return Ok(*loc_id == LOC_ID && *legal_officer == legal_officer_id(1));
     }
 }
 
 pub struct LocQueryMock;
 
-impl LocQuery<<Test as pallet_logion_vote::Config>::LocId, <Test as system::Config>::AccountId> for LocQueryMock {
-    fn has_closed_identity_locs(_account: &<Test as Config>::AccountId, _legal_officer: &Vec<<Test as Config>::AccountId>) -> bool {
-        false
+impl LocQuery<<Test as pallet_logion_vote::Config>::LocId, <Test as system::Config>::AccountId, BlockNumberFor<Test>> for LocQueryMock {
+    fn has_closed_identity_locs(_account: &<Test as Config>::AccountId, _legal_officer: &Vec<<Test as Config>::AccountId>) -> Result<bool, DispatchError> {
+        Ok(false)
     }
 
-    fn get_loc(loc_id: &<Test as crate::Config>::LocId) -> Option<LegalOfficerCaseSummary<<Test as Config>::AccountId>> {
+    fn get_loc(loc_id: &<Test as crate::Config>::LocId) -> Result<Option<LegalOfficerCaseSummary<<Test as Config>::AccountId, BlockNumberFor<Test>>>, DispatchError> {
         if *loc_id == LOC_ID {
-            return Some(LegalOfficerCaseSummary {
+            return Ok(Some(LegalOfficerCaseSummary {
                 owner: legal_officer_id(1),
                 requester: Some(legal_officer_id(3)),
-            })
+                created_on: Some(1),
+                closed_on: Some(1),
+                voided_on: None,
+            }))
         }
-        return None
+        return Ok(None)
     }
 }
 
@@ -37,7 +37,7 @@ pub mod pallet {
         type CreateRecoveryCallFactory: CreateRecoveryCallFactory<Self::RuntimeOrigin, Self::AccountId, BlockNumberFor<Self>>;
 
         /// Query for checking the existence of a closed Identity LOC
-        type LocQuery: LocQuery<Self::LocId, Self::AccountId>;
+        type LocQuery: LocQuery<Self::LocId, Self::AccountId, BlockNumberFor<Self>>;
 
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
@@ -82,7 +82,7 @@ pub mod pallet {
                 Err(Error::<T>::InvalidLegalOfficers)?
             } else {
                 let who = ensure_signed(origin.clone())?;
-                if T::LocQuery::has_closed_identity_locs(&who, &legal_officers) {
+                if T::LocQuery::has_closed_identity_locs(&who, &legal_officers)? {
                     Self::dispatch_create_recovery(origin, legal_officers)
                 } else {
                     Err(Error::<T>::MissingIdentityLoc)?
@@ -4,7 +4,7 @@ use sp_core::hash::H256;
 use frame_benchmarking::account;
 use frame_support::{derive_impl, parameter_types};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup}, BuildStorage,
+    traits::{BlakeTwo256, IdentityLookup}, BuildStorage, DispatchError,
 };
 use frame_system as system;
 use system::pallet_prelude::BlockNumberFor;
@@ -76,16 +76,16 @@ pub fn legal_officers_not_closed() -> Vec<AccountId> {
 }
 
 pub struct LocQueryMock;
-impl LocQuery<<Test as pallet_verified_recovery::Config>::LocId, <Test as system::Config>::AccountId> for LocQueryMock {
+impl LocQuery<<Test as pallet_verified_recovery::Config>::LocId, <Test as system::Config>::AccountId, BlockNumberFor<Test>> for LocQueryMock {
     fn has_closed_identity_locs(
         account: &<Test as system::Config>::AccountId,
         legal_officers: &Vec<<Test as system::Config>::AccountId>
-    ) -> bool {
-        return *account == requester() && legal_officers[0] == legal_officer(1) && legal_officers[1] == legal_officer(2);
+    ) -> Result<bool, DispatchError> {
+        return Ok(*account == requester() && legal_officers[0] == legal_officer(1) && legal_officers[1] == legal_officer(2));
     }
 
-    fn get_loc(_loc_id: &<Test as pallet_verified_recovery::Config>::LocId) -> Option<LegalOfficerCaseSummary<<Test as system::Config>::AccountId>> {
-        return None;
+    fn get_loc(_loc_id: &<Test as pallet_verified_recovery::Config>::LocId) -> Result<Option<LegalOfficerCaseSummary<<Test as system::Config>::AccountId, BlockNumberFor<Test>>>, DispatchError> {
+        return Ok(None);
     }
 }
 